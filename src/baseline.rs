@@ -0,0 +1,93 @@
+//! Baseline/diff mode for adopting the linter on legacy projects.
+//!
+//! `--write-baseline <path>` snapshots the model failures found by the
+//! current run. A later run passed `--baseline <path>` loads that snapshot
+//! and suppresses any failure already present in it, so only newly
+//! introduced failures fail the build. Failures are matched by
+//! [`ModelFailure::fingerprint`], not struct equality, so incidental drift
+//! (e.g. a fanout count growing) doesn't reopen an already-accepted failure.
+
+use crate::check::{CheckResult, ModelFailure, ModelFailureEntry};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::path::Path;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum BaselineError {
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("failed to parse baseline file: {0}")]
+    ParseFailure(#[from] serde_json::Error),
+}
+
+/// Canonical serializable form of a `CheckResult`'s model failures.
+///
+/// This intentionally doesn't derive from `ModelResult` directly: baseline
+/// files only need the model id and its failures, not the column results
+/// or pending writeback changes that `ModelResult` also carries.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Baseline {
+    models: Vec<BaselineModel>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct BaselineModel {
+    model_id: String,
+    failures: Vec<ModelFailure>,
+}
+
+impl Baseline {
+    /// Capture every model failure currently present in `result`.
+    pub fn capture(result: &CheckResult) -> Self {
+        let models = result
+            .models
+            .values()
+            .filter(|model_result| !model_result.failures().is_empty())
+            .map(|model_result| BaselineModel {
+                model_id: model_result.model_id().to_string(),
+                failures: model_result
+                    .failures()
+                    .iter()
+                    .map(|entry| entry.failure.clone())
+                    .collect(),
+            })
+            .collect();
+        Baseline { models }
+    }
+
+    pub fn load(path: &Path) -> Result<Self, BaselineError> {
+        let raw = std::fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&raw)?)
+    }
+
+    pub fn write(&self, path: &Path) -> Result<(), BaselineError> {
+        let json = serde_json::to_string_pretty(self).expect("baseline should always serialize");
+        std::fs::write(path, json)?;
+        Ok(())
+    }
+
+    fn fingerprints(&self) -> BTreeSet<(&str, String)> {
+        self.models
+            .iter()
+            .flat_map(|model| {
+                model
+                    .failures
+                    .iter()
+                    .map(move |failure| (model.model_id.as_str(), failure.fingerprint()))
+            })
+            .collect()
+    }
+
+    /// Remove any failure already recorded in this baseline from `result`,
+    /// leaving only failures that are newly introduced.
+    pub fn apply(&self, result: &mut CheckResult) {
+        let known = self.fingerprints();
+        for model_result in result.models.values_mut() {
+            let model_id = model_result.model_id().to_string();
+            model_result.failures.retain(|entry: &ModelFailureEntry| {
+                !known.contains(&(model_id.as_str(), entry.failure.fingerprint()))
+            });
+        }
+    }
+}
@@ -3,13 +3,30 @@ use std::path::PathBuf;
 
 use crate::config::ModelPropertiesLayout;
 use crate::writeback::properties::{ModelProperty, SourceProperty};
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum ColumnChange {
     ChangePropertiesFile,
+    /// A required test (e.g. `unique`) was added to this column's `data_tests:` entry.
+    AddDataTest,
+    /// A column's description was (re)populated from upstream lineage. `model_id`/
+    /// `model_name`/`patch_path` identify the node the description was attributed to --
+    /// this is also how a source's own column descriptions are seeded into
+    /// `accumulated_changes` up front (since a source has no `check_model` pass of its
+    /// own), with `model_id` holding the source's unique id so it's distinguishable from
+    /// an inherited model fix.
+    DescriptionChanged {
+        model_id: String,
+        model_name: String,
+        patch_path: Option<PathBuf>,
+        column_name: String,
+        old: Option<String>,
+        new: Option<String>,
+    },
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum ModelChange {
     MovePropertiesFile {
         model_id: String,
@@ -47,7 +64,7 @@ pub enum ModelChange {
     },
 }
 
-#[derive(Default, Debug, Clone)]
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
 pub struct ModelChanges {
     pub model_id: String,
     pub patch_path: Option<PathBuf>,
@@ -55,7 +72,7 @@ pub struct ModelChanges {
     pub column_changes: BTreeMap<String, BTreeSet<ColumnChange>>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum SourceChange {
     ChangePropertiesFile {
         source_id: String,
@@ -66,7 +83,7 @@ pub enum SourceChange {
     },
 }
 
-#[derive(Default, Debug, Clone)]
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
 pub struct SourceChanges {
     pub source_id: String,
     pub source_name: String,
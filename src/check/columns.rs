@@ -1,13 +1,15 @@
 use crate::change_descriptors::{ColumnChange, ModelChanges};
 use crate::config::{Config, Selector};
-use crate::osmosis::get_upstream_col_desc;
+use crate::graph::DbtGraph;
+use crate::osmosis::{InheritedValue, resolve_upstream_col_desc};
 use dbt_schemas::schemas::dbt_column::DbtColumnRef;
 use dbt_schemas::schemas::manifest::{DbtManifestV12, ManifestModel};
+use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 use std::sync::Arc;
 use strum::AsRefStr;
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ColumnResult {
     pub column_name: String,
     pub failures: Vec<ColumnFailure>,
@@ -34,6 +36,13 @@ impl ColumnResult {
                 ColumnFailure::DescriptionMissing => {
                     format!("Column `{}`: Missing Description", self.column_name)
                 }
+                ColumnFailure::AmbiguousInheritance(values) => {
+                    format!(
+                        "Column `{}`: Missing Description (ambiguous upstream values: {})",
+                        self.column_name,
+                        values.join(", ")
+                    )
+                }
             })
             .collect()
     }
@@ -55,16 +64,19 @@ impl std::fmt::Display for ColumnResult {
 
 // Column behavior and writeback coordination now flow through `ModelChange` descriptors.
 
-#[derive(Debug, Clone, Copy, AsRefStr, PartialEq, Eq)]
+#[derive(Debug, Clone, AsRefStr, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ColumnFailure {
     DescriptionMissing,
+    /// Upstream lineage had more than one distinct value for this field at the nearest
+    /// depth that had it set, so no fix was applied.
+    AmbiguousInheritance(Vec<String>),
 }
 
 impl std::fmt::Display for ColumnFailure {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        #[allow(clippy::match_single_binding)] // to allow future expansion
         let extra_info = match self {
-            _ => String::new(),
+            ColumnFailure::DescriptionMissing => String::new(),
+            ColumnFailure::AmbiguousInheritance(values) => format!(": {}", values.join(", ")),
         };
         write!(f, "{}{}", self.as_ref(), extra_info)
     }
@@ -93,6 +105,7 @@ pub fn missing_description(column: &DbtColumnRef, config: &Config) -> Result<(),
 /// Top-level entrypoint for checking all columns on a model.
 pub fn check_model_columns(
     manifest: &DbtManifestV12,
+    graph: &DbtGraph,
     original_model: &ManifestModel,
     working_model: &mut ManifestModel,
     prior_changes: &BTreeMap<String, ModelChanges>,
@@ -108,6 +121,7 @@ pub fn check_model_columns(
     {
         let result = check_model_column(
             manifest,
+            graph,
             original_model,
             original_column,
             working_column,
@@ -125,6 +139,7 @@ pub fn check_model_columns(
 /// apply fixes in-place.
 fn check_model_column(
     manifest: &DbtManifestV12,
+    graph: &DbtGraph,
     model: &ManifestModel,
     original_column: &DbtColumnRef,
     working_column: &mut DbtColumnRef,
@@ -135,6 +150,7 @@ fn check_model_column(
     let mut changes: Vec<ColumnChange> = Vec::new();
     match missing_column_description(
         manifest,
+        graph,
         model,
         original_column,
         working_column,
@@ -153,11 +169,14 @@ fn check_model_column(
     }
 }
 
-/// Try to populate a missing column description from upstream if configured.
+/// Try to populate a missing column description from upstream lineage if configured.
 /// Returns Ok(Some(Change)) if a change was applied, Ok(None) if no-op, or
-/// Err(ColumnFailure) if the column is considered failing and no fix was applied.
+/// Err(ColumnFailure) if the column is considered failing and no fix was applied --
+/// either because no ancestor had the description, or because ancestors at the same
+/// depth disagreed on it (`ColumnFailure::AmbiguousInheritance`).
 fn missing_column_description(
     manifest: &DbtManifestV12,
+    graph: &DbtGraph,
     model: &ManifestModel,
     original_column: &DbtColumnRef,
     working_column: &mut DbtColumnRef,
@@ -175,19 +194,23 @@ fn missing_column_description(
     if !config.is_fixable(Selector::MissingColumnDescriptions) {
         return Err(ColumnFailure::DescriptionMissing);
     }
-    if let Some(new_description_text) = get_upstream_col_desc(
+
+    match resolve_upstream_col_desc(
         manifest,
         Some(prior_changes),
+        graph,
         &model.__common_attr__.unique_id,
         original_column.name.as_str(),
         config,
     ) {
-        let column_mut = Arc::make_mut(working_column);
-        column_mut.description = Some(new_description_text);
+        InheritedValue::Resolved(new_description_text) => {
+            let column_mut = Arc::make_mut(working_column);
+            column_mut.description = Some(new_description_text);
 
-        Ok(Some(ColumnChange::ChangePropertiesFile))
-    } else {
-        Err(ColumnFailure::DescriptionMissing)
+            Ok(Some(ColumnChange::ChangePropertiesFile))
+        }
+        InheritedValue::Ambiguous(values) => Err(ColumnFailure::AmbiguousInheritance(values)),
+        InheritedValue::NotFound => Err(ColumnFailure::DescriptionMissing),
     }
 }
 
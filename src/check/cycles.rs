@@ -0,0 +1,142 @@
+//! Whole-DAG circular-dependency detection. The per-model checks in `models.rs` only
+//! reason about local edges (`depends_on.nodes`/`child_map`); dbt itself forbids a
+//! model depending on its own downstream (directly or transitively), but a cycle can
+//! still slip in through macro-generated `ref`s, so this module walks the whole graph
+//! once per `check_all` run to catch it.
+
+use super::dag_deps;
+use dbt_schemas::schemas::manifest::DbtManifestV12;
+use std::collections::{BTreeMap, BTreeSet};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Color {
+    White,
+    Gray,
+    Black,
+}
+
+/// Every node participating in a dependency cycle, mapped to the sorted, deduplicated
+/// member ids of the cycle it's part of (including itself). A node that sits on more
+/// than one distinct cycle only keeps the first one found.
+pub fn detect_circular_dependencies(manifest: &DbtManifestV12) -> BTreeMap<String, Vec<String>> {
+    let deps = dag_deps(manifest);
+    let mut colors: BTreeMap<String, Color> =
+        deps.keys().map(|id| (id.clone(), Color::White)).collect();
+    let mut seen_cycles: BTreeSet<Vec<String>> = BTreeSet::new();
+    let mut membership: BTreeMap<String, Vec<String>> = BTreeMap::new();
+
+    for root in deps.keys().cloned().collect::<Vec<_>>() {
+        if colors.get(&root) == Some(&Color::White) {
+            visit(&deps, &root, &mut colors, &mut seen_cycles, &mut membership);
+        }
+    }
+
+    membership
+}
+
+/// Iterative DFS with three-color marking, starting from `root`. `path` is the current
+/// DFS stack of node ids in visit order; `frames` pairs each node still on that stack
+/// with the index of the next dependency to visit, so returning from a child's subtree
+/// resumes the parent exactly where it left off. Following an edge into a `Gray` node
+/// means that edge closes a cycle back to an ancestor still on the stack -- slicing
+/// `path` from that node's first occurrence reconstructs the full cycle.
+fn visit(
+    deps: &BTreeMap<String, BTreeSet<String>>,
+    root: &str,
+    colors: &mut BTreeMap<String, Color>,
+    seen_cycles: &mut BTreeSet<Vec<String>>,
+    membership: &mut BTreeMap<String, Vec<String>>,
+) {
+    let mut path: Vec<String> = vec![root.to_string()];
+    let mut frames: Vec<(String, usize)> = vec![(root.to_string(), 0)];
+    colors.insert(root.to_string(), Color::Gray);
+
+    while let Some((node_id, child_idx)) = frames.pop() {
+        let empty = BTreeSet::new();
+        let children: Vec<&String> = deps.get(&node_id).unwrap_or(&empty).iter().collect();
+
+        if child_idx >= children.len() {
+            colors.insert(node_id, Color::Black);
+            path.pop();
+            continue;
+        }
+
+        // resume this frame at the next child once the one we're about to visit returns
+        frames.push((node_id.clone(), child_idx + 1));
+        let child = children[child_idx].clone();
+
+        match colors.get(&child) {
+            Some(Color::Gray) => {
+                if let Some(start) = path.iter().position(|id| id == &child) {
+                    let mut cycle: Vec<String> = path[start..].to_vec();
+                    cycle.sort();
+                    cycle.dedup();
+                    if seen_cycles.insert(cycle.clone()) {
+                        for member in &cycle {
+                            membership.entry(member.clone()).or_insert_with(|| cycle.clone());
+                        }
+                    }
+                }
+            }
+            Some(Color::Black) => {}
+            Some(Color::White) | None => {
+                colors.insert(child.clone(), Color::Gray);
+                path.push(child.clone());
+                frames.push((child, 0));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dbt_schemas::schemas::manifest::DbtNode;
+
+    fn model_depending_on(deps: &[&str]) -> DbtNode {
+        let mut node = DbtNode::Model(Default::default());
+        if let DbtNode::Model(model) = &mut node {
+            model.__base_attr__.depends_on.nodes = deps.iter().map(|d| d.to_string()).collect();
+        }
+        node
+    }
+
+    #[test]
+    fn detects_a_simple_cycle() {
+        let mut manifest = DbtManifestV12::default();
+        manifest.nodes.insert(
+            "model.test.a".to_string(),
+            model_depending_on(&["model.test.b"]),
+        );
+        manifest.nodes.insert(
+            "model.test.b".to_string(),
+            model_depending_on(&["model.test.a"]),
+        );
+
+        let cycles = detect_circular_dependencies(&manifest);
+        assert_eq!(
+            cycles.get("model.test.a"),
+            Some(&vec!["model.test.a".to_string(), "model.test.b".to_string()])
+        );
+        assert_eq!(
+            cycles.get("model.test.b"),
+            Some(&vec!["model.test.a".to_string(), "model.test.b".to_string()])
+        );
+    }
+
+    #[test]
+    fn acyclic_graph_has_no_cycles() {
+        let mut manifest = DbtManifestV12::default();
+        manifest.nodes.insert(
+            "model.test.upstream".to_string(),
+            model_depending_on(&[]),
+        );
+        manifest.nodes.insert(
+            "model.test.downstream".to_string(),
+            model_depending_on(&["model.test.upstream"]),
+        );
+
+        let cycles = detect_circular_dependencies(&manifest);
+        assert!(cycles.is_empty());
+    }
+}
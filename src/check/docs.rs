@@ -1,7 +1,10 @@
+use crate::config::{Config, Selector};
 use dbt_schemas::schemas::manifest::DbtManifestV12;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use strum::AsRefStr;
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct DocResult {
     pub doc_name: String,
     pub failures: Vec<DocFailure>,
@@ -40,37 +43,74 @@ impl std::fmt::Display for DocResult {
     }
 }
 
-#[derive(Debug, Clone, AsRefStr, PartialEq, Eq)]
-pub enum DocFailure{
-	DuplicateDocsBlock(Vec<String>),
+#[derive(Debug, Clone, AsRefStr, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DocFailure {
+    DuplicateDocsBlock(Vec<String>),
 }
 
 impl std::fmt::Display for DocFailure {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let extra_info = match self {
             DocFailure::DuplicateDocsBlock(dupes) => format!(" ({})", dupes.join(",")),
-            _ => String::new(),
         };
         write!(f, "{}{}", self.as_ref(), extra_info)
     }
 }
 
+impl DocFailure {
+    /// The `Selector` this failure is gated behind, used to let
+    /// `suppressions::Suppressions` match it against a
+    /// `disable`/`disable-file` directive. Mirrors `SourceFailure::selector`.
+    pub fn selector(&self) -> Option<Selector> {
+        match self {
+            DocFailure::DuplicateDocsBlock(_) => Some(Selector::DuplicateDocsBlock),
+        }
+    }
+}
 
-#[derive(Debug, Clone)]
-pub enum DocChange {}
-
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DocChange {
+    /// Collapse a group of docs blocks that share identical `block_contents` into `keep`,
+    /// dropping `remove` -- see `writeback::doc::apply_consolidate_docs_block` for the
+    /// textual edit this describes (deleting the `{% docs %}` blocks and rewriting
+    /// `{{ doc(...) }}` references).
+    ConsolidateDocsBlock { keep: String, remove: Vec<String> },
+}
 
-pub fn duplicate_docs(manifest: &DbtManifestV12) -> Option<Vec<DocFailure>> {
-    let mut desc_to_ids: std::collections::HashMap<&str, Vec<String>> = std::collections::HashMap::new();
+/// Groups docs entries by identical `block_contents` and reports each group of two or
+/// more as a `DuplicateDocsBlock` failure. When `Selector::DuplicateDocsBlock` is
+/// fixable (see `Config::is_fixable`), each group also gets a `ConsolidateDocsBlock`
+/// change keeping the lexicographically smallest `unique_id` and removing the rest, so
+/// the writeback subsystem can apply it the same way it applies model/source changes.
+pub fn duplicate_docs(manifest: &DbtManifestV12, config: &Config) -> Vec<DocResult> {
+    let mut desc_to_ids: HashMap<&str, Vec<String>> = HashMap::new();
     for doc in manifest.docs.values() {
         let desc = doc.block_contents.as_str();
         desc_to_ids.entry(desc).or_default().push(doc.unique_id.clone());
     }
-    let mut failures = Vec::new();
-    for (_desc, ids) in desc_to_ids {
-        if ids.len() > 1 {
-            failures.push(DocFailure::DuplicateDocsBlock(ids));
+
+    let mut results = Vec::new();
+    for (_desc, mut ids) in desc_to_ids {
+        if ids.len() <= 1 {
+            continue;
+        }
+        ids.sort();
+        let keep = ids[0].clone();
+        let remove = ids[1..].to_vec();
+
+        let mut changes = Vec::new();
+        if config.is_fixable(Selector::DuplicateDocsBlock) {
+            changes.push(DocChange::ConsolidateDocsBlock {
+                keep: keep.clone(),
+                remove: remove.clone(),
+            });
         }
+
+        results.push(DocResult {
+            doc_name: keep,
+            failures: vec![DocFailure::DuplicateDocsBlock(ids)],
+            changes,
+        });
     }
-    (!failures.is_empty()).then_some(failures)
+    results
 }
\ No newline at end of file
@@ -1,14 +1,27 @@
-use crate::config::{Config, Selector};
+use super::models::classify_layer;
+use crate::change_descriptors::ModelChange;
+use crate::config::{Config, Selector, Severity};
+use crate::writeback::properties::ModelProperty;
 use dbt_schemas::schemas::{
     common::{Access, DbtMaterialization},
-    manifest::{DbtManifestV12, DbtNode, ManifestExposure},
+    manifest::{DbtManifestV12, DbtNode, ManifestExposure, ManifestModel},
 };
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
 use strum::AsRefStr;
 
-#[derive(Debug, Clone, AsRefStr, PartialEq, Eq)]
+#[derive(Debug, Clone, AsRefStr, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ExposureFailure {
     DependentOnPrivateModel(Vec<String>),
     DependentOnMaterializedModel(Vec<String>),
+    /// The exposure itself has no description, or an empty one.
+    MissingDescription,
+    /// Depends directly on a staging/intermediate model or a source rather than a mart
+    /// -- the offending upstream node ids.
+    DependsOnNonMartModel(Vec<String>),
+    /// The exposure's `maturity` or `type` isn't set.
+    MissingMaturityOrType,
 }
 
 impl std::fmt::Display for ExposureFailure {
@@ -16,23 +29,197 @@ impl std::fmt::Display for ExposureFailure {
         let extra_info = match self {
             ExposureFailure::DependentOnPrivateModel(models) => models.join(", ").to_string(),
             ExposureFailure::DependentOnMaterializedModel(models) => models.join(", ").to_string(),
+            ExposureFailure::DependsOnNonMartModel(models) => models.join(", ").to_string(),
+            ExposureFailure::MissingDescription | ExposureFailure::MissingMaturityOrType => {
+                String::new()
+            }
         };
         write!(f, "{}({})", self.as_ref(), extra_info)
     }
 }
 
-#[derive(Debug, Clone)]
+impl ExposureFailure {
+    /// The `Selector` this failure is gated behind, used to resolve its configured
+    /// severity. Mirrors `SourceFailure::selector`.
+    pub fn selector(&self) -> Option<Selector> {
+        match self {
+            ExposureFailure::DependentOnPrivateModel(_) => {
+                Some(Selector::ExposureDependentOnPrivateModel)
+            }
+            ExposureFailure::DependentOnMaterializedModel(_) => {
+                Some(Selector::ExposureParentsMaterializations)
+            }
+            ExposureFailure::MissingDescription => Some(Selector::ExposureMissingDescription),
+            ExposureFailure::DependsOnNonMartModel(_) => Some(Selector::ExposureParentsStaging),
+            ExposureFailure::MissingMaturityOrType => Some(Selector::ExposureMissingMaturityOrType),
+        }
+    }
+
+    /// The effective severity of this failure under `config`. Like `SourceFailure::severity`,
+    /// there's no per-exposure `SeverityOverride` scope (no `ModelScope` equivalent exists
+    /// for exposures), so this only considers global overrides.
+    pub fn severity(&self, config: &Config) -> Severity {
+        self.selector()
+            .map(|selector| config.severity(selector))
+            .unwrap_or(Severity::Error)
+    }
+}
+
+/// An `ExposureFailure` together with its resolved severity. Mirrors
+/// `check::sources::SourceFailureEntry`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExposureFailureEntry {
+    pub failure: ExposureFailure,
+    pub severity: Severity,
+}
+
+impl ExposureFailureEntry {
+    pub fn is_error(&self) -> bool {
+        self.severity == Severity::Error
+    }
+}
+
+impl std::fmt::Display for ExposureFailureEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{}] {}", self.severity.as_ref(), self.failure)
+    }
+}
+
+/// Resolve `failure`'s severity and record it on `failures`, same as
+/// `check::sources::record_failure`. Skipped entirely if the selector is configured `off`.
+fn record_failure(
+    failures: &mut Vec<ExposureFailureEntry>,
+    failure: ExposureFailure,
+    config: &Config,
+) {
+    let severity = failure.severity(config);
+    if severity == Severity::Off {
+        return;
+    }
+    failures.push(ExposureFailureEntry { failure, severity });
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum ExposureChange {
-    Placeholder,
+    /// Widens an upstream model's `access` in its properties file so it clears a
+    /// `DependentOnPrivateModel` failure. Only ever produced when
+    /// `Config::allow_unsafe_exposure_fixes` is set -- see `exposure_dependent_on_private_model`.
+    SetModelAccess {
+        model_id: String,
+        model_name: String,
+        patch_path: Option<PathBuf>,
+        access: Access,
+    },
+    /// Changes an upstream model's `materialized` config so it clears a
+    /// `DependentOnMaterializedModel` failure.
+    SetModelMaterialization {
+        model_id: String,
+        model_name: String,
+        patch_path: Option<PathBuf>,
+        materialized: DbtMaterialization,
+    },
+}
+
+/// Converts a fix computed by `check_exposure` into the same [`ModelChange`] shape
+/// `check_model` produces, so it can be routed through the regular writeback path
+/// (`writeback::apply_model_changes`) instead of a bespoke one of its own.
+pub(crate) fn exposure_change_to_model_change(change: &ExposureChange) -> ModelChange {
+    match change {
+        ExposureChange::SetModelAccess {
+            model_id,
+            model_name,
+            patch_path,
+            access,
+        } => ModelChange::ChangePropertiesFile {
+            model_id: model_id.clone(),
+            model_name: model_name.clone(),
+            patch_path: patch_path.clone(),
+            property: Some(ModelProperty {
+                name: Some(model_name.clone()),
+                description: None,
+                columns: Vec::new(),
+                extras: config_extras("access", access_keyword(access)),
+            }),
+        },
+        ExposureChange::SetModelMaterialization {
+            model_id,
+            model_name,
+            patch_path,
+            materialized,
+        } => ModelChange::ChangePropertiesFile {
+            model_id: model_id.clone(),
+            model_name: model_name.clone(),
+            patch_path: patch_path.clone(),
+            property: Some(ModelProperty {
+                name: Some(model_name.clone()),
+                description: None,
+                columns: Vec::new(),
+                extras: config_extras("materialized", materialization_keyword(materialized)),
+            }),
+        },
+    }
+}
+
+/// The properties-file `config:` block keyword for `access`. Only `Public` is ever
+/// produced by this module; every other access level renders as `private`.
+fn access_keyword(access: &Access) -> &'static str {
+    match access {
+        Access::Public => "public",
+        _ => "private",
+    }
+}
+
+/// The properties-file `config:` block keyword for `materialized`. This module only ever
+/// fixes towards `Table` (the simpler of the two materializations
+/// `exposure_parents_materializations` accepts). `Incremental` renders as `incremental`;
+/// every other materialization renders as `view`.
+fn materialization_keyword(materialized: &DbtMaterialization) -> &'static str {
+    match materialized {
+        DbtMaterialization::Table => "table",
+        DbtMaterialization::Incremental => "incremental",
+        _ => "view",
+    }
+}
+
+/// Builds a `ModelProperty.extras` map holding a single-key `config:` block, e.g.
+/// `config_extras("access", "public")` renders as `config:\n  access: public`. `extras`
+/// is how non-struct-field properties-file keys (like `config`) flow through writeback
+/// (see `PropertyLevel::known_keys`), and merging a single key in is enough here since
+/// `ModelProperty::merge`/`merge_with_strategy` only ever touch keys actually present.
+fn config_extras(key: &str, value: &str) -> BTreeMap<String, dbt_serde_yaml::Value> {
+    dbt_serde_yaml::from_str(&format!("config:\n  {key}: {value}\n"))
+        .expect("a single scalar config key is always valid YAML")
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExposureResult {
     pub exposure_id: String,
-    pub failures: Vec<ExposureFailure>,
+    pub failures: Vec<ExposureFailureEntry>,
     pub changes: Vec<ExposureChange>,
 }
 
+impl ExposureResult {
+    pub fn is_pass(&self) -> bool {
+        self.failures.is_empty()
+    }
+
+    pub fn is_failure(&self) -> bool {
+        !self.is_pass()
+    }
+
+    /// The most severe `Severity` across this exposure's failures, or `None` if it
+    /// passed. Mirrors `SourceResult::max_severity`.
+    pub fn max_severity(&self) -> Option<Severity> {
+        self.failures.iter().map(|entry| entry.severity).min()
+    }
+
+    /// Whether this exposure has any failure severe enough to fail the run, i.e. not
+    /// suppressed down to `warn`/`info`. Mirrors `SourceResult::has_error_failures`.
+    pub fn has_error_failures(&self) -> bool {
+        self.max_severity() == Some(Severity::Error)
+    }
+}
+
 pub fn check_exposures(manifest: &DbtManifestV12, config: &Config) -> Vec<ExposureResult> {
     manifest
         .exposures
@@ -50,14 +237,24 @@ fn check_exposure(
     let mut changes = vec![];
 
     match exposure_dependent_on_private_model(exposure, manifest, config) {
-        Ok(Some(change)) => changes.push(change),
-        Err(failure) => failures.push(failure),
-        _ => {}
+        Ok(new_changes) => changes.extend(new_changes),
+        Err(failure) => record_failure(&mut failures, failure, config),
     }
     match exposure_parents_materializations(exposure, manifest, config) {
-        Ok(Some(change)) => changes.push(change),
-        Err(failure) => failures.push(failure),
-        _ => {}
+        Ok(new_changes) => changes.extend(new_changes),
+        Err(failure) => record_failure(&mut failures, failure, config),
+    }
+    match exposure_missing_description(exposure, config) {
+        Ok(new_changes) => changes.extend(new_changes),
+        Err(failure) => record_failure(&mut failures, failure, config),
+    }
+    match exposure_parents_staging(exposure, manifest, config) {
+        Ok(new_changes) => changes.extend(new_changes),
+        Err(failure) => record_failure(&mut failures, failure, config),
+    }
+    match exposure_missing_maturity_or_type(exposure, config) {
+        Ok(new_changes) => changes.extend(new_changes),
+        Err(failure) => record_failure(&mut failures, failure, config),
     }
 
     ExposureResult {
@@ -67,35 +264,54 @@ fn check_exposure(
     }
 }
 
-// possible unsafe fix, just make the models public?
+/// Possible unsafe fix, just make the models public? Widening `access` is the only fix
+/// that clears this failure, so it's gated behind `Config::allow_unsafe_exposure_fixes`
+/// on top of the usual `config.is_fixable` -- unlike a missing description, "models
+/// downstream of this one can now select from it" is a governance decision, not a
+/// mechanical one, so it shouldn't happen just because `--fix` was passed.
 fn exposure_dependent_on_private_model(
     exposure: &ManifestExposure,
     manifest: &DbtManifestV12,
     config: &Config,
-) -> Result<Option<ExposureChange>, ExposureFailure> {
+) -> Result<Vec<ExposureChange>, ExposureFailure> {
     if !config.is_selected(Selector::ExposureDependentOnPrivateModel) {
-        return Ok(None);
+        return Ok(Vec::new());
     }
 
     let depends_on = &exposure.__base_attr__.depends_on.nodes;
     // only models have access (to my knowledge)
     let nodes = depends_on.iter().filter(|node| node.starts_with("model"));
 
-    let private_models: Vec<String> = nodes
+    let private_models: Vec<(String, &ManifestModel)> = nodes
         .filter_map(|node_name| {
             let node = manifest.nodes.get(node_name)?;
             match node {
-                DbtNode::Model(model) => {
-                    (model.access == Some(Access::Private)).then_some(node_name.clone())
+                DbtNode::Model(model) if model.access == Some(Access::Private) => {
+                    Some((node_name.clone(), model))
                 }
                 _ => None,
             }
         })
         .collect();
-    if !private_models.is_empty() {
-        return Err(ExposureFailure::DependentOnPrivateModel(private_models));
+    if private_models.is_empty() {
+        return Ok(Vec::new());
     }
-    Ok(None)
+
+    if config.is_fixable(Selector::ExposureDependentOnPrivateModel) && config.allow_unsafe_exposure_fixes {
+        return Ok(private_models
+            .into_iter()
+            .map(|(model_id, model)| ExposureChange::SetModelAccess {
+                model_id: model_id.clone(),
+                model_name: model.__common_attr__.name.clone(),
+                patch_path: model.__common_attr__.patch_path.clone(),
+                access: Access::Public,
+            })
+            .collect());
+    }
+
+    Err(ExposureFailure::DependentOnPrivateModel(
+        private_models.into_iter().map(|(model_id, _)| model_id).collect(),
+    ))
 }
 
 /// https://dbt-labs.github.io/dbt-project-evaluator/latest/rules/performance/#exposure-parents-materializations
@@ -103,37 +319,140 @@ fn exposure_parents_materializations(
     exposure: &ManifestExposure,
     manifest: &DbtManifestV12,
     config: &Config,
-) -> Result<Option<ExposureChange>, ExposureFailure> {
+) -> Result<Vec<ExposureChange>, ExposureFailure> {
     if !config.is_selected(Selector::ExposureParentsMaterializations) {
-        return Ok(None);
+        return Ok(Vec::new());
     }
 
     let depends_on = &exposure.__base_attr__.depends_on.nodes;
     let nodes = depends_on.iter().filter(|node| node.starts_with("model"));
 
-    let materialized_parents: Vec<String> = nodes
+    let materialized_parents: Vec<(String, &ManifestModel)> = nodes
         .filter_map(|node_name| {
             let node = manifest.nodes.get(node_name)?;
             match node {
-                DbtNode::Model(model) => {
-                    // fail if materialized is not table or incremental
-                    match model.config.materialized {
-                        Some(DbtMaterialization::Table) | Some(DbtMaterialization::Incremental) => {
-                            None
-                        }
-                        _ => Some(node_name.clone()),
-                    }
+                // fail if materialized is not table or incremental
+                DbtNode::Model(model)
+                    if !matches!(
+                        model.config.materialized,
+                        Some(DbtMaterialization::Table) | Some(DbtMaterialization::Incremental)
+                    ) =>
+                {
+                    Some((node_name.clone(), model))
                 }
                 _ => None,
             }
         })
         .collect();
-    if !materialized_parents.is_empty() {
-        return Err(ExposureFailure::DependentOnMaterializedModel(
-            materialized_parents,
-        ));
+    if materialized_parents.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    if config.is_fixable(Selector::ExposureParentsMaterializations) {
+        // Promotes straight to `table`, the simpler of the two materializations that
+        // satisfy this check -- `incremental` needs a strategy/unique_key the model
+        // author has to choose, so it isn't something this fix can pick on its own.
+        return Ok(materialized_parents
+            .into_iter()
+            .map(|(model_id, model)| ExposureChange::SetModelMaterialization {
+                model_id: model_id.clone(),
+                model_name: model.__common_attr__.name.clone(),
+                patch_path: model.__common_attr__.patch_path.clone(),
+                materialized: DbtMaterialization::Table,
+            })
+            .collect());
+    }
+
+    Err(ExposureFailure::DependentOnMaterializedModel(
+        materialized_parents.into_iter().map(|(model_id, _)| model_id).collect(),
+    ))
+}
+
+/// An exposure has no description, or an empty one. Detection only -- there's no
+/// writeback path for an exposure's own properties file (only `ModelChange` exists), so
+/// unlike `missing_model_description` this can't offer a fix.
+fn exposure_missing_description(
+    exposure: &ManifestExposure,
+    config: &Config,
+) -> Result<Vec<ExposureChange>, ExposureFailure> {
+    if !config.is_selected(Selector::ExposureMissingDescription) {
+        return Ok(Vec::new());
+    }
+
+    let has_description = exposure
+        .__common_attr__
+        .description
+        .as_deref()
+        .is_some_and(|s| !s.trim().is_empty());
+
+    if has_description {
+        Ok(Vec::new())
+    } else {
+        Err(ExposureFailure::MissingDescription)
+    }
+}
+
+/// https://dbt-labs.github.io/dbt-project-evaluator/latest/rules/modeling/#exposure-parents-staging
+///
+/// An exposure should depend on marts, not staging/intermediate models or sources
+/// directly. Direct source dependencies are always a violation; model dependencies are
+/// judged with the same `config.layers`/`classify_layer` scheme
+/// `layer_direction_violation` uses, and skipped entirely if `layers` isn't configured.
+fn exposure_parents_staging(
+    exposure: &ManifestExposure,
+    manifest: &DbtManifestV12,
+    config: &Config,
+) -> Result<Vec<ExposureChange>, ExposureFailure> {
+    if !config.is_selected(Selector::ExposureParentsStaging) {
+        return Ok(Vec::new());
+    }
+
+    let depends_on = &exposure.__base_attr__.depends_on.nodes;
+
+    let mut offenders: Vec<String> = depends_on
+        .iter()
+        .filter(|id| id.starts_with("source."))
+        .cloned()
+        .collect();
+
+    if !config.layers.is_empty() {
+        let mart_layer = config.layers.len() - 1;
+        offenders.extend(
+            depends_on
+                .iter()
+                .filter(|id| id.starts_with("model."))
+                .filter_map(|id| {
+                    let DbtNode::Model(model) = manifest.nodes.get(id)? else {
+                        return None;
+                    };
+                    let layer = classify_layer(model, config)?;
+                    (layer != mart_layer).then(|| id.clone())
+                }),
+        );
+    }
+
+    if offenders.is_empty() {
+        Ok(Vec::new())
+    } else {
+        Err(ExposureFailure::DependsOnNonMartModel(offenders))
+    }
+}
+
+/// An exposure with no `maturity` or an empty `type` is missing metadata
+/// dbt-project-evaluator expects every exposure to declare.
+fn exposure_missing_maturity_or_type(
+    exposure: &ManifestExposure,
+    config: &Config,
+) -> Result<Vec<ExposureChange>, ExposureFailure> {
+    if !config.is_selected(Selector::ExposureMissingMaturityOrType) {
+        return Ok(Vec::new());
+    }
+
+    if exposure.maturity.is_none() || exposure.type_.trim().is_empty() {
+        Err(ExposureFailure::MissingMaturityOrType)
+    } else {
+        Ok(Vec::new())
     }
-    Ok(None)
 }
 
 #[cfg(test)]
@@ -217,6 +536,421 @@ mod tests {
 
         let res = exposure_dependent_on_private_model(&exposure, &manifest, &cfg);
         assert!(res.is_ok());
-        assert!(res.unwrap().is_none());
+        assert!(res.unwrap().is_empty());
+    }
+
+    #[test]
+    fn exposure_dependent_on_private_model_fixes_when_unsafe_fixes_allowed() {
+        let mut manifest = DbtManifestV12::default();
+
+        manifest.nodes.insert(
+            "model.test.upstream".to_string(),
+            DbtNode::Model(Default::default()),
+        );
+        if let Some(DbtNode::Model(upstream)) = manifest.nodes.get_mut("model.test.upstream") {
+            upstream.__common_attr__.unique_id = "model.test.upstream".to_string();
+            upstream.__common_attr__.name = "upstream".to_string();
+            upstream.access = Some(Access::Private);
+        }
+
+        let mut exposure = ManifestExposure {
+            __common_attr__: Default::default(),
+            __base_attr__: Default::default(),
+            owner: Default::default(),
+            label: None,
+            maturity: None,
+            type_: "user".to_string(),
+            url: None,
+            config: Default::default(),
+            __other__: Default::default(),
+        };
+        exposure.__common_attr__.unique_id = "exposure.test.dep".to_string();
+        exposure.__base_attr__.depends_on.nodes = vec!["model.test.upstream".to_string()];
+
+        let cfg = Config {
+            select: vec![Selector::ExposureDependentOnPrivateModel],
+            fixable: vec![Selector::ExposureDependentOnPrivateModel],
+            fix: true,
+            allow_unsafe_exposure_fixes: true,
+            ..Default::default()
+        };
+
+        let changes = exposure_dependent_on_private_model(&exposure, &manifest, &cfg)
+            .expect("fixable failure should turn into a change instead");
+        assert_eq!(changes.len(), 1);
+        match &changes[0] {
+            ExposureChange::SetModelAccess {
+                model_id,
+                model_name,
+                access,
+                ..
+            } => {
+                assert_eq!(model_id, "model.test.upstream");
+                assert_eq!(model_name, "upstream");
+                assert_eq!(*access, Access::Public);
+            }
+            other => panic!("expected SetModelAccess, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn exposure_dependent_on_private_model_does_not_fix_without_explicit_unsafe_flag() {
+        let mut manifest = DbtManifestV12::default();
+
+        manifest.nodes.insert(
+            "model.test.upstream".to_string(),
+            DbtNode::Model(Default::default()),
+        );
+        if let Some(DbtNode::Model(upstream)) = manifest.nodes.get_mut("model.test.upstream") {
+            upstream.__common_attr__.unique_id = "model.test.upstream".to_string();
+            upstream.access = Some(Access::Private);
+        }
+
+        let mut exposure = ManifestExposure {
+            __common_attr__: Default::default(),
+            __base_attr__: Default::default(),
+            owner: Default::default(),
+            label: None,
+            maturity: None,
+            type_: "user".to_string(),
+            url: None,
+            config: Default::default(),
+            __other__: Default::default(),
+        };
+        exposure.__common_attr__.unique_id = "exposure.test.dep".to_string();
+        exposure.__base_attr__.depends_on.nodes = vec!["model.test.upstream".to_string()];
+
+        // `fix` is on and the selector is fixable, but `allow_unsafe_exposure_fixes` isn't --
+        // access promotion must still fail, not fix.
+        let cfg = Config {
+            select: vec![Selector::ExposureDependentOnPrivateModel],
+            fixable: vec![Selector::ExposureDependentOnPrivateModel],
+            fix: true,
+            allow_unsafe_exposure_fixes: false,
+            ..Default::default()
+        };
+
+        let res = exposure_dependent_on_private_model(&exposure, &manifest, &cfg);
+        assert!(matches!(
+            res,
+            Err(ExposureFailure::DependentOnPrivateModel(_))
+        ));
+    }
+
+    #[test]
+    fn exposure_parents_materializations_fixes_to_table() {
+        let mut manifest = DbtManifestV12::default();
+
+        manifest.nodes.insert(
+            "model.test.upstream".to_string(),
+            DbtNode::Model(Default::default()),
+        );
+        if let Some(DbtNode::Model(upstream)) = manifest.nodes.get_mut("model.test.upstream") {
+            upstream.__common_attr__.unique_id = "model.test.upstream".to_string();
+            upstream.__common_attr__.name = "upstream".to_string();
+            upstream.config.materialized = Some(DbtMaterialization::View);
+        }
+
+        let mut exposure = ManifestExposure {
+            __common_attr__: Default::default(),
+            __base_attr__: Default::default(),
+            owner: Default::default(),
+            label: None,
+            maturity: None,
+            type_: "user".to_string(),
+            url: None,
+            config: Default::default(),
+            __other__: Default::default(),
+        };
+        exposure.__common_attr__.unique_id = "exposure.test.dep".to_string();
+        exposure.__base_attr__.depends_on.nodes = vec!["model.test.upstream".to_string()];
+
+        let cfg = Config {
+            select: vec![Selector::ExposureParentsMaterializations],
+            fixable: vec![Selector::ExposureParentsMaterializations],
+            fix: true,
+            ..Default::default()
+        };
+
+        let changes = exposure_parents_materializations(&exposure, &manifest, &cfg)
+            .expect("fixable failure should turn into a change instead");
+        assert_eq!(changes.len(), 1);
+        match &changes[0] {
+            ExposureChange::SetModelMaterialization {
+                model_id,
+                materialized,
+                ..
+            } => {
+                assert_eq!(model_id, "model.test.upstream");
+                assert!(matches!(materialized, DbtMaterialization::Table));
+            }
+            other => panic!("expected SetModelMaterialization, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn exposure_missing_description_detects_missing() {
+        let exposure = ManifestExposure {
+            __common_attr__: Default::default(),
+            __base_attr__: Default::default(),
+            owner: Default::default(),
+            label: None,
+            maturity: None,
+            type_: "user".to_string(),
+            url: None,
+            config: Default::default(),
+            __other__: Default::default(),
+        };
+
+        let cfg = Config {
+            select: vec![Selector::ExposureMissingDescription],
+            ..Default::default()
+        };
+
+        let res = exposure_missing_description(&exposure, &cfg);
+        assert!(matches!(res, Err(ExposureFailure::MissingDescription)));
+    }
+
+    #[test]
+    fn exposure_missing_description_passes_when_present() {
+        let mut exposure = ManifestExposure {
+            __common_attr__: Default::default(),
+            __base_attr__: Default::default(),
+            owner: Default::default(),
+            label: None,
+            maturity: None,
+            type_: "user".to_string(),
+            url: None,
+            config: Default::default(),
+            __other__: Default::default(),
+        };
+        exposure.__common_attr__.description = Some("What this exposure is for".to_string());
+
+        let cfg = Config {
+            select: vec![Selector::ExposureMissingDescription],
+            ..Default::default()
+        };
+
+        let res = exposure_missing_description(&exposure, &cfg);
+        assert!(res.is_ok());
+        assert!(res.unwrap().is_empty());
+    }
+
+    #[test]
+    fn exposure_parents_staging_detects_direct_source_dependency() {
+        let manifest = DbtManifestV12::default();
+
+        let mut exposure = ManifestExposure {
+            __common_attr__: Default::default(),
+            __base_attr__: Default::default(),
+            owner: Default::default(),
+            label: None,
+            maturity: None,
+            type_: "user".to_string(),
+            url: None,
+            config: Default::default(),
+            __other__: Default::default(),
+        };
+        exposure.__common_attr__.unique_id = "exposure.test.dep".to_string();
+        exposure.__base_attr__.depends_on.nodes = vec!["source.test.raw.orders".to_string()];
+
+        let cfg = Config {
+            select: vec![Selector::ExposureParentsStaging],
+            ..Default::default()
+        };
+
+        let res = exposure_parents_staging(&exposure, &manifest, &cfg);
+        assert!(res.is_err());
+        if let Err(ExposureFailure::DependsOnNonMartModel(nodes)) = res {
+            assert_eq!(nodes, vec!["source.test.raw.orders".to_string()]);
+        } else {
+            panic!("expected DependsOnNonMartModel failure");
+        }
+    }
+
+    #[test]
+    fn exposure_parents_staging_detects_staging_layer_model() {
+        let mut manifest = DbtManifestV12::default();
+
+        manifest.nodes.insert(
+            "model.test.stg_orders".to_string(),
+            DbtNode::Model(Default::default()),
+        );
+        if let Some(DbtNode::Model(upstream)) = manifest.nodes.get_mut("model.test.stg_orders") {
+            upstream.__common_attr__.unique_id = "model.test.stg_orders".to_string();
+            upstream.__common_attr__.name = "stg_orders".to_string();
+        }
+
+        let mut exposure = ManifestExposure {
+            __common_attr__: Default::default(),
+            __base_attr__: Default::default(),
+            owner: Default::default(),
+            label: None,
+            maturity: None,
+            type_: "user".to_string(),
+            url: None,
+            config: Default::default(),
+            __other__: Default::default(),
+        };
+        exposure.__common_attr__.unique_id = "exposure.test.dep".to_string();
+        exposure.__base_attr__.depends_on.nodes = vec!["model.test.stg_orders".to_string()];
+
+        let cfg = Config {
+            select: vec![Selector::ExposureParentsStaging],
+            layers: vec!["staging".to_string(), "marts".to_string()],
+            layer_patterns: std::collections::BTreeMap::from([
+                ("staging".to_string(), vec!["stg_".to_string()]),
+                ("marts".to_string(), vec!["fct_".to_string(), "dim_".to_string()]),
+            ]),
+            ..Default::default()
+        };
+
+        let res = exposure_parents_staging(&exposure, &manifest, &cfg);
+        assert!(res.is_err());
+        if let Err(ExposureFailure::DependsOnNonMartModel(nodes)) = res {
+            assert_eq!(nodes, vec!["model.test.stg_orders".to_string()]);
+        } else {
+            panic!("expected DependsOnNonMartModel failure");
+        }
+    }
+
+    #[test]
+    fn exposure_parents_staging_passes_for_mart_dependency() {
+        let mut manifest = DbtManifestV12::default();
+
+        manifest.nodes.insert(
+            "model.test.fct_orders".to_string(),
+            DbtNode::Model(Default::default()),
+        );
+        if let Some(DbtNode::Model(upstream)) = manifest.nodes.get_mut("model.test.fct_orders") {
+            upstream.__common_attr__.unique_id = "model.test.fct_orders".to_string();
+            upstream.__common_attr__.name = "fct_orders".to_string();
+        }
+
+        let mut exposure = ManifestExposure {
+            __common_attr__: Default::default(),
+            __base_attr__: Default::default(),
+            owner: Default::default(),
+            label: None,
+            maturity: None,
+            type_: "user".to_string(),
+            url: None,
+            config: Default::default(),
+            __other__: Default::default(),
+        };
+        exposure.__common_attr__.unique_id = "exposure.test.dep".to_string();
+        exposure.__base_attr__.depends_on.nodes = vec!["model.test.fct_orders".to_string()];
+
+        let cfg = Config {
+            select: vec![Selector::ExposureParentsStaging],
+            layers: vec!["staging".to_string(), "marts".to_string()],
+            layer_patterns: std::collections::BTreeMap::from([
+                ("staging".to_string(), vec!["stg_".to_string()]),
+                ("marts".to_string(), vec!["fct_".to_string(), "dim_".to_string()]),
+            ]),
+            ..Default::default()
+        };
+
+        let res = exposure_parents_staging(&exposure, &manifest, &cfg);
+        assert!(res.is_ok());
+        assert!(res.unwrap().is_empty());
+    }
+
+    #[test]
+    fn exposure_missing_maturity_or_type_detects_missing_maturity() {
+        let exposure = ManifestExposure {
+            __common_attr__: Default::default(),
+            __base_attr__: Default::default(),
+            owner: Default::default(),
+            label: None,
+            maturity: None,
+            type_: "user".to_string(),
+            url: None,
+            config: Default::default(),
+            __other__: Default::default(),
+        };
+
+        let cfg = Config {
+            select: vec![Selector::ExposureMissingMaturityOrType],
+            ..Default::default()
+        };
+
+        let res = exposure_missing_maturity_or_type(&exposure, &cfg);
+        assert!(matches!(res, Err(ExposureFailure::MissingMaturityOrType)));
+    }
+
+    #[test]
+    fn exposure_change_to_model_change_writes_the_config_block() {
+        let change = ExposureChange::SetModelAccess {
+            model_id: "model.test.upstream".to_string(),
+            model_name: "upstream".to_string(),
+            patch_path: None,
+            access: Access::Public,
+        };
+
+        match exposure_change_to_model_change(&change) {
+            ModelChange::ChangePropertiesFile {
+                model_name,
+                property: Some(property),
+                ..
+            } => {
+                assert_eq!(model_name, "upstream");
+                let rendered =
+                    dbt_serde_yaml::to_string(&property.extras).expect("extras should serialize");
+                assert!(rendered.contains("access: public"), "got: {rendered}");
+            }
+            other => panic!("expected ChangePropertiesFile with a property, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn check_exposure_warn_severity_does_not_count_as_error() {
+        let mut manifest = DbtManifestV12::default();
+
+        manifest.nodes.insert(
+            "model.test.upstream".to_string(),
+            DbtNode::Model(Default::default()),
+        );
+        if let Some(DbtNode::Model(upstream)) = manifest.nodes.get_mut("model.test.upstream") {
+            upstream.__common_attr__.unique_id = "model.test.upstream".to_string();
+            upstream.config.materialized = Some(DbtMaterialization::View);
+        }
+
+        let mut exposure = ManifestExposure {
+            __common_attr__: Default::default(),
+            __base_attr__: Default::default(),
+            owner: Default::default(),
+            label: None,
+            maturity: None,
+            type_: "user".to_string(),
+            url: None,
+            config: Default::default(),
+            __other__: Default::default(),
+        };
+        exposure.__common_attr__.unique_id = "exposure.test.dep".to_string();
+        exposure.__common_attr__.description = Some("What this exposure is for".to_string());
+        exposure.__base_attr__.depends_on.nodes = vec!["model.test.upstream".to_string()];
+        manifest
+            .exposures
+            .insert(exposure.__common_attr__.unique_id.clone(), exposure);
+
+        // `ExposureParentsMaterializations` defaults to `warn`, and every other check is
+        // deselected, so this exposure should report a failure but not count as an error.
+        let config = Config {
+            select: vec![Selector::ExposureParentsMaterializations],
+            ..Default::default()
+        };
+
+        let results = check_exposures(&manifest, &config);
+        let result = results
+            .iter()
+            .find(|r| r.exposure_id == "exposure.test.dep")
+            .expect("exposure should be checked");
+
+        assert!(result.failures.iter().any(|entry| {
+            matches!(entry.failure, ExposureFailure::DependentOnMaterializedModel(_))
+                && entry.severity == Severity::Warn
+        }));
+        assert!(!result.has_error_failures());
     }
 }
@@ -1,37 +1,49 @@
 use crate::config::Config;
+use crate::graph::{DbtGraph, Reachability};
+use crate::graph_cache;
+use crate::incremental::{IncrementalCache, dependency_hashes};
 use dbt_dag::deps_mgmt::topological_sort;
-use dbt_schemas::schemas::manifest::{DbtManifestV12, DbtNode};
+use dbt_schemas::schemas::manifest::{DbtManifestV12, DbtNode, ManifestSource};
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
 use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
 
 mod columns;
+mod cycles;
+mod docs;
 mod exposures;
 mod models;
 mod sources;
 
 use exposures::check_exposures;
-use models::check_model;
+use models::{StructuralChecks, check_model, structural_checks};
 use sources::check_source;
 
 pub use crate::change_descriptors::ColumnChange;
 pub use crate::change_descriptors::{ModelChange, ModelChanges};
-pub use columns::{ColumnFailure, ColumnResult};
+pub use columns::{ColumnFailure, ColumnResult, missing_description};
+pub use cycles::detect_circular_dependencies;
+pub use docs::{DocChange, DocFailure, DocResult, duplicate_docs};
 pub use exposures::{ExposureChange, ExposureFailure, ExposureResult};
-pub use models::{ModelFailure, ModelResult};
-pub use sources::{SourceFailure, SourceResult};
+pub use models::{BlameTrail, ModelFailure, ModelFailureEntry, ModelResult};
+pub use sources::{SourceFailure, SourceFailureEntry, SourceResult};
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct CheckResult {
     pub models: BTreeMap<String, ModelResult>,
     pub sources: BTreeMap<String, SourceResult>,
     pub exposures: BTreeMap<String, ExposureResult>,
+    pub docs: BTreeMap<String, DocResult>,
     pub model_changes: BTreeMap<String, ModelChanges>,
 }
 
 impl CheckResult {
     pub fn has_failures(&self) -> bool {
-        self.models.values().any(ModelResult::is_failure)
-            || self.sources.values().any(SourceResult::is_failure)
-            || self.exposures.values().any(|r| !r.failures.is_empty())
+        self.models.values().any(ModelResult::has_error_failures)
+            || self.sources.values().any(SourceResult::has_error_failures)
+            || self.exposures.values().any(ExposureResult::has_error_failures)
+            || self.docs.values().any(DocResult::is_failure)
     }
 
     pub fn model_failures(&self) -> impl Iterator<Item = &ModelResult> {
@@ -48,6 +60,7 @@ pub enum CheckEvent<'a> {
     Model(&'a ModelResult),
     Source(&'a SourceResult),
     Exposure(&'a ExposureResult),
+    Doc(&'a DocResult),
 }
 
 pub fn check_all(manifest: &DbtManifestV12, config: &Config) -> CheckResult {
@@ -63,15 +76,206 @@ where
     F: FnMut(CheckEvent<'_>),
 {
     let mut result = CheckResult::default();
-    let mut accumulated_changes: BTreeMap<String, ModelChanges> = BTreeMap::new();
+    // Seed with every source's own column descriptions so a model selecting directly
+    // from a source inherits them via `accumulated_changes` the same way it would an
+    // upstream model's fix, rather than relying solely on `osmosis`'s manifest fallback.
+    let mut accumulated_changes: BTreeMap<String, ModelChanges> = seed_source_changes(manifest, config);
+
+    // Built once per run: checks like `rejoining_of_upstream_concepts` query reachability
+    // for every model, so we precompute it instead of re-walking the graph each time.
+    let graph = DbtGraph::from(manifest);
+    let reachability = Reachability::build(&graph);
+    // Likewise, `circular_dependencies` needs to see the whole DAG rather than one
+    // model's local edges, so it's walked once here instead of per model.
+    let cycles = detect_circular_dependencies(manifest);
+    // A dedicated pool, rather than rayon's global one, so `config.parallelism` is an
+    // actual cap on concurrent work instead of just the on/off switch the `> 1` check
+    // below gates -- both the structural pass and the per-level `check_model` fan-out
+    // run inside it.
+    let pool = build_structural_pool(config);
+
+    // The 5 structural checks (fanout, direct-join-to-source, multiple-sources-joined,
+    // rejoining-of-upstream-concepts, missing-required-tests) never read
+    // `accumulated_changes`, so they're always safe to run in one flat `par_iter` pass
+    // up front. Still routed through `pool` so `config.parallelism` caps this fan-out
+    // too, rather than letting it spill onto rayon's global, uncapped pool.
+    let structural = pool.install(|| {
+        compute_structural(manifest, &graph, &reachability, &model_ids(manifest), config)
+    });
+
+    if config.parallelism > 1 {
+        for level in nodes_in_dag_levels(manifest) {
+            let model_ids: Vec<String> = level
+                .into_iter()
+                .filter(|node_id| should_lint_node(manifest, config, node_id))
+                .collect();
+
+            // Safe to read `accumulated_changes` from multiple threads here: the
+            // levelling invariant guarantees no two models in this level depend on
+            // each other, and nothing mutates the map until every model in the level
+            // has been checked below.
+            let level_results: Vec<ModelResult> = pool.install(|| {
+                model_ids
+                    .par_iter()
+                    .map(|node_id| {
+                        check_model(
+                            manifest,
+                            &graph,
+                            node_id,
+                            &accumulated_changes,
+                            &cycles,
+                            structural.get(node_id).expect("precomputed for every model"),
+                            config,
+                        )
+                    })
+                    .collect()
+            });
+
+            for model_result in level_results {
+                if let Some(changes) = model_result.changes() {
+                    accumulated_changes.insert(changes.model_id.clone(), changes.clone());
+                    result
+                        .model_changes
+                        .insert(changes.model_id.clone(), changes.clone());
+                }
+
+                reporter(CheckEvent::Model(&model_result));
+
+                let model_key = model_result.model_id().to_string();
+                result.models.insert(model_key, model_result);
+            }
+        }
+    } else {
+        for node_id in nodes_in_dag_order(manifest) {
+            if !should_lint_node(manifest, config, &node_id) {
+                continue;
+            }
+
+            let model_result = check_model(
+                manifest,
+                &graph,
+                &node_id,
+                &accumulated_changes,
+                &cycles,
+                structural.get(&node_id).expect("precomputed for every model"),
+                config,
+            );
+
+            if let Some(changes) = model_result.changes() {
+                accumulated_changes.insert(changes.model_id.clone(), changes.clone());
+                result
+                    .model_changes
+                    .insert(changes.model_id.clone(), changes.clone());
+            }
+
+            reporter(CheckEvent::Model(&model_result));
+
+            let model_key = model_result.model_id().to_string();
+            result.models.insert(model_key, model_result);
+        }
+    }
+
+    for source in manifest.sources.values() {
+        if !should_lint_source(config, source) {
+            continue;
+        }
+        let source_result = check_source(manifest, &graph, source, config);
+
+        reporter(CheckEvent::Source(&source_result));
+
+        let source_key = source_result.source_id().to_string();
+        result.sources.insert(source_key, source_result);
+    }
+
+    // run exposure checks
+    for exposure_result in check_exposures(manifest, config) {
+        reporter(CheckEvent::Exposure(&exposure_result));
+        record_exposure_changes(&mut result, &exposure_result);
+        let exposure_key = exposure_result.exposure_id.to_string();
+        result.exposures.insert(exposure_key, exposure_result);
+    }
+
+    for doc_result in duplicate_docs(manifest, config) {
+        reporter(CheckEvent::Doc(&doc_result));
+        let doc_key = doc_result.doc_name.clone();
+        result.docs.insert(doc_key, doc_result);
+    }
+
+    result
+}
+
+/// Routes the `ModelChange`s an exposure fix computed (see
+/// `exposures::exposure_change_to_model_change`) into `result.model_changes`, the same map
+/// `check_model`'s own fixes land in -- so `--fix` applies both through one
+/// `writeback::apply_model_changes` call, rather than exposures needing a writeback path
+/// of their own.
+fn record_exposure_changes(result: &mut CheckResult, exposure_result: &ExposureResult) {
+    for change in &exposure_result.changes {
+        let model_change = exposures::exposure_change_to_model_change(change);
+        let ModelChange::ChangePropertiesFile {
+            model_id,
+            patch_path,
+            ..
+        } = &model_change
+        else {
+            continue;
+        };
+
+        result
+            .model_changes
+            .entry(model_id.clone())
+            .or_insert_with(|| ModelChanges {
+                model_id: model_id.clone(),
+                patch_path: patch_path.clone(),
+                ..Default::default()
+            })
+            .changes
+            .push(model_change);
+    }
+}
+
+/// Like `check_all_with_report`, but loads `graph` from an on-disk cache at `cache_path`
+/// when one matching `manifest` already exists, instead of always rebuilding it from
+/// `manifest.child_map`. `reachability` is not cached; it is rebuilt from the loaded
+/// graph on every call. See [`crate::graph_cache`].
+pub fn check_all_with_graph_cache<F>(
+    manifest: &DbtManifestV12,
+    cache_path: &Path,
+    config: &Config,
+    mut reporter: F,
+) -> CheckResult
+where
+    F: FnMut(CheckEvent<'_>),
+{
+    let mut result = CheckResult::default();
+    // Seed with every source's own column descriptions so a model selecting directly
+    // from a source inherits them via `accumulated_changes` the same way it would an
+    // upstream model's fix, rather than relying solely on `osmosis`'s manifest fallback.
+    let mut accumulated_changes: BTreeMap<String, ModelChanges> = seed_source_changes(manifest, config);
     let sorted_nodes = nodes_in_dag_order(manifest);
 
+    let graph = graph_cache::load_or_build(manifest, cache_path);
+    let reachability = Reachability::build(&graph);
+    let cycles = detect_circular_dependencies(manifest);
+    let pool = build_structural_pool(config);
+    let structural = pool.install(|| {
+        compute_structural(manifest, &graph, &reachability, &model_ids(manifest), config)
+    });
+
     for node_id in sorted_nodes {
-        let Some(DbtNode::Model(_)) = manifest.nodes.get(&node_id) else {
+        if !should_lint_node(manifest, config, &node_id) {
             continue;
-        };
+        }
 
-        let model_result = check_model(manifest, &node_id, &accumulated_changes, config);
+        let model_result = check_model(
+            manifest,
+            &graph,
+            &node_id,
+            &accumulated_changes,
+            &cycles,
+            structural.get(&node_id).expect("precomputed for every model"),
+            config,
+        );
 
         if let Some(changes) = model_result.changes() {
             accumulated_changes.insert(changes.model_id.clone(), changes.clone());
@@ -87,7 +291,10 @@ where
     }
 
     for source in manifest.sources.values() {
-        let source_result = check_source(manifest, source, config);
+        if !should_lint_source(config, source) {
+            continue;
+        }
+        let source_result = check_source(manifest, &graph, source, config);
 
         reporter(CheckEvent::Source(&source_result));
 
@@ -95,20 +302,352 @@ where
         result.sources.insert(source_key, source_result);
     }
 
-    // run exposure checks
     for exposure_result in check_exposures(manifest, config) {
         reporter(CheckEvent::Exposure(&exposure_result));
+        record_exposure_changes(&mut result, &exposure_result);
         let exposure_key = exposure_result.exposure_id.to_string();
         result.exposures.insert(exposure_key, exposure_result);
     }
 
+    for doc_result in duplicate_docs(manifest, config) {
+        reporter(CheckEvent::Doc(&doc_result));
+        let doc_key = doc_result.doc_name.clone();
+        result.docs.insert(doc_key, doc_result);
+    }
+
     result
 }
 
-// TODO: this still feels a bit off because it doesn't have sources.
-fn nodes_in_dag_order(manifest: &DbtManifestV12) -> Vec<String> {
+/// Like `check_all_with_report`, but skips `check_model` for any model whose
+/// `dependency_hash` (own spec plus every upstream dependency's hash) is unchanged since
+/// the last run according to `cache` -- its cached failures are replayed instead. Models
+/// downstream of a deleted upstream dependency are always rechecked, since a missing
+/// dependency can change a check's outcome in ways the hash alone won't capture. Sources
+/// get the same treatment keyed on `incremental::source_fingerprint` instead of a
+/// `dependency_hash` (sources have no upstream `depends_on` of their own). Exposures
+/// aren't cached and always run in full. `cache` is updated in place with the fresh
+/// hash/fingerprint and failures for every model/source that was (re)checked, ready to be
+/// persisted by the caller.
+///
+/// Models skipped this way still have their cached `--fix` changes (if any) replayed into
+/// both `accumulated_changes` and `model_changes`, so a clean cache hit doesn't drop
+/// pending fixes.
+pub fn check_all_incremental<F>(
+    manifest: &DbtManifestV12,
+    config: &Config,
+    cache: &mut IncrementalCache,
+    mut reporter: F,
+) -> CheckResult
+where
+    F: FnMut(CheckEvent<'_>),
+{
+    let mut result = CheckResult::default();
+    // Seed with every source's own column descriptions so a model selecting directly
+    // from a source inherits them via `accumulated_changes` the same way it would an
+    // upstream model's fix, rather than relying solely on `osmosis`'s manifest fallback.
+    let mut accumulated_changes: BTreeMap<String, ModelChanges> = seed_source_changes(manifest, config);
+
+    let graph = DbtGraph::from(manifest);
+    let reachability = Reachability::build(&graph);
+    let cycles = detect_circular_dependencies(manifest);
+
+    cache.prune(manifest);
+    let (hashes, force_dirty) = dependency_hashes(manifest, &graph, config);
+    let plan = cache.plan(manifest, &hashes, &force_dirty);
+
+    for model_id in &plan.clean {
+        let changes = cache.cached_changes(model_id).cloned();
+        result.models.insert(
+            model_id.clone(),
+            ModelResult {
+                model_id: model_id.clone(),
+                failures: cache.cached_failures(model_id).to_vec(),
+                changes: changes.clone(),
+                ..Default::default()
+            },
+        );
+        // So a dirty downstream model's inherited-description lookup still sees a
+        // clean upstream model's previously-computed fix, instead of treating it as
+        // if the fix had never happened.
+        if let Some(changes) = changes {
+            accumulated_changes.insert(model_id.clone(), changes.clone());
+            result.model_changes.insert(model_id.clone(), changes);
+        }
+    }
+
+    recheck_models(
+        manifest,
+        &graph,
+        &reachability,
+        &plan.dirty,
+        &mut accumulated_changes,
+        &cycles,
+        config,
+        &mut result,
+    );
+
+    for model_id in &plan.dirty {
+        if let (Some(hash), Some(model_result)) = (hashes.get(model_id), result.models.get(model_id)) {
+            cache.record(
+                model_id.clone(),
+                hash.clone(),
+                model_result.failures.clone(),
+                model_result.changes.clone(),
+            );
+        }
+    }
+
+    // Report in the project's overall dag order, same as `check_all_with_report`,
+    // regardless of which models were skipped vs rechecked.
+    for node_id in nodes_in_dag_order(manifest) {
+        if let Some(model_result) = result.models.get(&node_id) {
+            reporter(CheckEvent::Model(model_result));
+        }
+    }
+
+    // Mirrors the model-side clean/dirty split above: a source whose fingerprint
+    // (own spec + cross-source state + config, see `incremental::source_fingerprint`)
+    // is unchanged reuses its cached `SourceResult` instead of re-running `check_source`.
+    let source_plan = cache.plan_sources(manifest, config);
+
+    for source in manifest.sources.values() {
+        if !should_lint_source(config, source) {
+            continue;
+        }
+        let source_id = source.__common_attr__.unique_id.clone();
+
+        let source_result = if source_plan.dirty.contains(&source_id) {
+            let source_result = check_source(manifest, &graph, source, config);
+            if let Some(fingerprint) = source_plan.fingerprints.get(&source_id) {
+                cache.record_source(
+                    source_id.clone(),
+                    fingerprint.clone(),
+                    source_result.failures.clone(),
+                    source_result.changes.clone(),
+                );
+            }
+            source_result
+        } else {
+            SourceResult {
+                source_id: source_id.clone(),
+                failures: cache.cached_source_failures(&source_id).to_vec(),
+                changes: cache.cached_source_changes(&source_id).cloned(),
+            }
+        };
+
+        reporter(CheckEvent::Source(&source_result));
+        result.sources.insert(source_id, source_result);
+    }
+
+    for exposure_result in check_exposures(manifest, config) {
+        reporter(CheckEvent::Exposure(&exposure_result));
+        record_exposure_changes(&mut result, &exposure_result);
+        let exposure_key = exposure_result.exposure_id.to_string();
+        result.exposures.insert(exposure_key, exposure_result);
+    }
+
+    for doc_result in duplicate_docs(manifest, config) {
+        reporter(CheckEvent::Doc(&doc_result));
+        let doc_key = doc_result.doc_name.clone();
+        result.docs.insert(doc_key, doc_result);
+    }
+
+    result
+}
+
+/// Re-run `check_model` for exactly `model_ids`, splicing the results into `result` in
+/// place. Unlike `check_all_with_report`, this doesn't rebuild `graph`/`reachability`/
+/// `cycles` or revisit every model in the project — callers (e.g. watch mode) are
+/// expected to keep those, plus `accumulated_changes`, around across calls and pass in
+/// just the set of models invalidated by a file change (typically the changed model
+/// plus its graph-downstream dependents, since column inheritance and fanout/dead-model
+/// checks can flip based on an upstream model).
+///
+/// `model_ids` is visited in the project's overall dag order so that, when a change
+/// invalidates both a model and its dependent, the upstream one is rechecked first and
+/// `accumulated_changes` reflects it before the dependent is checked.
+pub fn recheck_models(
+    manifest: &DbtManifestV12,
+    graph: &DbtGraph,
+    reachability: &Reachability,
+    model_ids: &BTreeSet<String>,
+    accumulated_changes: &mut BTreeMap<String, ModelChanges>,
+    cycles: &BTreeMap<String, Vec<String>>,
+    config: &Config,
+    result: &mut CheckResult,
+) {
+    let pool = build_structural_pool(config);
+    let structural = pool.install(|| {
+        compute_structural(
+            manifest,
+            graph,
+            reachability,
+            &model_ids.iter().cloned().collect::<Vec<_>>(),
+            config,
+        )
+    });
+
+    for node_id in nodes_in_dag_order(manifest) {
+        if !model_ids.contains(&node_id) {
+            continue;
+        }
+        if !should_lint_node(manifest, config, &node_id) {
+            continue;
+        }
+
+        let model_result = check_model(
+            manifest,
+            graph,
+            &node_id,
+            accumulated_changes,
+            cycles,
+            structural.get(&node_id).expect("precomputed for every model"),
+            config,
+        );
+
+        match model_result.changes() {
+            Some(changes) => {
+                accumulated_changes.insert(changes.model_id.clone(), changes.clone());
+                result
+                    .model_changes
+                    .insert(changes.model_id.clone(), changes.clone());
+            }
+            None => {
+                accumulated_changes.remove(&node_id);
+                result.model_changes.remove(&node_id);
+            }
+        }
+
+        result
+            .models
+            .insert(model_result.model_id().to_string(), model_result);
+    }
+}
+
+/// A dedicated pool, capped at `config.parallelism`, for running [`compute_structural`]
+/// (and, in `check_all_with_report`, the per-level `check_model` fan-out) instead of
+/// rayon's global, uncapped one -- so the knob means what its doc comment says
+/// regardless of which parallel pass it's gating.
+fn build_structural_pool(config: &Config) -> rayon::ThreadPool {
+    rayon::ThreadPoolBuilder::new()
+        .num_threads(config.parallelism.max(1))
+        .build()
+        .expect("failed to build check::mod's rayon thread pool")
+}
+
+/// Precompute [`StructuralChecks`] for `model_ids` in one flat, unordered `par_iter`
+/// pass. Unlike the rest of `check_model`, these checks only read `manifest`/`graph`/
+/// `reachability`/`config` -- never `accumulated_changes` -- so they don't need to wait
+/// on DAG order between models. Callers run this inside a pool built by
+/// [`build_structural_pool`] so `config.parallelism` caps this fan-out the same way it
+/// caps the column-inheritance pass below, rather than this spilling onto rayon's
+/// global, uncapped pool.
+fn compute_structural(
+    manifest: &DbtManifestV12,
+    graph: &DbtGraph,
+    reachability: &Reachability,
+    model_ids: &[String],
+    config: &Config,
+) -> BTreeMap<String, StructuralChecks> {
+    model_ids
+        .par_iter()
+        .map(|model_id| {
+            (
+                model_id.clone(),
+                structural_checks(manifest, graph, reachability, model_id, config),
+            )
+        })
+        .collect()
+}
+
+/// Whether `node_id` is a model *and* passes `Config::should_lint_path` -- the combined
+/// gate every model-checking loop above runs its node ids through before calling
+/// `check_model`, so `include`/`exclude_paths` scope the project the same way an
+/// unselected `Selector` scopes the rule set.
+fn should_lint_node(manifest: &DbtManifestV12, config: &Config, node_id: &str) -> bool {
+    match manifest.nodes.get(node_id) {
+        Some(DbtNode::Model(model)) => {
+            config.should_lint_path(&model.__common_attr__.original_file_path)
+        }
+        _ => false,
+    }
+}
+
+/// Like `should_lint_node`, for a source -- scoped by its properties file (`patch_path`)
+/// since a source has no `.sql` file of its own. A source with no `patch_path` yet (not
+/// documented anywhere) is always linted; there's no path for `include`/`exclude_paths`
+/// to match against.
+fn should_lint_source(config: &Config, source: &ManifestSource) -> bool {
+    match &source.__common_attr__.patch_path {
+        Some(patch_path) => config.should_lint_path(patch_path),
+        None => true,
+    }
+}
+
+fn model_ids(manifest: &DbtManifestV12) -> Vec<String> {
+    manifest
+        .nodes
+        .iter()
+        .filter(|(_, node)| matches!(node, DbtNode::Model(_)))
+        .map(|(node_id, _)| node_id.clone())
+        .collect()
+}
+
+/// Seed `accumulated_changes` with every source's own column descriptions before the
+/// model loop runs, keyed by source id the same way a model's entry would be. A source
+/// never goes through `check_model`, so without this a model selecting directly from one
+/// could only inherit its column descriptions via `osmosis`'s `manifest.sources` fallback
+/// -- this makes that inheritance visible in `accumulated_changes` too, with `model_id`
+/// holding the source's own unique id so it's distinguishable from an inherited model fix.
+fn seed_source_changes(manifest: &DbtManifestV12, config: &Config) -> BTreeMap<String, ModelChanges> {
+    let mut seeded = BTreeMap::new();
+
+    for (source_id, source) in &manifest.sources {
+        let mut column_changes: BTreeMap<String, BTreeSet<ColumnChange>> = BTreeMap::new();
+        for column in &source.columns {
+            if missing_description(column, config).is_err() {
+                continue;
+            }
+            column_changes.insert(
+                column.as_ref().name.clone(),
+                BTreeSet::from([ColumnChange::DescriptionChanged {
+                    model_id: source_id.clone(),
+                    model_name: source.__common_attr__.name.clone(),
+                    patch_path: source.__common_attr__.patch_path.clone(),
+                    column_name: column.as_ref().name.clone(),
+                    old: None,
+                    new: column.as_ref().description.clone(),
+                }]),
+            );
+        }
+
+        if column_changes.is_empty() {
+            continue;
+        }
+
+        seeded.insert(
+            source_id.clone(),
+            ModelChanges {
+                model_id: source_id.clone(),
+                column_changes,
+                ..Default::default()
+            },
+        );
+    }
+
+    seeded
+}
+
+/// `manifest.nodes`' dependency edges, plus every source as a dependency-free root so a
+/// model selecting directly from a source keeps that edge instead of silently dropping
+/// it (a source never appears in `manifest.nodes`, only `manifest.sources`).
+pub(crate) fn dag_deps(manifest: &DbtManifestV12) -> BTreeMap<String, BTreeSet<String>> {
     let mut deps: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
 
+    for source_id in manifest.sources.keys() {
+        deps.insert(source_id.clone(), BTreeSet::new());
+    }
+
     for (node_id, node) in &manifest.nodes {
         let upstream_nodes = match node {
             DbtNode::Model(model) => Some(&model.__base_attr__.depends_on.nodes),
@@ -122,15 +661,16 @@ fn nodes_in_dag_order(manifest: &DbtManifestV12) -> Vec<String> {
             let upstream = upstream_nodes
                 .iter()
                 .filter(|upstream_id| {
-                    matches!(
-                        manifest.nodes.get(*upstream_id),
-                        Some(
-                            DbtNode::Model(_)
-                                | DbtNode::Seed(_)
-                                | DbtNode::Snapshot(_)
-                                | DbtNode::Analysis(_)
+                    manifest.sources.contains_key(*upstream_id)
+                        || matches!(
+                            manifest.nodes.get(*upstream_id),
+                            Some(
+                                DbtNode::Model(_)
+                                    | DbtNode::Seed(_)
+                                    | DbtNode::Snapshot(_)
+                                    | DbtNode::Analysis(_)
+                            )
                         )
-                    )
                 })
                 .cloned()
                 .collect::<BTreeSet<_>>();
@@ -139,7 +679,45 @@ fn nodes_in_dag_order(manifest: &DbtManifestV12) -> Vec<String> {
         }
     }
 
-    topological_sort(&deps)
+    deps
+}
+
+pub(crate) fn nodes_in_dag_order(manifest: &DbtManifestV12) -> Vec<String> {
+    topological_sort(&dag_deps(manifest))
+}
+
+/// Groups `nodes_in_dag_order`'s nodes into topological levels (Kahn layering): level 0
+/// is every node with no in-DAG upstream dependency, level 1 is every node whose
+/// upstream deps are all in level 0, and so on. No two nodes in the same level depend
+/// on each other, so `check_all_with_report`'s parallel path can check a whole level
+/// concurrently and only needs to merge `accumulated_changes` between levels.
+pub(crate) fn nodes_in_dag_levels(manifest: &DbtManifestV12) -> Vec<Vec<String>> {
+    let mut remaining = dag_deps(manifest);
+    let mut emitted: BTreeSet<String> = BTreeSet::new();
+    let mut levels = Vec::new();
+
+    while !remaining.is_empty() {
+        let level: Vec<String> = remaining
+            .iter()
+            .filter(|(_, upstream)| upstream.is_subset(&emitted))
+            .map(|(node_id, _)| node_id.clone())
+            .collect();
+
+        if level.is_empty() {
+            // A cycle shouldn't occur in a valid dbt DAG, but emit whatever's left as a
+            // single level rather than looping forever.
+            levels.push(remaining.keys().cloned().collect());
+            break;
+        }
+
+        for node_id in &level {
+            remaining.remove(node_id);
+        }
+        emitted.extend(level.iter().cloned());
+        levels.push(level);
+    }
+
+    levels
 }
 
 #[cfg(test)]
@@ -220,4 +798,200 @@ mod tests {
             "column change should be present"
         );
     }
+
+    #[allow(clippy::field_reassign_with_default)]
+    fn manifest_with_inheritable_column_from_source() -> DbtManifestV12 {
+        use dbt_schemas::schemas::manifest::ManifestSource;
+
+        let mut manifest = DbtManifestV12::default();
+
+        let mut source = ManifestSource::default();
+        source.__common_attr__.unique_id = "source.test.raw.customers".to_string();
+        source.columns.push(Arc::new(DbtColumn {
+            name: "customer_id".to_string(),
+            description: Some("Source description".to_string()),
+            ..Default::default()
+        }));
+        manifest
+            .sources
+            .insert(source.__common_attr__.unique_id.clone(), source);
+
+        manifest.nodes.insert(
+            "model.test.downstream".to_string(),
+            DbtNode::Model(Default::default()),
+        );
+        if let Some(DbtNode::Model(downstream)) = manifest.nodes.get_mut("model.test.downstream") {
+            downstream.__common_attr__.unique_id = "model.test.downstream".to_string();
+            downstream.__base_attr__.depends_on.nodes = vec!["source.test.raw.customers".to_string()];
+            downstream.__base_attr__.columns.push(Arc::new(DbtColumn {
+                name: "customer_id".to_string(),
+                description: None,
+                ..Default::default()
+            }));
+        }
+
+        manifest.child_map.insert(
+            "source.test.raw.customers".to_string(),
+            vec!["model.test.downstream".to_string()],
+        );
+
+        manifest
+    }
+
+    #[test]
+    fn seed_source_changes_attributes_to_the_source() {
+        let manifest = manifest_with_inheritable_column_from_source();
+        let config = Config {
+            select: vec![Selector::MissingColumnDescriptions],
+            ..Default::default()
+        };
+
+        let seeded = seed_source_changes(&manifest, &config);
+
+        let change = seeded
+            .get("source.test.raw.customers")
+            .and_then(|changes| changes.column_changes.get("customer_id"))
+            .and_then(|changes| changes.iter().next())
+            .expect("source column description should be seeded");
+        assert!(matches!(
+            change,
+            ColumnChange::DescriptionChanged {
+                model_id,
+                new: Some(desc),
+                ..
+            } if desc == "Source description" && model_id == "source.test.raw.customers"
+        ));
+    }
+
+    #[test]
+    fn check_all_inherits_column_description_from_source() {
+        let manifest = manifest_with_inheritable_column_from_source();
+        let config = Config {
+            select: vec![Selector::MissingColumnDescriptions],
+            ..Default::default()
+        }
+        .with_fix(true);
+
+        let result = check_all(&manifest, &config);
+
+        let model_result = result
+            .models
+            .get("model.test.downstream")
+            .expect("model result should be tracked");
+        assert!(
+            model_result.is_pass(),
+            "downstream model should inherit the source's column description"
+        );
+        assert!(
+            result
+                .model_changes
+                .get("model.test.downstream")
+                .expect("changes should be recorded")
+                .column_changes
+                .contains_key("customer_id"),
+            "column change should be present"
+        );
+    }
+
+    #[test]
+    fn nodes_in_dag_levels_separates_dependent_models() {
+        let manifest = manifest_with_inheritable_column();
+
+        let levels = nodes_in_dag_levels(&manifest);
+
+        assert_eq!(levels.len(), 2, "upstream then downstream");
+        assert_eq!(levels[0], vec!["model.test.upstream".to_string()]);
+        assert_eq!(levels[1], vec!["model.test.downstream".to_string()]);
+    }
+
+    #[test]
+    fn nodes_in_dag_levels_puts_unrelated_models_in_the_same_level() {
+        let mut manifest = DbtManifestV12::default();
+        manifest.nodes.insert(
+            "model.test.a".to_string(),
+            DbtNode::Model(Default::default()),
+        );
+        manifest.nodes.insert(
+            "model.test.b".to_string(),
+            DbtNode::Model(Default::default()),
+        );
+
+        let levels = nodes_in_dag_levels(&manifest);
+
+        assert_eq!(levels.len(), 1, "unrelated models share the first level");
+        assert_eq!(levels[0].len(), 2);
+    }
+
+    #[test]
+    fn check_all_with_report_parallel_matches_serial_output() {
+        let manifest = manifest_with_inheritable_column();
+        let base_config = Config {
+            select: vec![
+                Selector::MissingModelDescriptions,
+                Selector::MissingColumnDescriptions,
+            ],
+            ..Default::default()
+        }
+        .with_fix(true);
+
+        let serial_result = check_all_with_report(&manifest, &base_config, |_| {});
+
+        let parallel_config = Config {
+            parallelism: 4,
+            ..base_config
+        };
+        let parallel_result = check_all_with_report(&manifest, &parallel_config, |_| {});
+
+        assert_eq!(
+            serial_result.model_changes.keys().collect::<Vec<_>>(),
+            parallel_result.model_changes.keys().collect::<Vec<_>>(),
+            "level-parallel execution finds the same models to fix"
+        );
+        assert_eq!(
+            serial_result
+                .models
+                .get("model.test.downstream")
+                .unwrap()
+                .is_pass(),
+            parallel_result
+                .models
+                .get("model.test.downstream")
+                .unwrap()
+                .is_pass(),
+            "downstream fix still propagates across levels"
+        );
+    }
+
+    #[test]
+    fn build_structural_pool_caps_at_configured_parallelism() {
+        let config = Config {
+            parallelism: 2,
+            ..Default::default()
+        };
+
+        let pool = build_structural_pool(&config);
+
+        assert_eq!(
+            pool.current_num_threads(),
+            2,
+            "compute_structural's pool should be capped at config.parallelism, not \
+             rayon's global, uncapped pool"
+        );
+    }
+
+    #[test]
+    fn build_structural_pool_never_builds_a_zero_thread_pool() {
+        let config = Config {
+            parallelism: 0,
+            ..Default::default()
+        };
+
+        let pool = build_structural_pool(&config);
+
+        assert_eq!(
+            pool.current_num_threads(),
+            1,
+            "a misconfigured parallelism of 0 should still run, not deadlock"
+        );
+    }
 }
@@ -1,21 +1,23 @@
 use super::columns::ColumnResult;
 use crate::change_descriptors::{ColumnChange, ModelChange, ModelChanges};
 use crate::codegen::write_generated_model;
+use crate::ownership::{BlameCache, Ownership};
 use crate::{
     check::columns::check_model_columns,
-    config::{Config, Selector},
-    graph::DbtGraph,
-    writeback::properties::model_property_from_manifest_differences,
+    config::{Config, ModelScope, Selector, Severity},
+    graph::{DbtGraph, Reachability},
+    writeback::properties::{ColumnProperty, ModelProperty, model_property_from_manifest_differences},
 };
 use dbt_schemas::schemas::manifest::{DbtManifestV12, DbtNode, ManifestModel};
-use petgraph::algo::has_path_connecting;
 use std::collections::{BTreeMap, BTreeSet};
 use std::fmt::Display;
 use std::path::PathBuf;
 
+use serde::{Deserialize, Serialize};
 use strum::AsRefStr;
 
-#[derive(Debug, Clone, AsRefStr, PartialEq, Eq)]
+#[derive(Debug, Clone, AsRefStr, PartialEq, Eq, Serialize, Deserialize)]
+#[strum(serialize_all = "snake_case")]
 pub enum ModelFailure {
     DescriptionMissing,
     TagsMissing(Vec<String>),
@@ -33,6 +35,25 @@ pub enum ModelFailure {
         patch_path: PathBuf,
         original_file_path: PathBuf,
     },
+    LayerDirectionViolation(Vec<LayerViolation>),
+    /// This model participates in a dependency cycle; the sorted, deduplicated member
+    /// ids of the whole cycle, including this model itself. See
+    /// `check::cycles::detect_circular_dependencies`.
+    CircularDependency(Vec<String>),
+}
+
+/// A single upstream dependency that sits in a layer downstream of the model depending
+/// on it (e.g. a `marts` model depended on by a `staging` one).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LayerViolation {
+    pub upstream_id: String,
+    pub upstream_layer: String,
+}
+
+impl Display for LayerViolation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({})", self.upstream_id, self.upstream_layer)
+    }
 }
 
 impl Display for ModelFailure {
@@ -59,18 +80,269 @@ impl Display for ModelFailure {
                     original_file_path.display()
                 )
             }
+            ModelFailure::LayerDirectionViolation(violations) => {
+                let joined = violations
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                format!(" (upstream: {joined})")
+            }
+            ModelFailure::CircularDependency(members) => {
+                format!(" (cycle: {})", members.join(" -> "))
+            }
             _ => String::new(),
         };
         write!(f, "{}{}", self.as_ref(), extra_info)
     }
 }
 
+impl ModelFailure {
+    /// A stable identity for baseline comparison, distinct from `Display`'s
+    /// human-readable form. Fields that naturally drift between manifest
+    /// regenerations (e.g. a fanout count) are excluded so a baselined
+    /// failure isn't re-flagged just because it grew.
+    pub fn fingerprint(&self) -> String {
+        let detail = match self {
+            ModelFailure::TagsMissing(tags) => tags.join(","),
+            ModelFailure::DirectJoinToSource(sources) => sources.join(","),
+            ModelFailure::MissingRequiredTests(tests) => tests.join(","),
+            ModelFailure::MultipleSourcesJoined(sources) => sources.join(","),
+            ModelFailure::RejoiningOfUpstreamConcepts(concepts) => concepts.join(","),
+            ModelFailure::ModelSeparateFromPropertiesFile {
+                patch_path,
+                original_file_path,
+            } => format!("{}:{}", patch_path.display(), original_file_path.display()),
+            ModelFailure::LayerDirectionViolation(violations) => violations
+                .iter()
+                .map(|v| v.upstream_id.as_str())
+                .collect::<Vec<_>>()
+                .join(","),
+            ModelFailure::CircularDependency(members) => members.join(","),
+            // ModelFanout(usize) and the unit variants fingerprint on the
+            // variant name alone, so a fanout growing from 6 to 7 models
+            // isn't treated as a newly introduced failure.
+            _ => String::new(),
+        };
+        format!("{}:{}", self.as_ref(), detail)
+    }
+}
+
+impl ModelFailure {
+    /// The `Selector` this failure is gated behind, used to resolve its configured
+    /// severity. `None` for failures not driven by a selector (required tests are
+    /// gated on `Config::required_tests` being non-empty, not a `Selector`).
+    pub fn selector(&self) -> Option<Selector> {
+        match self {
+            ModelFailure::DescriptionMissing => Some(Selector::MissingModelDescriptions),
+            ModelFailure::TagsMissing(_) => Some(Selector::MissingModelTags),
+            ModelFailure::DirectJoinToSource(_) => Some(Selector::DirectJoinToSource),
+            ModelFailure::MissingPropertiesFile => Some(Selector::MissingPropertiesFile),
+            ModelFailure::ModelFanout(_) => Some(Selector::ModelFanout),
+            ModelFailure::MissingRequiredTests(_) => None,
+            ModelFailure::RootModel => Some(Selector::RootModels),
+            ModelFailure::MissingPrimaryKey => Some(Selector::MissingPrimaryKey),
+            ModelFailure::MultipleSourcesJoined(_) => Some(Selector::MultipleSourcesJoined),
+            ModelFailure::RejoiningOfUpstreamConcepts(_) => {
+                Some(Selector::RejoiningOfUpstreamConcepts)
+            }
+            ModelFailure::PublicModelWithoutContract => {
+                Some(Selector::PublicModelsWithoutContract)
+            }
+            ModelFailure::DeadModel => Some(Selector::DeadModel),
+            ModelFailure::ModelSeparateFromPropertiesFile { .. } => {
+                Some(Selector::ModelsSeparateFromPropertiesFile)
+            }
+            ModelFailure::LayerDirectionViolation(_) => Some(Selector::LayerDirectionViolation),
+            ModelFailure::CircularDependency(_) => Some(Selector::CircularDependencies),
+        }
+    }
+
+    /// The effective severity of this failure under `config`, for `model` -- accounting
+    /// for any `SeverityOverride` scoped to `model` by path, tag, or fqn prefix.
+    pub fn severity(&self, config: &Config, model: &ManifestModel) -> Severity {
+        let scope = ModelScope {
+            path: &model.__common_attr__.original_file_path,
+            tags: model.config.tags.as_deref().unwrap_or(&[]),
+            unique_id: &model.__common_attr__.unique_id,
+        };
+        self.selector()
+            .map(|selector| config.severity_for_model(selector, scope))
+            .unwrap_or(Severity::Error)
+    }
+}
+
+/// One hop of a "blame trail": an upstream/downstream node responsible for a
+/// graph-derived finding, and the shortest path connecting it to the model under lint.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BlameTrail {
+    pub node_id: String,
+    pub path: Vec<String>,
+}
+
+impl Display for BlameTrail {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} (via {})", self.node_id, self.path.join(" -> "))
+    }
+}
+
+/// A `ModelFailure` together with its resolved severity and, for graph-derived
+/// checks, the blame trail explaining why it fired.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModelFailureEntry {
+    pub failure: ModelFailure,
+    pub severity: Severity,
+    #[serde(default)]
+    pub blame: Vec<BlameTrail>,
+    /// Who last touched the file this failure was raised against, per `Config::blame`.
+    /// Always empty when blame attribution is off (the default).
+    #[serde(default)]
+    pub owners: Vec<Ownership>,
+}
+
+impl ModelFailureEntry {
+    pub fn is_error(&self) -> bool {
+        self.severity == Severity::Error
+    }
+}
+
+impl Display for ModelFailureEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{}] {}", self.severity.as_ref(), self.failure)?;
+        for trail in &self.blame {
+            write!(f, "; {trail}")?;
+        }
+        for owner in &self.owners {
+            write!(f, "; owner: {owner}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Build the blame trail for a graph-derived failure: the specific node(s) and the
+/// shortest path through `DbtGraph` that explain why the model was flagged. Failures
+/// that aren't graph-derived (e.g. a missing description) have no blame trail.
+fn blame_for(
+    failure: &ModelFailure,
+    model_id: &str,
+    model: &ManifestModel,
+    graph: &DbtGraph,
+) -> Vec<BlameTrail> {
+    match failure {
+        ModelFailure::DirectJoinToSource(sources) | ModelFailure::MultipleSourcesJoined(sources) => {
+            sources
+                .iter()
+                .filter_map(|source_id| {
+                    graph.shortest_path(source_id, model_id).map(|path| BlameTrail {
+                        node_id: source_id.clone(),
+                        path,
+                    })
+                })
+                .collect()
+        }
+        ModelFailure::RejoiningOfUpstreamConcepts(concepts) => {
+            let deps = &model.__base_attr__.depends_on.nodes;
+            concepts
+                .iter()
+                .filter_map(|concept_id| {
+                    // `concept_id` is rejoined because it transitively reaches some other
+                    // direct dependency `p` of this model; recover that `p` and the path to it.
+                    let rejoined_via = deps
+                        .iter()
+                        .find(|p| *p != concept_id && graph.shortest_path(concept_id, p).is_some())?;
+                    graph
+                        .shortest_path(concept_id, rejoined_via)
+                        .map(|path| BlameTrail {
+                            node_id: concept_id.clone(),
+                            path,
+                        })
+                })
+                .collect()
+        }
+        ModelFailure::ModelFanout(_) => graph
+            .children(model_id)
+            .filter(|id| id.starts_with("model."))
+            .map(|child_id| BlameTrail {
+                path: vec![model_id.to_string(), child_id.clone()],
+                node_id: child_id,
+            })
+            .collect(),
+        ModelFailure::LayerDirectionViolation(violations) => violations
+            .iter()
+            .filter_map(|violation| {
+                graph
+                    .shortest_path(&violation.upstream_id, model_id)
+                    .map(|path| BlameTrail {
+                        node_id: violation.upstream_id.clone(),
+                        path,
+                    })
+            })
+            .collect(),
+        // No upstream/downstream culprit node exists by definition -- the "blame" here
+        // is the confirmed absence of any non-test/unit_test downstream consumer.
+        ModelFailure::DeadModel => Vec::new(),
+        ModelFailure::CircularDependency(members) => members
+            .iter()
+            .filter(|member| member.as_str() != model_id)
+            .map(|member| BlameTrail {
+                node_id: member.clone(),
+                path: vec![model_id.to_string(), member.clone()],
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Resolve `failure`'s severity, blame trail, and (if `blame_cache` is set) ownership,
+/// and record it on `failures`.
+fn record_failure(
+    failures: &mut Vec<ModelFailureEntry>,
+    failure: ModelFailure,
+    model_id: &str,
+    model: &ManifestModel,
+    graph: &DbtGraph,
+    config: &Config,
+    blame_cache: Option<&BlameCache>,
+) {
+    let severity = failure.severity(config, model);
+    if severity == Severity::Off {
+        return;
+    }
+    let blame = blame_for(&failure, model_id, model, graph);
+    let owners = blame_cache
+        .and_then(|cache| cache.attribute(&model_file(model, config), None))
+        .into_iter()
+        .collect();
+    failures.push(ModelFailureEntry {
+        failure,
+        severity,
+        blame,
+        owners,
+    });
+}
+
+/// The file a model's failures should be attributed against: its properties file if it
+/// has one, otherwise the `.sql` file itself. The manifest doesn't carry per-field line
+/// numbers, so every lookup is file-level (`BlameCache::attribute`'s most-recent-commit
+/// path) rather than pointing at the specific line that's missing a description.
+fn model_file(model: &ManifestModel, config: &Config) -> PathBuf {
+    let relative = model
+        .__common_attr__
+        .patch_path
+        .clone()
+        .unwrap_or_else(|| model.__common_attr__.original_file_path.clone());
+    match &config.project_dir {
+        Some(project_dir) => project_dir.join(relative),
+        None => relative,
+    }
+}
+
 // ModelChange and ModelChanges are defined in `crate::change_descriptors`.
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ModelResult {
     pub model_id: String,
-    pub failures: Vec<ModelFailure>,
+    pub failures: Vec<ModelFailureEntry>,
     pub column_results: BTreeMap<String, ColumnResult>, // kind of hate this, but...
     pub changes: Option<ModelChanges>,
 }
@@ -84,7 +356,7 @@ impl ModelResult {
         self.changes.as_ref()
     }
 
-    pub fn failures(&self) -> &[ModelFailure] {
+    pub fn failures(&self) -> &[ModelFailureEntry] {
         &self.failures
     }
 
@@ -100,6 +372,13 @@ impl ModelResult {
         !self.is_pass()
     }
 
+    /// Whether this model has any failure severe enough to fail the run, i.e. not
+    /// suppressed down to `warn`/`info`. Column failures don't carry a severity yet,
+    /// so they're always treated as error-level.
+    pub fn has_error_failures(&self) -> bool {
+        self.failures.iter().any(ModelFailureEntry::is_error) || self.has_column_failures()
+    }
+
     pub fn failure_reasons(&self) -> Vec<String> {
         let mut reasons: Vec<String> = self.failures.iter().map(ToString::to_string).collect();
         for column_result in self.column_results.values() {
@@ -123,11 +402,64 @@ impl Display for ModelResult {
     }
 }
 
+/// The results of the checks that read only the manifest, graph, and reachability --
+/// never another model's `ModelChanges` -- so `check_all` computes every model's
+/// `StructuralChecks` in one flat, unordered `par_iter` pass before the topological pass
+/// that resolves column-description inheritance (which does need upstream models'
+/// results, via `prior_changes`).
+#[derive(Debug, Default, Clone)]
+pub(crate) struct StructuralChecks {
+    failures: Vec<ModelFailure>,
+    required_test_change: Option<ModelChange>,
+}
+
+/// Compute `model_id`'s `model_fanout`/`direct_join_to_source`/`multiple_sources_joined`/
+/// `rejoining_of_upstream_concepts`/`missing_required_tests` results. Safe to run for
+/// every model concurrently: none of these consult another model's prior fixes.
+pub(crate) fn structural_checks(
+    manifest: &DbtManifestV12,
+    graph: &DbtGraph,
+    reachability: &Reachability,
+    model_id: &str,
+    config: &Config,
+) -> StructuralChecks {
+    let Some(DbtNode::Model(model)) = manifest.nodes.get(model_id) else {
+        return StructuralChecks::default();
+    };
+
+    let mut failures = Vec::new();
+    if let Err(failure) = direct_join_to_source(model, config) {
+        failures.push(failure);
+    }
+    if let Err(failure) = model_fanout(graph, model_id, config) {
+        failures.push(failure);
+    }
+    if let Err(failure) = multiple_sources_joined(model, config) {
+        failures.push(failure);
+    }
+    if let Err(failure) = rejoining_of_upstream_concepts(graph, reachability, model, config) {
+        failures.push(failure);
+    }
+
+    let mut required_test_change = None;
+    match missing_required_tests(manifest, model, config) {
+        Ok(change) => required_test_change = change,
+        Err(failure) => failures.push(failure),
+    }
+
+    StructuralChecks {
+        failures,
+        required_test_change,
+    }
+}
+
 pub(crate) fn check_model(
     manifest: &DbtManifestV12,
     graph: &DbtGraph,
     model_id: &str,
     prior_changes: &BTreeMap<String, ModelChanges>,
+    cycles: &BTreeMap<String, Vec<String>>,
+    structural: &StructuralChecks,
     config: &Config,
 ) -> ModelResult {
     let Some(node @ DbtNode::Model(original_model)) = manifest.nodes.get(model_id) else {
@@ -147,9 +479,27 @@ pub(crate) fn check_model(
         .to_string();
     let _model_type = model_type(original_model); // currently unused
 
-    let mut failures: Vec<ModelFailure> = Vec::new();
+    let mut failures: Vec<ModelFailureEntry> = Vec::new();
     let mut model_level_changes: Vec<ModelChange> = Vec::new();
     let mut property_change_required = false;
+    // One `BlameCache` per model check, so its several failures (e.g. a missing
+    // description and a fanout violation) reuse the same `git log`/`git blame` call
+    // instead of spawning a process per failure.
+    let blame_cache = config.blame.then(BlameCache::new);
+
+    macro_rules! push_failure {
+        ($failure:expr) => {
+            record_failure(
+                &mut failures,
+                $failure,
+                &model_unique_id,
+                &working_model,
+                graph,
+                config,
+                blame_cache.as_ref(),
+            )
+        };
+    }
 
     match missing_properties_file(node, config) {
         Ok(Some(change)) => {
@@ -164,7 +514,7 @@ pub(crate) fn check_model(
             // we're NOT pushing to model_level_changes here, as the file gets created by the check
         }
         Ok(None) => {}
-        Err(failure) => failures.push(failure),
+        Err(failure) => push_failure!(failure),
     }
 
     match missing_model_description(&mut working_model, config) {
@@ -176,48 +526,43 @@ pub(crate) fn check_model(
             }
         }
         Ok(None) => {}
-        Err(failure) => failures.push(failure),
+        Err(failure) => push_failure!(failure),
     }
     if let Err(failure) = missing_model_tags(&working_model, config) {
-        failures.push(failure)
-    }
-    if let Err(failure) = missing_required_tests(manifest, &working_model, config) {
-        failures.push(failure)
+        push_failure!(failure)
     }
     if let Err(failure) = missing_primary_key(&working_model, config) {
-        failures.push(failure)
+        push_failure!(failure)
     }
     if let Err(failure) = public_model_without_contract(&working_model, config) {
-        failures.push(failure)
+        push_failure!(failure)
     }
 
-    if let Err(failure) = direct_join_to_source(&working_model, config) {
-        failures.push(failure)
+    for failure in structural.failures.clone() {
+        push_failure!(failure)
     }
-    if let Err(failure) = model_fanout(graph, model_id, config) {
-        failures.push(failure)
+    if let Err(failure) = layer_direction_violation(manifest, &working_model, config) {
+        push_failure!(failure)
     }
     if let Err(failure) = root_model(&working_model, config) {
-        failures.push(failure)
-    }
-    if let Err(failure) = multiple_sources_joined(&working_model, config) {
-        failures.push(failure)
-    }
-    if let Err(failure) = rejoining_of_upstream_concepts(graph, &working_model, config) {
-        failures.push(failure)
+        push_failure!(failure)
     }
     if let Err(failure) = dead_model(&working_model, graph, config) {
-        failures.push(failure)
+        push_failure!(failure)
+    }
+    if let Err(failure) = circular_dependency(&model_unique_id, cycles, config) {
+        push_failure!(failure)
     }
 
     match model_separate_from_properties_file(node, config) {
         Ok(Some(change)) => model_level_changes.push(change),
         Ok(None) => {}
-        Err(failure) => failures.push(failure),
+        Err(failure) => push_failure!(failure),
     }
 
     let column_results = check_model_columns(
         manifest,
+        graph,
         original_model,
         &mut working_model,
         prior_changes,
@@ -238,11 +583,27 @@ pub(crate) fn check_model(
         }
     }
 
+    if let Some(change) = structural.required_test_change.clone() {
+        if let ModelChange::ChangePropertiesFile {
+            property: Some(prop),
+            ..
+        } = &change
+        {
+            for col in &prop.columns {
+                column_changes
+                    .entry(col.name.clone())
+                    .or_default()
+                    .insert(ColumnChange::AddDataTest);
+            }
+        }
+        model_level_changes.push(change);
+    }
+
     let patch_path = working_model.__common_attr__.patch_path.clone();
 
     if config.fix
         && property_change_required
-        && let Some(property) =
+        && let Some((property, _column_diffs)) =
             model_property_from_manifest_differences(original_model, &working_model)
     {
         model_level_changes.push(ModelChange::ChangePropertiesFile {
@@ -381,6 +742,75 @@ fn direct_join_to_source(model: &ManifestModel, config: &Config) -> Result<(), M
     }
 }
 
+/// Classify `model` into one of `config.layers` by matching its name or folder path
+/// against `config.layer_patterns`. Returns the layer's index in `config.layers` --
+/// lower means further upstream (e.g. `staging` before `marts`) -- or `None` if no
+/// pattern matches, meaning the model can't be placed and is skipped by the check.
+///
+/// `pub(crate)` so `check::exposures::exposure_parents_staging` can classify an
+/// exposure's upstream models with the same layer semantics, rather than a second
+/// copy of this heuristic.
+pub(crate) fn classify_layer(model: &ManifestModel, config: &Config) -> Option<usize> {
+    let name = &model.__common_attr__.name;
+    let path = model.__common_attr__.original_file_path.to_string_lossy();
+
+    config.layers.iter().position(|layer| {
+        config.layer_patterns.get(layer).is_some_and(|patterns| {
+            patterns
+                .iter()
+                .any(|pattern| name.starts_with(pattern.as_str()) || path.contains(pattern.as_str()))
+        })
+    })
+}
+
+/// Like a provider/user dependency graph: `config.layers` is ordered from furthest
+/// upstream to furthest downstream, and a model may only depend on models in its own
+/// layer or an earlier one. Flags any direct model dependency that sits in a later
+/// layer than `model` itself. Source dependencies are left to `direct_join_to_source`;
+/// models that can't be classified (no matching `layer_patterns` entry) are skipped,
+/// both as the model under check and as a candidate upstream violator.
+fn layer_direction_violation(
+    manifest: &DbtManifestV12,
+    model: &ManifestModel,
+    config: &Config,
+) -> Result<(), ModelFailure> {
+    if !config.is_selected(Selector::LayerDirectionViolation) || config.layers.is_empty() {
+        return Ok(());
+    }
+
+    let Some(model_layer) = classify_layer(model, config) else {
+        return Ok(());
+    };
+
+    let violations: Vec<LayerViolation> = model
+        .__base_attr__
+        .depends_on
+        .nodes
+        .iter()
+        .filter(|upstream_id| upstream_id.starts_with("model."))
+        .filter_map(|upstream_id| {
+            let Some(DbtNode::Model(upstream_model)) = manifest.nodes.get(upstream_id) else {
+                return None;
+            };
+            let upstream_layer = classify_layer(upstream_model, config)?;
+            if upstream_layer > model_layer {
+                Some(LayerViolation {
+                    upstream_id: upstream_id.clone(),
+                    upstream_layer: config.layers[upstream_layer].clone(),
+                })
+            } else {
+                None
+            }
+        })
+        .collect();
+
+    if violations.is_empty() {
+        Ok(())
+    } else {
+        Err(ModelFailure::LayerDirectionViolation(violations))
+    }
+}
+
 // TODO: to really propagate well, this would need to recreate the ManifestModel
 // or at the very least add the columns
 // https://github.com/VDFaller/dbt-lint-yaml/issues/40
@@ -408,7 +838,7 @@ fn missing_properties_file(
     if config.is_fixable(Selector::MissingPropertiesFile) {
         match node {
             DbtNode::Model(model) => {
-                match write_generated_model(model, config.project_dir.as_deref()) {
+                match write_generated_model(model, config.project_dir.as_deref(), false) {
                     Ok(generated_patch) => {
                         // If we successfully wrote the generated model, we can return the change.
                         return Ok(Some(ModelChange::GeneratePropertiesFile {
@@ -536,6 +966,7 @@ fn model_separate_from_properties_file(
 /// https://dbt-labs.github.io/dbt-project-evaluator/latest/rules/modeling/#rejoining-of-upstream-concepts
 fn rejoining_of_upstream_concepts(
     graph: &DbtGraph,
+    reachability: &Reachability,
     model: &ManifestModel,
     config: &Config,
 ) -> Result<(), ModelFailure> {
@@ -551,11 +982,9 @@ fn rejoining_of_upstream_concepts(
             if p == q {
                 continue;
             }
-            if let (Some(&p_idx), Some(&q_idx)) = (graph.index.get(p), graph.index.get(q)) {
-                // if there's a path q -> p then q is rejoined
-                if has_path_connecting(&graph.graph, q_idx, p_idx, None) {
-                    rejoined.insert(q.clone());
-                }
+            // if there's a path q -> p then q is rejoined
+            if reachability.reaches(graph, q, p) {
+                rejoined.insert(q.clone());
             }
         }
     }
@@ -583,13 +1012,18 @@ fn public_model_without_contract(
     }
 }
 
+/// Checks that at least one of `config.required_tests` (e.g. `unique`) is present
+/// among the model's tests. When fixable and the model has a `primary_key`, the fix
+/// is a `data_tests:` entry listing `config.required_tests` under the primary key
+/// column(s) -- there's no other column a required test can be unambiguously attached
+/// to, so a model without a primary key still fails even with `--fix`.
 fn missing_required_tests(
     manifest: &DbtManifestV12,
     model: &ManifestModel,
     config: &Config,
-) -> Result<(), ModelFailure> {
+) -> Result<Option<ModelChange>, ModelFailure> {
     if config.required_tests.is_empty() {
-        return Ok(());
+        return Ok(None);
     }
 
     let existing_tests: Vec<String> = manifest
@@ -610,12 +1044,41 @@ fn missing_required_tests(
         .any(|test_name| config.required_tests.contains(test_name));
 
     if has_required_test {
-        Ok(())
-    } else {
-        Err(ModelFailure::MissingRequiredTests(
-            config.required_tests.clone(),
-        ))
+        return Ok(None);
     }
+
+    if config.fix
+        && let Some(primary_key) = model.primary_key.as_ref().filter(|pk| !pk.is_empty())
+    {
+        let model_id = model.__common_attr__.unique_id.clone();
+        let model_name = model_id.rsplit('.').next().unwrap_or(&model_id).to_string();
+        let patch_path = model.__common_attr__.patch_path.clone();
+
+        let columns = primary_key
+            .iter()
+            .map(|column_name| ColumnProperty {
+                name: column_name.clone(),
+                data_tests: Some(config.required_tests.clone()),
+                ..Default::default()
+            })
+            .collect();
+
+        return Ok(Some(ModelChange::ChangePropertiesFile {
+            model_id,
+            model_name: model_name.clone(),
+            patch_path,
+            property: Some(ModelProperty {
+                name: Some(model_name),
+                description: None,
+                columns,
+                extras: BTreeMap::new(),
+            }),
+        }));
+    }
+
+    Err(ModelFailure::MissingRequiredTests(
+        config.required_tests.clone(),
+    ))
 }
 
 // Column checking moved into `src/check/columns.rs`.
@@ -664,6 +1127,22 @@ fn dead_model(
     }
 }
 
+/// Whether `model` sits on a dependency cycle precomputed once per `check_all` run by
+/// `check::cycles::detect_circular_dependencies`.
+fn circular_dependency(
+    model_id: &str,
+    cycles: &BTreeMap<String, Vec<String>>,
+    config: &Config,
+) -> Result<(), ModelFailure> {
+    if !config.is_selected(Selector::CircularDependencies) {
+        return Ok(());
+    }
+    match cycles.get(model_id) {
+        Some(members) => Err(ModelFailure::CircularDependency(members.clone())),
+        None => Ok(()),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -723,11 +1202,16 @@ mod tests {
         }
         .with_fix(true);
         let graph = DbtGraph::from(&manifest);
+        let reachability = Reachability::build(&graph);
+        let cycles = BTreeMap::<String, Vec<String>>::new();
+        let structural = structural_checks(&manifest, &graph, &reachability, "model.test.downstream", &config);
         let model_result = check_model(
             &manifest,
             &graph,
             "model.test.downstream",
             &prior_changes,
+            &cycles,
+            &structural,
             &config,
         );
 
@@ -808,6 +1292,79 @@ mod tests {
         assert!(missing_required_tests(&manifest, model, &config).is_err());
     }
 
+    #[test]
+    fn missing_required_tests_fixes_primary_key_column_when_fixable() {
+        let mut manifest = DbtManifestV12::default();
+        let model_id = "model.test.with_primary_key".to_string();
+        manifest
+            .nodes
+            .insert(model_id.clone(), DbtNode::Model(Default::default()));
+
+        if let Some(DbtNode::Model(model)) = manifest.nodes.get_mut(&model_id) {
+            model.__common_attr__.unique_id = model_id.clone();
+            model.primary_key = Some(vec!["customer_id".to_string()]);
+        } else {
+            panic!("expected model to be inserted");
+        }
+
+        let model = match manifest.nodes.get(&model_id) {
+            Some(DbtNode::Model(model)) => model,
+            _ => panic!("expected model node"),
+        };
+
+        let config = Config {
+            required_tests: vec!["unique".to_string()],
+            ..Default::default()
+        }
+        .with_fix(true);
+
+        let change = missing_required_tests(&manifest, model, &config)
+            .expect("fixable model should produce a change")
+            .expect("change should be Some");
+
+        let ModelChange::ChangePropertiesFile { property, .. } = change else {
+            panic!("expected a ChangePropertiesFile change");
+        };
+        let prop = property.expect("property payload attached");
+        let pk_column = prop
+            .columns
+            .iter()
+            .find(|col| col.name == "customer_id")
+            .expect("primary key column should carry the fix");
+        assert_eq!(
+            pk_column.data_tests.as_deref(),
+            Some(["unique".to_string()].as_slice())
+        );
+    }
+
+    #[test]
+    fn missing_required_tests_still_fails_without_primary_key_even_when_fixable() {
+        let mut manifest = DbtManifestV12::default();
+        let model_id = "model.test.without_primary_key".to_string();
+        manifest
+            .nodes
+            .insert(model_id.clone(), DbtNode::Model(Default::default()));
+
+        if let Some(DbtNode::Model(model)) = manifest.nodes.get_mut(&model_id) {
+            model.__common_attr__.unique_id = model_id.clone();
+        } else {
+            panic!("expected model to be inserted");
+        }
+
+        let model = match manifest.nodes.get(&model_id) {
+            Some(DbtNode::Model(model)) => model,
+            _ => panic!("expected model node"),
+        };
+
+        let config = Config {
+            required_tests: vec!["unique".to_string()],
+            ..Default::default()
+        }
+        .with_fix(true);
+
+        assert!(missing_required_tests(&manifest, model, &config).is_err());
+    }
+
     #[test]
     fn test_direct_join_to_source() {
         let mut model = ManifestModel::default();
@@ -853,6 +1410,85 @@ mod tests {
         assert!(direct_join_to_source(&model, &config).is_ok());
     }
 
+    #[test]
+    fn layer_direction_violation_flags_downstream_dependency() {
+        let mut manifest = DbtManifestV12::default();
+
+        let upstream_id = "model.test.marts_customers".to_string();
+        manifest
+            .nodes
+            .insert(upstream_id.clone(), DbtNode::Model(Default::default()));
+        if let Some(DbtNode::Model(upstream)) = manifest.nodes.get_mut(&upstream_id) {
+            upstream.__common_attr__.unique_id = upstream_id.clone();
+            upstream.__common_attr__.name = "marts_customers".to_string();
+        }
+
+        let mut model = ManifestModel::default();
+        model.__common_attr__.unique_id = "model.test.stg_customers".to_string();
+        model.__common_attr__.name = "stg_customers".to_string();
+        model.__base_attr__.depends_on.nodes = vec![upstream_id.clone()];
+
+        let config = Config {
+            layers: vec!["staging".to_string(), "marts".to_string()],
+            layer_patterns: BTreeMap::from([
+                ("staging".to_string(), vec!["stg_".to_string()]),
+                ("marts".to_string(), vec!["marts_".to_string()]),
+            ]),
+            ..Default::default()
+        };
+
+        let err = layer_direction_violation(&manifest, &model, &config)
+            .expect_err("staging model depending on marts model should be flagged");
+        let ModelFailure::LayerDirectionViolation(violations) = err else {
+            panic!("expected LayerDirectionViolation");
+        };
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].upstream_id, upstream_id);
+        assert_eq!(violations[0].upstream_layer, "marts");
+    }
+
+    #[test]
+    fn layer_direction_violation_allows_upstream_ordered_dependency() {
+        let mut manifest = DbtManifestV12::default();
+
+        let upstream_id = "model.test.stg_customers".to_string();
+        manifest
+            .nodes
+            .insert(upstream_id.clone(), DbtNode::Model(Default::default()));
+        if let Some(DbtNode::Model(upstream)) = manifest.nodes.get_mut(&upstream_id) {
+            upstream.__common_attr__.unique_id = upstream_id.clone();
+            upstream.__common_attr__.name = "stg_customers".to_string();
+        }
+
+        let mut model = ManifestModel::default();
+        model.__common_attr__.unique_id = "model.test.marts_customers".to_string();
+        model.__common_attr__.name = "marts_customers".to_string();
+        model.__base_attr__.depends_on.nodes = vec![upstream_id];
+
+        let config = Config {
+            layers: vec!["staging".to_string(), "marts".to_string()],
+            layer_patterns: BTreeMap::from([
+                ("staging".to_string(), vec!["stg_".to_string()]),
+                ("marts".to_string(), vec!["marts_".to_string()]),
+            ]),
+            ..Default::default()
+        };
+
+        assert!(layer_direction_violation(&manifest, &model, &config).is_ok());
+    }
+
+    #[test]
+    fn layer_direction_violation_disabled_without_configured_layers() {
+        let manifest = DbtManifestV12::default();
+        let mut model = ManifestModel::default();
+        model.__common_attr__.unique_id = "model.test.stg_customers".to_string();
+        model.__common_attr__.name = "stg_customers".to_string();
+        model.__base_attr__.depends_on.nodes = vec!["model.test.marts_customers".to_string()];
+
+        let config = Config::default();
+        assert!(layer_direction_violation(&manifest, &model, &config).is_ok());
+    }
+
     #[test]
     fn test_model_fanout() {
         let mut manifest = DbtManifestV12::default();
@@ -957,7 +1593,11 @@ mod tests {
             "model.test.upstream".to_string(),
             "model.test.midstream".to_string(),
         ];
-        assert!(rejoining_of_upstream_concepts(&dbt_graph, &downstream, &config).is_err());
+        let reachability = Reachability::build(&dbt_graph);
+        assert!(
+            rejoining_of_upstream_concepts(&dbt_graph, &reachability, &downstream, &config)
+                .is_err()
+        );
     }
 
     #[test]
@@ -1,14 +1,23 @@
 use crate::change_descriptors::{SourceChange, SourceChanges};
 use crate::check::columns::missing_description;
-use crate::config::{Config, Selector};
+use crate::config::{Config, Selector, Severity};
+use crate::graph::DbtGraph;
+use crate::osmosis::{InheritedValue, resolve_source_col_desc, resolve_source_table_desc};
+use crate::ownership::{BlameCache, Ownership};
 use crate::writeback::properties::source_property_from_manifest_differences;
 use dbt_schemas::schemas::manifest::{DbtManifestV12, ManifestSource};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fmt::Display;
+use std::sync::Arc;
 use strum::AsRefStr;
 
-#[derive(Debug, Clone, AsRefStr, PartialEq, Eq)]
+#[derive(Debug, Clone, AsRefStr, PartialEq, Eq, Serialize, Deserialize)]
 pub enum SourceFailure {
     MissingDescription,
+    /// `missing_source_table_description` found candidate descriptions from more than
+    /// one downstream model/sibling source and they disagreed, so no fix was applied.
+    AmbiguousSourceDescription(Vec<String>),
     DuplicateDefinition(String),
     UnusedSource,
     MissingFreshness,
@@ -22,15 +31,74 @@ impl Display for SourceFailure {
             SourceFailure::DuplicateDefinition(duplicate_id) => {
                 write!(f, "DuplicateDefinition:{duplicate_id}")
             }
+            SourceFailure::AmbiguousSourceDescription(values) => {
+                write!(f, "MissingDescription: ambiguous upstream values: {}", values.join(", "))
+            }
             _ => f.write_str(self.as_ref()),
         }
     }
 }
 
-#[derive(Debug, Clone, Default)]
+impl SourceFailure {
+    /// The `Selector` this failure is gated behind, used to resolve its configured
+    /// severity. Mirrors `ModelFailure::selector`.
+    pub fn selector(&self) -> Option<Selector> {
+        match self {
+            SourceFailure::MissingDescription => Some(Selector::MissingSourceTableDescriptions),
+            SourceFailure::AmbiguousSourceDescription(_) => {
+                Some(Selector::MissingSourceTableDescriptions)
+            }
+            SourceFailure::DuplicateDefinition(_) => Some(Selector::DuplicateSources),
+            SourceFailure::UnusedSource => Some(Selector::UnusedSources),
+            SourceFailure::MissingFreshness => Some(Selector::MissingSourceFreshness),
+            SourceFailure::MissingSourceDescription => Some(Selector::MissingSourceDescriptions),
+            SourceFailure::SourceTableColumnDescriptions => {
+                Some(Selector::MissingSourceColumnDescriptions)
+            }
+            SourceFailure::SourceFanout => Some(Selector::SourceFanout),
+        }
+    }
+
+    /// The effective severity of this failure under `config`. Unlike
+    /// `ModelFailure::severity`, there's no `SeverityOverride` scope-matching wired up
+    /// for sources (no `ManifestSource`-shaped `ModelScope` equivalent exists), so this
+    /// only considers global overrides, same as `Config::severity`.
+    pub fn severity(&self, config: &Config) -> Severity {
+        self.selector()
+            .map(|selector| config.severity(selector))
+            .unwrap_or(Severity::Error)
+    }
+}
+
+/// A `SourceFailure` together with its resolved severity and, if `Config::blame` is set,
+/// who last touched the source's properties file. Mirrors `check::models::ModelFailureEntry`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SourceFailureEntry {
+    pub failure: SourceFailure,
+    pub severity: Severity,
+    pub owners: Vec<Ownership>,
+}
+
+impl SourceFailureEntry {
+    pub fn is_error(&self) -> bool {
+        self.severity == Severity::Error
+    }
+}
+
+impl Display for SourceFailureEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{}] {}", self.severity.as_ref(), self.failure)?;
+        for owner in &self.owners {
+            write!(f, "; owner: {owner}")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct SourceResult {
     pub source_id: String,
-    pub failures: Vec<SourceFailure>,
+    pub failures: Vec<SourceFailureEntry>,
     pub changes: Option<SourceChanges>,
 }
 
@@ -51,6 +119,19 @@ impl SourceResult {
         self.is_failure().then_some(self)
     }
 
+    /// The most severe `Severity` across this source's failures, or `None` if it
+    /// passed. `Severity` derives `Ord` in most-to-least-severe declaration order
+    /// (`Error` < `Warn` < `Info` < `Off`), so the most severe entry is the minimum.
+    pub fn max_severity(&self) -> Option<Severity> {
+        self.failures.iter().map(|entry| entry.severity).min()
+    }
+
+    /// Whether this source has any failure severe enough to fail the run, i.e. not
+    /// suppressed down to `warn`/`info`. Mirrors `ModelResult::has_error_failures`.
+    pub fn has_error_failures(&self) -> bool {
+        self.max_severity() == Some(Severity::Error)
+    }
+
     pub fn failure_reasons(&self) -> Vec<String> {
         self.failures.iter().map(ToString::to_string).collect()
     }
@@ -74,16 +155,37 @@ impl Display for SourceResult {
     }
 }
 
+/// Resolve `failure`'s severity and record it on `failures`, same as
+/// `check::models::record_failure`. Skipped entirely if the selector is configured `off`.
+fn record_failure(
+    failures: &mut Vec<SourceFailureEntry>,
+    failure: SourceFailure,
+    config: &Config,
+    owners: Vec<Ownership>,
+) {
+    let severity = failure.severity(config);
+    if severity == Severity::Off {
+        return;
+    }
+    failures.push(SourceFailureEntry {
+        failure,
+        severity,
+        owners,
+    });
+}
+
 pub fn check_sources(manifest: &DbtManifestV12, config: &Config) -> Vec<SourceResult> {
+    let graph = DbtGraph::from(manifest);
     manifest
         .sources
         .values()
-        .map(|source| check_source(manifest, source, config))
+        .map(|source| check_source(manifest, &graph, source, config))
         .collect()
 }
 
 fn check_source(
     manifest: &DbtManifestV12,
+    graph: &DbtGraph,
     source: &ManifestSource,
     config: &Config,
 ) -> SourceResult {
@@ -93,41 +195,60 @@ fn check_source(
     let table_name = working_source.__common_attr__.name.clone();
     let patch_path = working_source.__common_attr__.patch_path.clone();
 
-    let mut failures = Vec::new();
+    let mut failures: Vec<SourceFailureEntry> = Vec::new();
     let mut source_level_changes: Vec<SourceChange> = Vec::new();
     let mut property_change_required = false;
+    // One `BlameCache` per source check, for the same reason `check_model` keeps one:
+    // several failures on the same source share the same properties file.
+    let blame_cache = config.blame.then(BlameCache::new);
+    let owners_for = |blame_cache: &Option<BlameCache>| -> Vec<Ownership> {
+        match (blame_cache, &patch_path) {
+            (Some(cache), Some(file)) => cache.attribute(file, None).into_iter().collect(),
+            _ => Vec::new(),
+        }
+    };
 
-    match missing_source_table_description(&mut working_source, config) {
+    match missing_source_table_description(manifest, graph, &mut working_source, config) {
         Ok(Some(change)) => {
             property_change_required = true;
             source_level_changes.push(change);
         }
         Ok(None) => {}
-        Err(failure) => failures.push(failure),
+        Err(failure) => record_failure(&mut failures, failure, config, owners_for(&blame_cache)),
     }
 
-    if let Err(failure) = missing_source_column_descriptions(&mut working_source, config) {
-        failures.push(failure);
+    match missing_source_column_descriptions(manifest, graph, &mut working_source, config) {
+        Ok(changed) => property_change_required = property_change_required || changed,
+        Err(failure) => {
+            // Columns resolved before the failure are already written into `working_source`.
+            property_change_required = property_change_required
+                || source
+                    .columns
+                    .iter()
+                    .zip(working_source.columns.iter())
+                    .any(|(before, after)| before.as_ref().description != after.as_ref().description);
+            record_failure(&mut failures, failure, config, owners_for(&blame_cache));
+        }
     }
 
     if let Err(failure) = duplicate_source(manifest, source, config) {
-        failures.push(failure);
+        record_failure(&mut failures, failure, config, owners_for(&blame_cache));
     }
 
     if let Err(failure) = unused_source(manifest, source, config) {
-        failures.push(failure);
+        record_failure(&mut failures, failure, config, owners_for(&blame_cache));
     }
 
     if let Err(failure) = missing_source_freshness(source, config) {
-        failures.push(failure);
+        record_failure(&mut failures, failure, config, owners_for(&blame_cache));
     }
 
     if let Err(failure) = missing_source_description(&working_source, config) {
-        failures.push(failure);
+        record_failure(&mut failures, failure, config, owners_for(&blame_cache));
     }
 
     if let Err(failure) = source_fanout(manifest, source, config) {
-        failures.push(failure);
+        record_failure(&mut failures, failure, config, owners_for(&blame_cache));
     }
 
     let mut changes = if source_level_changes.is_empty() {
@@ -193,7 +314,14 @@ fn check_source(
 /// - None
 /// - An empty string (after trimming)
 /// - Matches any of the configured invalid descriptions (case-insensitive, after trimming)
+///
+/// When fixable, the replacement is pulled from the rest of the manifest via
+/// `osmosis::resolve_source_table_desc` (a downstream model or a sibling source sharing
+/// the same `identifier`) rather than stamped with a placeholder -- if the candidates
+/// disagree, the fix is skipped and reported as `AmbiguousSourceDescription` instead.
 fn missing_source_table_description(
+    manifest: &DbtManifestV12,
+    graph: &DbtGraph,
     source: &mut ManifestSource,
     config: &Config,
 ) -> Result<Option<SourceChange>, SourceFailure> {
@@ -219,39 +347,79 @@ fn missing_source_table_description(
         return Ok(None);
     }
 
-    if config.is_fixable(Selector::MissingSourceTableDescriptions) {
-        source.__common_attr__.description = Some("Auto-generated description".to_string());
-        let change = SourceChange::ChangePropertiesFile {
-            source_id: source.__common_attr__.unique_id.clone(),
-            source_name: source.source_name.clone(),
-            table_name: source.__common_attr__.name.clone(),
-            patch_path: source.__common_attr__.patch_path.clone(),
-            property: None,
-        };
-        Ok(Some(change))
-    } else {
-        Err(SourceFailure::MissingDescription)
+    if !config.is_fixable(Selector::MissingSourceTableDescriptions) {
+        return Err(SourceFailure::MissingDescription);
+    }
+
+    match resolve_source_table_desc(manifest, graph, source, config) {
+        InheritedValue::Resolved(description) => {
+            source.__common_attr__.description = Some(description);
+            let change = SourceChange::ChangePropertiesFile {
+                source_id: source.__common_attr__.unique_id.clone(),
+                source_name: source.source_name.clone(),
+                table_name: source.__common_attr__.name.clone(),
+                patch_path: source.__common_attr__.patch_path.clone(),
+                property: None,
+            };
+            Ok(Some(change))
+        }
+        InheritedValue::Ambiguous(values) => Err(SourceFailure::AmbiguousSourceDescription(values)),
+        InheritedValue::NotFound => Err(SourceFailure::MissingDescription),
     }
 }
 
-/// Check that every column on a source table has a non-empty description.
+/// Check that every column on a source table has a non-empty description, filling in
+/// whichever are missing from the rest of the manifest via
+/// `osmosis::resolve_source_col_desc` when fixable. Returns whether any column's
+/// description actually changed. If some column is left unresolved it returns `Err`
+/// instead, but the columns it did resolve are still written into `source`, so the caller
+/// can compare `source` against the original to tell whether a rewrite is needed.
 fn missing_source_column_descriptions(
+    manifest: &DbtManifestV12,
+    graph: &DbtGraph,
     source: &mut ManifestSource,
     config: &Config,
-) -> Result<(), SourceFailure> {
+) -> Result<bool, SourceFailure> {
     if !config.is_selected(Selector::MissingSourceColumnDescriptions) {
-        return Ok(());
+        return Ok(false);
     }
 
-    let has_missing = source
+    let missing_names: Vec<String> = source
         .columns
         .iter()
-        .any(|col| missing_description(col, config).is_err());
+        .filter(|col| missing_description(col, config).is_err())
+        .map(|col| col.name.clone())
+        .collect();
+
+    if missing_names.is_empty() {
+        return Ok(false);
+    }
+
+    if !config.is_fixable(Selector::MissingSourceColumnDescriptions) {
+        return Err(SourceFailure::SourceTableColumnDescriptions);
+    }
+
+    let mut resolved: BTreeMap<String, String> = BTreeMap::new();
+    let mut unresolved = false;
+    for col_name in &missing_names {
+        match resolve_source_col_desc(manifest, graph, source, col_name, config) {
+            InheritedValue::Resolved(description) => {
+                resolved.insert(col_name.clone(), description);
+            }
+            InheritedValue::Ambiguous(_) | InheritedValue::NotFound => unresolved = true,
+        }
+    }
+
+    for col in source.columns.iter_mut() {
+        if let Some(description) = resolved.get(&col.name) {
+            Arc::make_mut(col).description = Some(description.clone());
+        }
+    }
 
-    if has_missing {
+    if unresolved {
         Err(SourceFailure::SourceTableColumnDescriptions)
     } else {
-        Ok(())
+        Ok(!resolved.is_empty())
     }
 }
 
@@ -482,7 +650,8 @@ mod tests {
             .insert(source.__common_attr__.unique_id.clone(), vec![]);
 
         let config = Config::default();
-        let result = check_source(&manifest, &source, &config);
+        let graph = DbtGraph::from(&manifest);
+        let result = check_source(&manifest, &graph, &source, &config);
         assert!(result.is_failure());
     }
 
@@ -515,8 +684,10 @@ mod tests {
 
     #[test]
     fn test_missing_source_table_column_descriptions() {
+        let manifest = DbtManifestV12::default();
+        let graph = DbtGraph::from(&manifest);
         let mut source = ManifestSource::default();
-        // create a column without a description
+        // create a column without a description, with no downstream/sibling to inherit from
         let col = DbtColumn {
             name: "id".to_string(),
             description: None,
@@ -529,25 +700,29 @@ mod tests {
             ..Default::default()
         };
         assert!(matches!(
-            missing_source_column_descriptions(&mut source, &config),
+            missing_source_column_descriptions(&manifest, &graph, &mut source, &config),
             Err(SourceFailure::SourceTableColumnDescriptions)
         ));
     }
 
     #[test]
     fn test_missing_source_table_description_invalid_marker() {
+        let manifest = DbtManifestV12::default();
+        let graph = DbtGraph::from(&manifest);
         let mut source = ManifestSource::default();
         source.__common_attr__.description = Some("TBD".to_string());
 
         let config = Config::default();
         assert!(matches!(
-            missing_source_table_description(&mut source, &config),
+            missing_source_table_description(&manifest, &graph, &mut source, &config),
             Err(SourceFailure::MissingDescription)
         ));
     }
 
     #[test]
     fn test_source_table_column_descriptions_all_present() {
+        let manifest = DbtManifestV12::default();
+        let graph = DbtGraph::from(&manifest);
         let mut source = ManifestSource::default();
         let col = DbtColumn {
             name: "id".to_string(),
@@ -557,6 +732,132 @@ mod tests {
         source.columns.push(Arc::new(col));
 
         let config = Config::default();
-        assert!(missing_source_column_descriptions(&mut source, &config).is_ok());
+        assert!(
+            missing_source_column_descriptions(&manifest, &graph, &mut source, &config).is_ok()
+        );
+    }
+
+    #[test]
+    fn test_missing_source_table_description_fixed_from_downstream_model() {
+        let mut manifest = DbtManifestV12::default();
+        manifest.nodes.insert(
+            "model.test.stg_orders".to_string(),
+            dbt_schemas::schemas::manifest::DbtNode::Model(Default::default()),
+        );
+        match manifest.nodes.get_mut("model.test.stg_orders").unwrap() {
+            dbt_schemas::schemas::manifest::DbtNode::Model(model) => {
+                model.__common_attr__.description = Some("Staged raw orders".to_string());
+            }
+            _ => unreachable!(),
+        }
+        manifest.child_map.insert(
+            "source.raw.orders".to_string(),
+            vec!["model.test.stg_orders".to_string()],
+        );
+
+        let graph = DbtGraph::from(&manifest);
+        let mut source = ManifestSource::default();
+        source.__common_attr__.unique_id = "source.raw.orders".to_string();
+
+        let config = Config {
+            select: vec![Selector::MissingSourceTableDescriptions],
+            fix: true,
+            ..Default::default()
+        };
+        let change = missing_source_table_description(&manifest, &graph, &mut source, &config)
+            .expect("fix should apply")
+            .expect("a change should be produced");
+        assert_eq!(
+            source.__common_attr__.description.as_deref(),
+            Some("Staged raw orders")
+        );
+        assert!(matches!(change, SourceChange::ChangePropertiesFile { .. }));
+    }
+
+    #[test]
+    fn test_check_source_failure_severity_defaults_to_error() {
+        let mut manifest = DbtManifestV12::default();
+        let mut source = ManifestSource::default();
+        source.__common_attr__.unique_id = "source.raw.orders".to_string();
+        manifest
+            .sources
+            .insert(source.__common_attr__.unique_id.clone(), source.clone());
+        manifest
+            .child_map
+            .insert(source.__common_attr__.unique_id.clone(), vec![]);
+
+        let config = Config::default();
+        let graph = DbtGraph::from(&manifest);
+        let result = check_source(&manifest, &graph, &source, &config);
+        assert_eq!(result.max_severity(), Some(Severity::Error));
+        assert!(result.has_error_failures());
+    }
+
+    #[test]
+    fn test_check_source_warn_severity_does_not_count_as_error() {
+        let mut manifest = DbtManifestV12::default();
+        let mut source = ManifestSource::default();
+        source.__common_attr__.unique_id = "source.raw.orders".to_string();
+        manifest
+            .sources
+            .insert(source.__common_attr__.unique_id.clone(), source.clone());
+        manifest
+            .child_map
+            .insert(source.__common_attr__.unique_id.clone(), vec![]);
+
+        let config = Config {
+            severity: vec![crate::config::SeverityOverride {
+                selector: Selector::UnusedSources,
+                severity: Severity::Warn,
+                paths: Vec::new(),
+                tags: Vec::new(),
+                fqn_prefixes: Vec::new(),
+            }],
+            ..Default::default()
+        };
+        let graph = DbtGraph::from(&manifest);
+        let result = check_source(&manifest, &graph, &source, &config);
+        assert!(
+            result
+                .failures
+                .iter()
+                .any(|entry| matches!(entry.failure, SourceFailure::UnusedSource)
+                    && entry.severity == Severity::Warn)
+        );
+        // Other failures (e.g. missing description) are still error-level, so the source
+        // as a whole still counts as a failure.
+        assert!(result.has_error_failures());
+    }
+
+    #[test]
+    fn test_check_source_off_severity_suppresses_the_failure_entirely() {
+        let mut manifest = DbtManifestV12::default();
+        let mut source = ManifestSource::default();
+        source.__common_attr__.unique_id = "source.raw.orders".to_string();
+        manifest
+            .sources
+            .insert(source.__common_attr__.unique_id.clone(), source.clone());
+        manifest
+            .child_map
+            .insert(source.__common_attr__.unique_id.clone(), vec![]);
+
+        let config = Config {
+            severity: vec![crate::config::SeverityOverride {
+                selector: Selector::UnusedSources,
+                severity: Severity::Off,
+                paths: Vec::new(),
+                tags: Vec::new(),
+                fqn_prefixes: Vec::new(),
+            }],
+            ..Default::default()
+        };
+        let graph = DbtGraph::from(&manifest);
+        let result = check_source(&manifest, &graph, &source, &config);
+        assert!(
+            !result
+                .failures
+                .iter()
+                .any(|entry| matches!(entry.failure, SourceFailure::UnusedSource))
+        );
     }
 }
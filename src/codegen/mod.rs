@@ -1,4 +1,6 @@
+use parquet::basic::{LogicalType, Repetition, Type as PhysicalType};
 use parquet::file::reader::{FileReader, SerializedFileReader};
+use parquet::schema::types::ColumnDescriptor;
 /// This module contains code generation utilities for the project.
 /// It provides functions and structures to facilitate
 /// Currently it requires dbt compile which is not SA'd yet.
@@ -66,18 +68,110 @@ fn get_columns_from_parquet(
             let col = schema_descr.column(i);
             ColumnProperty {
                 name: col.name().to_string(),
-                description: None,
-                extras: std::collections::BTreeMap::new(),
+                data_type: Some(data_type_of(&col)),
+                data_tests: not_null_test(&col),
+                meta: repeated_note(&col),
+                ..Default::default()
             }
         })
         .collect();
     Ok(columns)
 }
 
+/// Maps a leaf column's parquet type to a dbt-style `data_type` string, preferring the
+/// logical type (e.g. `Decimal { precision, scale }` -> `numeric(p,s)`, `Timestamp` ->
+/// `timestamp`/`timestamptz` depending on `is_adjusted_to_utc`) and falling back to the
+/// physical type (`BOOLEAN` -> `boolean`, `INT32` -> `int`, ...) when no logical type is
+/// set -- the same precedence parquet record derivation gives logical types over the
+/// physical storage type.
+fn data_type_of(col: &ColumnDescriptor) -> String {
+    match col.logical_type() {
+        Some(logical) => data_type_from_logical(&logical, col.physical_type()),
+        None => data_type_from_physical(col.physical_type()),
+    }
+}
+
+fn data_type_from_logical(logical: &LogicalType, physical: PhysicalType) -> String {
+    match logical {
+        LogicalType::String => "string".to_string(),
+        LogicalType::Decimal { precision, scale } => format!("numeric({precision},{scale})"),
+        LogicalType::Date => "date".to_string(),
+        LogicalType::Timestamp { is_adjusted_to_utc, .. } => {
+            if *is_adjusted_to_utc {
+                "timestamptz".to_string()
+            } else {
+                "timestamp".to_string()
+            }
+        }
+        LogicalType::Time { .. } => "time".to_string(),
+        LogicalType::Integer { bit_width, is_signed } => {
+            let base = match bit_width {
+                8 => "tinyint",
+                16 => "smallint",
+                32 => "int",
+                _ => "bigint",
+            };
+            if *is_signed {
+                base.to_string()
+            } else {
+                format!("{base} unsigned")
+            }
+        }
+        _ => data_type_from_physical(physical),
+    }
+}
+
+fn data_type_from_physical(physical: PhysicalType) -> String {
+    match physical {
+        PhysicalType::BOOLEAN => "boolean",
+        PhysicalType::INT32 => "int",
+        PhysicalType::INT64 => "bigint",
+        PhysicalType::INT96 => "timestamp",
+        PhysicalType::FLOAT => "float",
+        PhysicalType::DOUBLE => "double",
+        PhysicalType::BYTE_ARRAY => "string",
+        PhysicalType::FIXED_LEN_BYTE_ARRAY => "bytes",
+    }
+    .to_string()
+}
+
+/// A `REQUIRED` parquet column gets a real `not_null` generic test instead of a
+/// description -- a canned description string would permanently satisfy
+/// `osmosis::valid_description` (it's neither empty nor `config.invalid_descriptions`)
+/// without a human ever having written one, silently defeating
+/// `MissingColumnDescriptions` on every generated column.
+fn not_null_test(col: &ColumnDescriptor) -> Option<Vec<String>> {
+    match col.self_type().get_basic_info().repetition() {
+        Repetition::REQUIRED => Some(vec!["not_null".to_string()]),
+        Repetition::OPTIONAL | Repetition::REPEATED => None,
+    }
+}
+
+/// No generic dbt test captures "this is a repeated/array field" the way `not_null`
+/// captures required-ness, so that fact goes in `meta` instead -- same reasoning as
+/// `not_null_test`, just with nowhere else to put it.
+fn repeated_note(
+    col: &ColumnDescriptor,
+) -> Option<std::collections::BTreeMap<String, dbt_serde_yaml::Value>> {
+    match col.self_type().get_basic_info().repetition() {
+        Repetition::REPEATED => Some(
+            dbt_serde_yaml::from_str("dbt_lint_yaml_repeated: true\n")
+                .expect("a single scalar meta key is always valid YAML"),
+        ),
+        Repetition::REQUIRED | Repetition::OPTIONAL => None,
+    }
+}
+
+/// Writes a generated properties file for `model`, deriving its path from the model's
+/// `original_file_path` (see `get_write_path`). Returns the resolved path written on
+/// success. Unless `force` is set, refuses to clobber a file that's already there --
+/// the same `overwrite`-gated-by-default posture `writeback::rust::MoveOptions` uses for
+/// move destinations.
 pub fn write_generated_model(
     model: &ManifestModel,
     project_root: Option<&Path>,
-) -> Result<(), Box<dyn std::error::Error>> {
+    force: bool,
+) -> Result<PathBuf, Box<dyn std::error::Error>> {
     let models_root = generate_model(model, project_root)?;
     let yaml_str = dbt_serde_yaml::to_string(&models_root)?;
     let write_path = get_write_path(model);
@@ -90,9 +184,17 @@ pub fn write_generated_model(
         write_path
     };
 
+    if resolved.exists() && !force {
+        return Err(format!(
+            "{} already exists (pass --force to overwrite)",
+            resolved.display()
+        )
+        .into());
+    }
+
     if let Some(parent) = resolved.parent() {
         std::fs::create_dir_all(parent)?;
     }
-    std::fs::write(resolved, yaml_str)?;
-    Ok(())
+    std::fs::write(&resolved, yaml_str)?;
+    Ok(resolved)
 }
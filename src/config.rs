@@ -1,10 +1,34 @@
+use globset::Glob;
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use strsim::levenshtein;
 use struct_field_names_as_array::FieldNamesAsSlice;
 use strum::{AsRefStr, EnumIter, EnumProperty, IntoEnumIterator};
 use thiserror::Error;
 
+/// How hard a selector's findings push back. `Error` fails the run (and the process exit
+/// code); `Warn`/`Info` are still reported but don't fail it, so a project can adopt a
+/// rule gradually instead of either enforcing it fully or not selecting it at all.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize, AsRefStr)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum Severity {
+    Error,
+    Warn,
+    Info,
+    /// Suppresses the finding entirely rather than just downgrading it. Unlike
+    /// `config.exclude`, which turns a selector off project-wide, `off` can come from a
+    /// scoped `SeverityOverride` and so only suppress it for the models that override
+    /// matches, leaving the rule active everywhere else.
+    Off,
+}
+
+impl Default for Severity {
+    fn default() -> Self {
+        Severity::Error
+    }
+}
+
 #[derive(
     Debug,
     Clone,
@@ -28,23 +52,117 @@ pub enum Selector {
     MissingModelTags,
     MissingSourceDescriptions,
     MissingSourceTableDescriptions,
+    /// See `check::sources::missing_source_column_descriptions`.
+    MissingSourceColumnDescriptions,
+    #[strum(props(severity = "warn"))]
     DirectJoinToSource,
     #[strum(props(fixable = "true"))]
     MissingPropertiesFile,
     DuplicateSources,
+    #[strum(props(severity = "warn"))]
     ModelFanout,
+    #[strum(props(severity = "warn"))]
     RootModels,
     UnusedSources,
     MissingPrimaryKey,
     MissingSourceFreshness,
+    #[strum(props(severity = "warn"))]
     MultipleSourcesJoined,
+    #[strum(props(severity = "warn"))]
     RejoiningOfUpstreamConcepts,
+    #[strum(props(severity = "warn"))]
+    LayerDirectionViolation,
     SourceFanout,
     PublicModelsWithoutContract,
+    DeadModel,
     // this is fixable, but right now it doesn't work right
     // if two models have the same patch path
     #[strum(props(fixable = "false"))]
     ModelsSeparateFromPropertiesFile,
+    CircularDependencies,
+    /// Fixable by widening the upstream model's `access` to `public`, but only when
+    /// `Config::allow_unsafe_exposure_fixes` is also set -- see
+    /// `check::exposures::exposure_dependent_on_private_model`.
+    #[strum(props(fixable = "true"))]
+    ExposureDependentOnPrivateModel,
+    #[strum(props(fixable = "true", severity = "warn"))]
+    ExposureParentsMaterializations,
+    /// See `check::exposures::exposure_missing_description`.
+    ExposureMissingDescription,
+    /// See `check::exposures::exposure_parents_staging`. Uses the same `config.layers`
+    /// classification as `LayerDirectionViolation`; skipped entirely if `layers` isn't
+    /// configured.
+    #[strum(props(severity = "warn"))]
+    ExposureParentsStaging,
+    /// See `check::exposures::exposure_missing_maturity_or_type`.
+    ExposureMissingMaturityOrType,
+    /// See `check::docs::duplicate_docs`.
+    #[strum(props(fixable = "true"))]
+    DuplicateDocsBlock,
+}
+
+impl Selector {
+    /// The severity a selector's findings have unless overridden by `Config::severity`.
+    pub fn default_severity(&self) -> Severity {
+        match self.get_str("severity") {
+            Some("warn") => Severity::Warn,
+            Some("info") => Severity::Info,
+            _ => Severity::Error,
+        }
+    }
+}
+
+/// A per-selector severity override, e.g. `{ selector = "model_fanout", severity = "warn" }`.
+/// Scoping it to a cohort of models -- by file path, tag, or fqn prefix -- targets the
+/// override at those models specifically rather than changing the selector everywhere;
+/// an override with every scope field left empty (the default) applies globally.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SeverityOverride {
+    pub selector: Selector,
+    pub severity: Severity,
+    /// Substrings of the model's `original_file_path`, at least one of which it must
+    /// contain. Entries are plain substrings, not glob patterns, and the model's name is
+    /// not matched against them. Empty matches any model.
+    #[serde(default)]
+    pub paths: Vec<String>,
+    /// Tags (`model.config.tags` in the manifest) a model must carry at least one of.
+    /// Empty matches any model.
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Prefixes matched against the model's `unique_id` (e.g.
+    /// `model.my_project.staging`). The manifest schema this crate reads doesn't expose
+    /// a separate fqn field, but `unique_id` carries the same package/folder breadcrumb
+    /// fqn would, so it stands in for one here. Empty matches any model.
+    #[serde(default)]
+    pub fqn_prefixes: Vec<String>,
+}
+
+impl SeverityOverride {
+    /// Whether this override has no scope restriction, i.e. it applies to every model.
+    fn is_global(&self) -> bool {
+        self.paths.is_empty() && self.tags.is_empty() && self.fqn_prefixes.is_empty()
+    }
+
+    fn matches(&self, scope: ModelScope) -> bool {
+        let path = scope.path.to_string_lossy();
+        (self.paths.is_empty() || self.paths.iter().any(|p| path.contains(p.as_str())))
+            && (self.tags.is_empty() || self.tags.iter().any(|t| scope.tags.contains(t)))
+            && (self.fqn_prefixes.is_empty()
+                || self
+                    .fqn_prefixes
+                    .iter()
+                    .any(|prefix| scope.unique_id.starts_with(prefix.as_str())))
+    }
+}
+
+/// The model attributes a scoped `SeverityOverride` is matched against. Kept as
+/// borrowed primitives rather than a manifest node type so `config` doesn't need a
+/// dependency on `dbt_schemas`; callers build one from whatever model type they hold.
+#[derive(Debug, Clone, Copy)]
+pub struct ModelScope<'a> {
+    pub path: &'a Path,
+    pub tags: &'a [String],
+    pub unique_id: &'a str,
 }
 
 #[derive(Debug, Error)]
@@ -171,8 +289,15 @@ pub struct Config {
     pub fixable: Vec<Selector>,
     #[serde(default)]
     pub unfixable: Vec<Selector>,
+    #[serde(default)]
+    pub severity: Vec<SeverityOverride>,
     #[serde(skip)]
     pub fix: bool,
+    /// When set, failures are attributed to whoever `git blame`/`git log` says last
+    /// touched the relevant file (see `ownership::BlameCache`). Off by default since it
+    /// spawns `git` subprocesses per failing file, which isn't free on a large project.
+    #[serde(skip)]
+    pub blame: bool,
 
     #[serde(skip)]
     pub project_dir: Option<PathBuf>,
@@ -187,8 +312,92 @@ pub struct Config {
     pub required_tests: Vec<String>,
     #[serde(default)]
     pub render_descriptions: bool,
+    /// Whether a missing column description may be inherited from a direct parent only,
+    /// or from anywhere in the upstream lineage (see `DbtGraph::ancestors`).
+    #[serde(default = "default_column_inheritance_mode")]
+    pub column_inheritance_mode: ColumnInheritanceMode,
+    /// Caps how many BFS levels `column_inheritance_mode = transitive_nearest_ancestor`
+    /// walks upstream before giving up. `None` (the default) means unlimited.
+    #[serde(default)]
+    pub max_inheritance_depth: Option<usize>,
+    /// How `--fix` writes staged changes to disk. Defaults to `Rust` -- a format-
+    /// preserving in-crate editor (see `writeback::splice`, falling back to a full
+    /// `dbt_serde_yaml` round-trip only for edits too wide for it) -- so a project never
+    /// needs a Python/ruamel.yaml install just to run `--fix`. `Python` remains available
+    /// for projects relying on its specific ruamel.yaml formatting.
     #[serde(default = "default_writeback")]
     pub writeback: WritebackMethod,
+    /// The layout `NormalizePropertiesLayout` fixes enforce. Unused unless
+    /// `Selector::MissingPropertiesFile`-style layout checks are selected.
+    #[serde(default = "default_properties_layout")]
+    pub properties_layout: ModelPropertiesLayout,
+    /// Whether `MoveModelFile`/`MovePropertiesFile` writeback may clobber an existing
+    /// destination (overwriting a `.sql`/`.yml` file, or merging into a property file
+    /// that already defines the same model). Off by default: a collision fails the run
+    /// with `WriteBackError::DestinationConflict` instead of silently losing data.
+    #[serde(default)]
+    pub overwrite_on_move: bool,
+    /// Whether `exposure_dependent_on_private_model`'s fix may widen a model's `access`
+    /// to `public` to clear the failure. Off by default: unlike a description or a missing
+    /// test, promoting access is a governance decision (it changes who's allowed to select
+    /// from the model) that shouldn't happen silently just because `--fix` was passed.
+    #[serde(default)]
+    pub allow_unsafe_exposure_fixes: bool,
+    /// YAML serialization style the Rust writeback backend renders property files in
+    /// (see `writeback::properties::render_property_file_styled`). Defaults to whatever
+    /// `dbt_serde_yaml::to_string` already produces, so a project that never sets this
+    /// sees no change in output.
+    #[serde(default)]
+    pub properties_format: PropertiesFormat,
+
+    /// Ordered layer names from furthest upstream to furthest downstream, e.g.
+    /// `["staging", "intermediate", "marts"]`. Empty disables `LayerDirectionViolation`.
+    #[serde(default)]
+    pub layers: Vec<String>,
+    /// Name-prefix or folder-path substrings that identify a model as belonging to a
+    /// layer, keyed by the layer name (must match an entry in `layers`).
+    #[serde(default)]
+    pub layer_patterns: std::collections::BTreeMap<String, Vec<String>>,
+
+    /// Glob patterns (resolved relative to `project_dir`) a model/source's file must
+    /// match to be linted at all. Empty (the default) means "everything not excluded" --
+    /// see `exclude_paths`.
+    #[serde(default)]
+    pub include: Vec<String>,
+    /// Glob patterns (resolved relative to `project_dir`) whose matches are skipped
+    /// entirely, e.g. a vendored or staging-only directory. Checked before `include`, so
+    /// excluding a file also removes it even if some `include` pattern matches it too.
+    #[serde(default)]
+    pub exclude_paths: Vec<String>,
+
+    /// How many models `check_all_with_report` may check concurrently within a single
+    /// topological level. `1` (the default) checks strictly in `nodes_in_dag_order`,
+    /// which is what keeps model-check output byte-for-byte reproducible run to run.
+    /// Anything greater processes the DAG level-by-level (see
+    /// `check::nodes_in_dag_levels`) and checks each level's models in parallel; output
+    /// ordering is still deterministic (each level is visited in a stable order and
+    /// rayon's indexed `collect` preserves it), but it's an extra knob worth opting into
+    /// deliberately rather than silently.
+    #[serde(default = "default_parallelism")]
+    pub parallelism: usize,
+
+    /// Which origin `missing_source_table_description`/`missing_source_column_descriptions`
+    /// prefer when fixing a source: a downstream model that already documents it, or a
+    /// sibling source table exposing the same `identifier` (see
+    /// `osmosis::resolve_source_col_desc`).
+    #[serde(default)]
+    pub source_description_inheritance_direction: SourceInheritanceDirection,
+    /// What to do when the downstream-model and sibling-source candidates disagree.
+    #[serde(default)]
+    pub source_description_conflict_policy: SourceInheritanceConflictPolicy,
+
+    /// Descriptions treated as if missing (case-insensitive, after trimming) -- e.g. a
+    /// placeholder left behind by a generator or a half-written docstring. Matched by
+    /// `osmosis::valid_description` and `check::columns::missing_description`, and
+    /// never inherited from an upstream model/source (see
+    /// `osmosis::column_description_at`/`node_level_description_at`).
+    #[serde(default = "default_invalid_descriptions")]
+    pub invalid_descriptions: Vec<String>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
@@ -196,10 +405,153 @@ pub struct Config {
 pub enum WritebackMethod {
     Python,
     Rust,
+    /// Like `Rust`, but instead of writing the staged changes to disk, prints a
+    /// `git apply`-compatible unified diff per file and leaves the project untouched --
+    /// see `writeback::plan::plan_model_changes`, whose staging pass this reuses so the
+    /// diff is guaranteed to match what `Rust` would have written.
+    Diff,
 }
 
 fn default_writeback() -> WritebackMethod {
-    WritebackMethod::Python
+    WritebackMethod::Rust
+}
+
+/// Where a model's properties file should live, enforced as a lint-fix by
+/// `ModelChange::NormalizePropertiesLayout`. Mirrors the handful of layouts dsync's
+/// schema-driven file generation supports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ModelPropertiesLayout {
+    /// One properties file per model, named `<model>.yml` next to its `.sql` file.
+    PerModel,
+    /// A single `_models.yml` shared by every model in a folder.
+    PerFolder,
+}
+
+fn default_properties_layout() -> ModelPropertiesLayout {
+    ModelPropertiesLayout::PerFolder
+}
+
+/// How far upstream `missing_column_description` looks for an inherited description.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ColumnInheritanceMode {
+    /// Only consider a model's direct parents.
+    DirectParent,
+    /// Walk the full upstream lineage breadth-first (see `DbtGraph::ancestors`) and use
+    /// the nearest depth with a description, same as `DirectParent` but not stopping at
+    /// depth 1.
+    TransitiveNearestAncestor,
+}
+
+fn default_column_inheritance_mode() -> ColumnInheritanceMode {
+    ColumnInheritanceMode::TransitiveNearestAncestor
+}
+
+/// Where a source's description fix looks for a value to copy, mirroring
+/// `ColumnInheritanceMode` for models. See `osmosis::resolve_source_col_desc`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SourceInheritanceDirection {
+    /// Prefer a downstream model's description: sources are usually documented after
+    /// the models selecting from them, so the model is likelier to carry the real text.
+    #[default]
+    Downstream,
+    /// Prefer another source table sharing the same `identifier` (e.g. the same
+    /// physical table registered under two source names).
+    Sibling,
+}
+
+/// What to do when the downstream-model and sibling-source candidate descriptions
+/// disagree, mirroring `InheritedValue::Ambiguous`'s "don't guess" behavior for models.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SourceInheritanceConflictPolicy {
+    /// Skip the fix and report it as ambiguous (default).
+    #[default]
+    SkipAmbiguous,
+    /// Take whichever source `source_description_inheritance_direction` prefers, even
+    /// though the other direction disagrees.
+    PreferDirection,
+}
+
+/// How `render_property_file_styled` quotes plain scalar strings it writes. Existing
+/// quoting on values that pass through unmodified is never touched -- this only governs
+/// values the Rust writeback backend itself produces (e.g. a fixed-up description).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum QuotePolicy {
+    /// Leave `dbt_serde_yaml`'s own quoting choice alone.
+    Preserve,
+    /// Always wrap in double quotes, even when YAML wouldn't require it.
+    AlwaysDouble,
+}
+
+fn default_quote_policy() -> QuotePolicy {
+    QuotePolicy::Preserve
+}
+
+/// Whether `render_property_file_styled` keeps a property file's existing key order or
+/// normalizes every model/column to dbt's canonical field order
+/// (`name`/`description`/`columns`/extras).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KeyOrder {
+    /// Re-sort every model/column's keys into `name`, `description`, `columns`/
+    /// `data_tests`, then any `extras` alphabetically -- what `ModelProperty`/
+    /// `ColumnProperty`'s derived `Serialize` impl already emits, so this is a no-op.
+    CanonicalDbtOrder,
+    /// Leave keys in whatever order the source document had them in. `ModelProperty`/
+    /// `ColumnProperty` don't currently retain a parsed entry's original key order (only
+    /// its values survive the round trip), so until that's tracked this behaves the same
+    /// as `CanonicalDbtOrder` -- flagged here rather than silently pretending to honor it.
+    PreserveOriginal,
+}
+
+fn default_key_order() -> KeyOrder {
+    KeyOrder::CanonicalDbtOrder
+}
+
+fn default_indent_width() -> usize {
+    2
+}
+
+/// YAML serialization style for property files written by the Rust writeback backend
+/// (see `writeback::properties::render_property_file_styled`). Every field defaults to
+/// matching `dbt_serde_yaml`'s own output, so a project that doesn't set `[properties_format]`
+/// sees the same bytes it always has.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct PropertiesFormat {
+    #[serde(default = "default_indent_width")]
+    pub indent_width: usize,
+    /// Rewrite a `description` value longer than `description_wrap_threshold` into a
+    /// folded block scalar (`>-`) wrapped across multiple physical lines instead of one
+    /// long quoted line. Folded style re-joins the lines back into the same single
+    /// logical string on read, so this only changes how the file looks, not the value.
+    #[serde(default)]
+    pub wrap_long_descriptions: bool,
+    #[serde(default = "default_description_wrap_threshold")]
+    pub description_wrap_threshold: usize,
+    #[serde(default = "default_quote_policy")]
+    pub quote_policy: QuotePolicy,
+    #[serde(default = "default_key_order")]
+    pub key_order: KeyOrder,
+}
+
+fn default_description_wrap_threshold() -> usize {
+    80
+}
+
+impl Default for PropertiesFormat {
+    fn default() -> Self {
+        PropertiesFormat {
+            indent_width: default_indent_width(),
+            wrap_long_descriptions: false,
+            description_wrap_threshold: default_description_wrap_threshold(),
+            quote_policy: default_quote_policy(),
+            key_order: default_key_order(),
+        }
+    }
 }
 
 impl Default for Config {
@@ -209,12 +561,28 @@ impl Default for Config {
             exclude: Vec::new(),
             fixable: default_fixable(),
             unfixable: Vec::new(),
+            severity: Vec::new(),
             fix: false,
+            blame: false,
             project_dir: None,
             model_fanout_threshold: default_model_fanout_threshold(),
             required_tests: Vec::new(),
             render_descriptions: false,
+            column_inheritance_mode: default_column_inheritance_mode(),
+            max_inheritance_depth: None,
             writeback: default_writeback(),
+            properties_layout: default_properties_layout(),
+            overwrite_on_move: false,
+            allow_unsafe_exposure_fixes: false,
+            properties_format: PropertiesFormat::default(),
+            layers: Vec::new(),
+            layer_patterns: std::collections::BTreeMap::new(),
+            include: Vec::new(),
+            exclude_paths: Vec::new(),
+            parallelism: default_parallelism(),
+            source_description_inheritance_direction: SourceInheritanceDirection::default(),
+            source_description_conflict_policy: SourceInheritanceConflictPolicy::default(),
+            invalid_descriptions: default_invalid_descriptions(),
         }
     }
 }
@@ -231,11 +599,58 @@ impl Config {
             && !self.unfixable.contains(&selector)
     }
 
+    /// The effective severity for `selector`: an explicit global override if configured,
+    /// otherwise the selector's own default. Scoped overrides (see
+    /// `SeverityOverride::paths`/`tags`/`fqn_prefixes`) are ignored here since there's no
+    /// model to match them against -- use `severity_for_model` when one is available.
+    pub fn severity(&self, selector: Selector) -> Severity {
+        self.severity
+            .iter()
+            .find(|o| o.selector == selector && o.is_global())
+            .map(|o| o.severity)
+            .unwrap_or_else(|| selector.default_severity())
+    }
+
+    /// Like `severity`, but also considers overrides scoped to `scope` by path, tag, or
+    /// fqn prefix. A scoped override that matches `scope` takes precedence over a global
+    /// one for the same selector.
+    pub fn severity_for_model(&self, selector: Selector, scope: ModelScope) -> Severity {
+        let matching: Vec<&SeverityOverride> = self
+            .severity
+            .iter()
+            .filter(|o| o.selector == selector && o.matches(scope))
+            .collect();
+        matching
+            .iter()
+            .find(|o| !o.is_global())
+            .or_else(|| matching.iter().find(|o| o.is_global()))
+            .map(|o| o.severity)
+            .unwrap_or_else(|| selector.default_severity())
+    }
+
+    /// Whether `path` (as found on a manifest node, e.g. `original_file_path` -- already
+    /// relative to `project_dir`) should be linted: `false` if it matches any
+    /// `exclude_paths` glob, or if `include` is non-empty and `path` matches none of its
+    /// globs. An unparseable glob pattern is treated as never matching rather than
+    /// failing the run.
+    pub fn should_lint_path(&self, path: &Path) -> bool {
+        if self.exclude_paths.iter().any(|pattern| glob_matches(pattern, path)) {
+            return false;
+        }
+
+        self.include.is_empty() || self.include.iter().any(|pattern| glob_matches(pattern, path))
+    }
+
     pub fn with_fix(mut self, enable: bool) -> Self {
         self.fix = enable;
         self
     }
 
+    pub fn with_blame(mut self, enable: bool) -> Self {
+        self.blame = enable;
+        self
+    }
+
     pub fn to_str(&self) -> String {
         toml::to_string_pretty(self).expect("Failed to serialize Config to TOML")
     }
@@ -246,6 +661,12 @@ impl Config {
     }
 }
 
+fn glob_matches(pattern: &str, path: &Path) -> bool {
+    Glob::new(pattern)
+        .map(|glob| glob.compile_matcher().is_match(path))
+        .unwrap_or(false)
+}
+
 fn default_select() -> Vec<Selector> {
     Selector::iter().collect()
 }
@@ -254,12 +675,20 @@ fn default_model_fanout_threshold() -> usize {
     3
 }
 
+fn default_parallelism() -> usize {
+    1
+}
+
 fn default_fixable() -> Vec<Selector> {
     Selector::iter()
         .filter(|s| s.get_str("fixable") == Some("true"))
         .collect()
 }
 
+fn default_invalid_descriptions() -> Vec<String> {
+    vec!["TBD".to_string(), "FILL ME OUT".to_string()]
+}
+
 fn validate_keys(table: &toml::value::Table) -> Result<(), ConfigError> {
     let mut unknown_messages = Vec::new();
 
@@ -296,6 +725,17 @@ fn find_suggestion(unknown: &str) -> Option<&'static str> {
     if distance <= 3 { Some(candidate) } else { None }
 }
 
+/// Like `find_suggestion`, but against `Selector` variant names instead of `Config`
+/// field names -- used to turn a typo'd selector (e.g. in an inline suppression
+/// directive, see `suppressions`) into a "did you mean" hint.
+pub(crate) fn find_selector_suggestion(unknown: &str) -> Option<&'static str> {
+    let (candidate, distance) = Selector::iter()
+        .map(|candidate| (candidate.as_ref(), levenshtein(unknown, candidate.as_ref())))
+        .min_by_key(|(_, distance)| *distance)?;
+
+    if distance <= 3 { Some(candidate) } else { None }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -408,6 +848,110 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_severity_global_override() {
+        let config = Config {
+            severity: vec![SeverityOverride {
+                selector: Selector::ModelFanout,
+                severity: Severity::Off,
+                paths: Vec::new(),
+                tags: Vec::new(),
+                fqn_prefixes: Vec::new(),
+            }],
+            ..Default::default()
+        };
+
+        assert_eq!(config.severity(Selector::ModelFanout), Severity::Off);
+        assert_eq!(
+            config.severity(Selector::DirectJoinToSource),
+            Selector::DirectJoinToSource.default_severity(),
+            "selectors without an override keep their default"
+        );
+    }
+
+    #[test]
+    fn test_severity_for_model_scoped_override() {
+        let config = Config {
+            severity: vec![
+                SeverityOverride {
+                    selector: Selector::ModelFanout,
+                    severity: Severity::Error,
+                    paths: Vec::new(),
+                    tags: Vec::new(),
+                    fqn_prefixes: Vec::new(),
+                },
+                SeverityOverride {
+                    selector: Selector::ModelFanout,
+                    severity: Severity::Off,
+                    paths: vec!["staging".to_string()],
+                    tags: Vec::new(),
+                    fqn_prefixes: Vec::new(),
+                },
+            ],
+            ..Default::default()
+        };
+
+        let staging_path = PathBuf::from("models/staging/stg_orders.sql");
+        let staging = ModelScope {
+            path: &staging_path,
+            tags: &[],
+            unique_id: "model.jaffle_shop.staging.stg_orders",
+        };
+        assert_eq!(
+            config.severity_for_model(Selector::ModelFanout, staging),
+            Severity::Off,
+            "scoped override should take precedence for matching models"
+        );
+
+        let marts_path = PathBuf::from("models/marts/fct_orders.sql");
+        let marts = ModelScope {
+            path: &marts_path,
+            tags: &[],
+            unique_id: "model.jaffle_shop.marts.fct_orders",
+        };
+        assert_eq!(
+            config.severity_for_model(Selector::ModelFanout, marts),
+            Severity::Error,
+            "models outside the scope fall back to the global override"
+        );
+    }
+
+    #[test]
+    fn test_severity_for_model_tag_and_fqn_scope() {
+        let config = Config {
+            severity: vec![SeverityOverride {
+                selector: Selector::DirectJoinToSource,
+                severity: Severity::Warn,
+                paths: Vec::new(),
+                tags: vec!["legacy".to_string()],
+                fqn_prefixes: vec!["model.jaffle_shop.deprecated".to_string()],
+            }],
+            ..Default::default()
+        };
+
+        let path = PathBuf::from("models/deprecated/old_model.sql");
+        let legacy = ModelScope {
+            path: &path,
+            tags: &["legacy".to_string()],
+            unique_id: "model.jaffle_shop.deprecated.old_model",
+        };
+        assert_eq!(
+            config.severity_for_model(Selector::DirectJoinToSource, legacy),
+            Severity::Warn
+        );
+
+        let not_tagged = ModelScope {
+            path: &path,
+            tags: &[],
+            unique_id: "model.jaffle_shop.deprecated.old_model",
+        };
+        assert_eq!(
+            config.severity_for_model(Selector::DirectJoinToSource, not_tagged),
+            Selector::DirectJoinToSource.default_severity(),
+            "missing the required tag means the scoped override doesn't apply"
+        );
+    }
+
     #[test]
     fn test_resolve_target_override_jaffle_shop() {
         use dbt_jinja_utils::invocation_args::InvocationArgs;
@@ -436,4 +980,26 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn should_lint_path_excludes_before_considering_include() {
+        let config = Config {
+            include: vec!["models/staging/**".to_string()],
+            exclude_paths: vec!["models/staging/vendor/**".to_string()],
+            ..Default::default()
+        };
+
+        assert!(config.should_lint_path(Path::new("models/staging/stg_orders.sql")));
+        assert!(!config.should_lint_path(Path::new("models/staging/vendor/stg_raw.sql")));
+        assert!(
+            !config.should_lint_path(Path::new("models/marts/fct_orders.sql")),
+            "not matched by any include pattern"
+        );
+    }
+
+    #[test]
+    fn should_lint_path_with_no_patterns_lints_everything() {
+        let config = Config::default();
+        assert!(config.should_lint_path(Path::new("models/anything.sql")));
+    }
 }
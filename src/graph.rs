@@ -1,7 +1,9 @@
 use dbt_schemas::schemas::manifest::DbtManifestV12;
+use fixedbitset::FixedBitSet;
 use petgraph::Direction;
+use petgraph::algo::{has_path_connecting, tarjan_scc, toposort};
 use petgraph::graph::{Graph, NodeIndex};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 pub struct DbtGraph {
     pub graph: Graph<String, ()>,
@@ -26,6 +28,164 @@ impl DbtGraph {
             .flatten()
             .filter_map(|n| self.graph.node_weight(n).cloned())
     }
+
+    /// Breadth-first ancestors of `uid`, grouped by depth: index 0 is `uid`'s direct
+    /// parents, index 1 is their parents not already seen, and so on. Each level is
+    /// sorted by UID so callers that need to process same-depth ancestors in a
+    /// deterministic order (e.g. to surface an ambiguous-inheritance conflict
+    /// reproducibly) don't depend on the graph's internal edge order. Cycle-safe via a
+    /// `visited` set; `max_depth`, if set, stops after that many levels.
+    pub fn ancestors(&self, uid: &str, max_depth: Option<usize>) -> Vec<Vec<String>> {
+        let mut visited: HashSet<String> = HashSet::from([uid.to_string()]);
+        let mut frontier: Vec<String> = self.parents(uid).collect();
+        for id in &frontier {
+            visited.insert(id.clone());
+        }
+
+        let mut levels = Vec::new();
+        while !frontier.is_empty() {
+            let mut next_frontier = Vec::new();
+            for ancestor_id in &frontier {
+                for parent in self.parents(ancestor_id) {
+                    if visited.insert(parent.clone()) {
+                        next_frontier.push(parent);
+                    }
+                }
+            }
+
+            frontier.sort();
+            levels.push(frontier);
+
+            if max_depth.is_some_and(|max| levels.len() >= max) {
+                break;
+            }
+            frontier = next_frontier;
+        }
+
+        levels
+    }
+
+    /// Breadth-first descendants of `uid`, grouped by depth, mirroring [`ancestors`]
+    /// but walking children instead of parents -- used for sources, which have no
+    /// `depends_on` of their own to walk upstream from.
+    ///
+    /// [`ancestors`]: DbtGraph::ancestors
+    pub fn descendants(&self, uid: &str, max_depth: Option<usize>) -> Vec<Vec<String>> {
+        let mut visited: HashSet<String> = HashSet::from([uid.to_string()]);
+        let mut frontier: Vec<String> = self.children(uid).collect();
+        for id in &frontier {
+            visited.insert(id.clone());
+        }
+
+        let mut levels = Vec::new();
+        while !frontier.is_empty() {
+            let mut next_frontier = Vec::new();
+            for descendant_id in &frontier {
+                for child in self.children(descendant_id) {
+                    if visited.insert(child.clone()) {
+                        next_frontier.push(child);
+                    }
+                }
+            }
+
+            frontier.sort();
+            levels.push(frontier);
+
+            if max_depth.is_some_and(|max| levels.len() >= max) {
+                break;
+            }
+            frontier = next_frontier;
+        }
+
+        levels
+    }
+
+    /// Models in dependency order, parents before children, via `petgraph::algo::toposort`.
+    /// Returns `None` if the graph contains a cycle -- callers that need a definite
+    /// ordering should check [`DbtGraph::find_cycles`] first and surface that as an
+    /// error rather than treating `None` as "no models".
+    pub fn topo_order(&self) -> Option<Vec<String>> {
+        let order = toposort(&self.graph, None).ok()?;
+        Some(
+            order
+                .into_iter()
+                .filter_map(|idx| self.graph.node_weight(idx).cloned())
+                .collect(),
+        )
+    }
+
+    /// Every strongly connected component of more than one node, reported as an ordered
+    /// model-id chain suitable for a `"X → Y → X"`-style message (last element repeats
+    /// the first to make the cycle explicit). Uses `petgraph::algo::tarjan_scc`, which
+    /// also reports single-node components, so those are filtered out -- a lone node is
+    /// only a cycle if it has a self-edge, which `child_map` never produces.
+    pub fn find_cycles(&self) -> Vec<Vec<String>> {
+        tarjan_scc(&self.graph)
+            .into_iter()
+            .filter(|scc| scc.len() > 1)
+            .map(|scc| self.cycle_path_within(&scc))
+            .collect()
+    }
+
+    /// Walks a strongly connected component (as returned by `tarjan_scc`) into a single
+    /// concrete cycle path: start at the lowest-index member, repeatedly step to the
+    /// lowest-index outgoing neighbor still in the component, and stop (closing the
+    /// loop back to whichever member is revisited first). Deterministic so the same
+    /// graph always reports the same path, and guaranteed to terminate since each step
+    /// either visits a new member or closes the cycle.
+    fn cycle_path_within(&self, scc: &[NodeIndex]) -> Vec<String> {
+        let members: HashSet<NodeIndex> = scc.iter().copied().collect();
+        let mut start = scc[0];
+        for &member in scc {
+            if member < start {
+                start = member;
+            }
+        }
+
+        let mut path = vec![start];
+        let mut seen: HashMap<NodeIndex, usize> = HashMap::from([(start, 0)]);
+        let mut current = start;
+        loop {
+            let mut next = None;
+            for neighbor in self.graph.neighbors_directed(current, Direction::Outgoing) {
+                if members.contains(&neighbor) && next.is_none_or(|n| neighbor < n) {
+                    next = Some(neighbor);
+                }
+            }
+            let Some(next) = next else {
+                // No outgoing edge stays within the component; shouldn't happen for a
+                // genuine SCC of size > 1, but close the loop rather than panicking.
+                break;
+            };
+
+            if let Some(&first_seen) = seen.get(&next) {
+                path = path[first_seen..].to_vec();
+                path.push(next);
+                break;
+            }
+
+            seen.insert(next, path.len());
+            path.push(next);
+            current = next;
+        }
+
+        path.into_iter()
+            .filter_map(|idx| self.graph.node_weight(idx).cloned())
+            .collect()
+    }
+
+    /// Shortest path (by hop count) from `from` to `to`, inclusive of both endpoints.
+    /// Returns `None` if either node is unknown or they aren't connected. Used to explain
+    /// *why* a graph-derived check flagged a model, not just which nodes it involves.
+    pub fn shortest_path(&self, from: &str, to: &str) -> Option<Vec<String>> {
+        let (&from_idx, &to_idx) = (self.index.get(from)?, self.index.get(to)?);
+        let (_, path) = petgraph::algo::astar(&self.graph, from_idx, |n| n == to_idx, |_| 1, |_| 0)?;
+        Some(
+            path.into_iter()
+                .filter_map(|idx| self.graph.node_weight(idx).cloned())
+                .collect(),
+        )
+    }
 }
 
 impl From<&DbtManifestV12> for DbtGraph {
@@ -63,4 +223,122 @@ impl From<&DbtManifestV12> for DbtGraph {
 
         DbtGraph { graph, index }
     }
+}
+
+/// Precomputed transitive-reachability over a [`DbtGraph`], built once per check run.
+///
+/// Checks like `rejoining_of_upstream_concepts` need to answer "is there a path from
+/// q to p" for many pairs of a model's dependencies. Doing that with
+/// `petgraph::algo::has_path_connecting` per pair is O(d^2) graph walks for a model
+/// with d dependencies. Instead we compute, once, the full set of nodes reachable from
+/// every node (reach[n] = {n} union the reach sets of n's direct successors, folded in
+/// reverse topological order) so each query afterwards is an O(1) bitset lookup.
+pub struct Reachability {
+    // reach[i] is the set of node indices reachable from node i (including i itself).
+    // Empty when the graph contains a cycle and no topological order exists; callers
+    // fall back to `has_path_connecting` in that case.
+    reach: Vec<FixedBitSet>,
+}
+
+impl Reachability {
+    pub fn build(graph: &DbtGraph) -> Self {
+        let node_count = graph.graph.node_count();
+
+        let Ok(order) = toposort(&graph.graph, None) else {
+            return Reachability { reach: Vec::new() };
+        };
+
+        let mut reach = vec![FixedBitSet::with_capacity(node_count); node_count];
+        for node in order.into_iter().rev() {
+            let idx = node.index();
+            reach[idx].insert(idx);
+            for successor in graph.graph.neighbors_directed(node, Direction::Outgoing) {
+                let successor_reach = reach[successor.index()].clone();
+                reach[idx].union_with(&successor_reach);
+            }
+        }
+
+        Reachability { reach }
+    }
+
+    /// Returns whether `to` is reachable from `from` (including `from == to`).
+    pub fn reaches(&self, graph: &DbtGraph, from: &str, to: &str) -> bool {
+        let (Some(&from_idx), Some(&to_idx)) = (graph.index.get(from), graph.index.get(to)) else {
+            return false;
+        };
+
+        if self.reach.is_empty() {
+            // cyclic graph: no cached order, fall back to a direct path search
+            return has_path_connecting(&graph.graph, from_idx, to_idx, None);
+        }
+
+        self.reach[from_idx.index()].contains(to_idx.index())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn graph_from_edges(edges: &[(&str, &str)]) -> DbtGraph {
+        let mut graph = Graph::<String, ()>::new();
+        let mut index: HashMap<String, NodeIndex> = HashMap::new();
+        let mut node_idx = |graph: &mut Graph<String, ()>, index: &mut HashMap<String, NodeIndex>, id: &str| {
+            *index
+                .entry(id.to_string())
+                .or_insert_with(|| graph.add_node(id.to_string()))
+        };
+
+        for (parent, child) in edges {
+            let p = node_idx(&mut graph, &mut index, parent);
+            let c = node_idx(&mut graph, &mut index, child);
+            graph.add_edge(p, c, ());
+        }
+
+        DbtGraph { graph, index }
+    }
+
+    #[test]
+    fn topo_order_puts_parents_before_children() {
+        let graph = graph_from_edges(&[("a", "b"), ("b", "c"), ("a", "c")]);
+        let order = graph.topo_order().unwrap();
+
+        let pos = |id: &str| order.iter().position(|n| n == id).unwrap();
+        assert!(pos("a") < pos("b"));
+        assert!(pos("b") < pos("c"));
+    }
+
+    #[test]
+    fn topo_order_is_none_for_a_cyclic_graph() {
+        let graph = graph_from_edges(&[("a", "b"), ("b", "a")]);
+        assert_eq!(graph.topo_order(), None);
+    }
+
+    #[test]
+    fn find_cycles_is_empty_for_an_acyclic_graph() {
+        let graph = graph_from_edges(&[("a", "b"), ("b", "c")]);
+        assert!(graph.find_cycles().is_empty());
+    }
+
+    #[test]
+    fn find_cycles_reports_a_closed_chain() {
+        let graph = graph_from_edges(&[("a", "b"), ("b", "c"), ("c", "a")]);
+        let cycles = graph.find_cycles();
+
+        assert_eq!(cycles.len(), 1);
+        let cycle = &cycles[0];
+        assert_eq!(cycle.first(), cycle.last());
+        assert_eq!(cycle.len(), 4);
+        let members: HashSet<&String> = cycle.iter().collect();
+        assert_eq!(members.len(), 3);
+    }
+
+    #[test]
+    fn find_cycles_ignores_an_unrelated_acyclic_branch() {
+        let graph = graph_from_edges(&[("a", "b"), ("b", "a"), ("a", "c")]);
+        let cycles = graph.find_cycles();
+
+        assert_eq!(cycles.len(), 1);
+        assert!(!cycles[0].contains(&"c".to_string()));
+    }
 }
\ No newline at end of file
@@ -0,0 +1,144 @@
+//! On-disk cache for the derived [`DbtGraph`], keyed by a content hash of the manifest's
+//! `child_map` (the only part of `DbtManifestV12` that [`DbtGraph::from`] actually reads).
+//!
+//! Parsing the manifest and rebuilding the graph from scratch dominates wall-clock time
+//! on large projects, even though the graph itself is just `child_map` flattened into
+//! node/edge lists. A cold run builds the graph as usual and archives a zero-copy
+//! snapshot of it next to the manifest; a warm run with an unchanged `child_map` memory-maps
+//! that archive and deserializes straight out of it, skipping `DbtGraph::from` entirely.
+//! Any I/O failure, a cache written by a different [`CACHE_FORMAT_VERSION`], or bytes that
+//! fail `rkyv`'s validation falls back to a full rebuild rather than panicking -- this is
+//! purely an optimization and must never be load-bearing for correctness.
+
+use crate::graph::DbtGraph;
+use dbt_schemas::schemas::manifest::DbtManifestV12;
+use memmap2::Mmap;
+use petgraph::graph::{Graph, NodeIndex};
+use rkyv::{Archive, Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs::File;
+use std::hash::{Hash, Hasher};
+use std::io::Write;
+use std::path::Path;
+use thiserror::Error;
+
+/// Bumped whenever [`GraphSnapshot`]'s shape changes, so an archive written by an older
+/// binary is rejected instead of misread as the new shape.
+const CACHE_FORMAT_VERSION: u32 = 1;
+
+#[derive(Debug, Error)]
+pub enum GraphCacheError {
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("failed to archive graph cache: {0}")]
+    Archive(String),
+}
+
+/// A zero-copy-friendly snapshot of [`DbtGraph`]: node ids in index order, plus the edge
+/// list as index pairs. Rebuilding a `DbtGraph` from this is just replaying
+/// `add_node`/`add_edge` in order, with no `child_map` walk or hashing needed.
+#[derive(Debug, Archive, Serialize, Deserialize)]
+#[archive(check_bytes)]
+struct GraphSnapshot {
+    format_version: u32,
+    manifest_hash: u64,
+    node_ids: Vec<String>,
+    edges: Vec<(u32, u32)>,
+}
+
+impl GraphSnapshot {
+    fn from_graph(graph: &DbtGraph, manifest_hash: u64) -> Self {
+        let node_ids: Vec<String> = graph
+            .graph
+            .node_indices()
+            .map(|idx| graph.graph[idx].clone())
+            .collect();
+        let edges: Vec<(u32, u32)> = graph
+            .graph
+            .edge_indices()
+            .filter_map(|edge| graph.graph.edge_endpoints(edge))
+            .map(|(a, b)| (a.index() as u32, b.index() as u32))
+            .collect();
+
+        GraphSnapshot {
+            format_version: CACHE_FORMAT_VERSION,
+            manifest_hash,
+            node_ids,
+            edges,
+        }
+    }
+
+    fn into_graph(self) -> DbtGraph {
+        let mut graph = Graph::<String, ()>::new();
+        let mut index: HashMap<String, NodeIndex> = HashMap::new();
+        let mut indices: Vec<NodeIndex> = Vec::with_capacity(self.node_ids.len());
+
+        for node_id in self.node_ids {
+            let idx = graph.add_node(node_id.clone());
+            index.insert(node_id, idx);
+            indices.push(idx);
+        }
+        for (from, to) in self.edges {
+            graph.add_edge(indices[from as usize], indices[to as usize], ());
+        }
+
+        DbtGraph { graph, index }
+    }
+}
+
+/// A hash of everything `DbtGraph::from` reads from the manifest. Stable across runs as
+/// long as the project's dependency edges haven't changed, independent of unrelated
+/// manifest fields (descriptions, tags, etc.) that would otherwise cause spurious
+/// cache misses.
+fn manifest_hash(manifest: &DbtManifestV12) -> u64 {
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    manifest.child_map.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Returns the cached `DbtGraph` at `cache_path` if it exists, matches
+/// [`CACHE_FORMAT_VERSION`], and was built from a manifest with the same `child_map`.
+/// Any failure along the way (missing file, corrupted bytes, version mismatch, stale
+/// hash) returns `None` rather than propagating an error -- the caller always has a
+/// full rebuild to fall back on.
+fn try_load(cache_path: &Path, expected_hash: u64) -> Option<DbtGraph> {
+    let file = File::open(cache_path).ok()?;
+    let mmap = unsafe { Mmap::map(&file).ok()? };
+    let archived = rkyv::check_archived_root::<GraphSnapshot>(&mmap).ok()?;
+
+    if archived.format_version != CACHE_FORMAT_VERSION || archived.manifest_hash != expected_hash {
+        return None;
+    }
+
+    let snapshot: GraphSnapshot = archived.deserialize(&mut rkyv::Infallible).ok()?;
+    Some(snapshot.into_graph())
+}
+
+/// Best-effort archive of `graph` to `cache_path`; a write failure is silently dropped
+/// since it only costs the next run a cache hit, not correctness.
+fn save(cache_path: &Path, graph: &DbtGraph, hash: u64) -> Result<(), GraphCacheError> {
+    let snapshot = GraphSnapshot::from_graph(graph, hash);
+    let bytes = rkyv::to_bytes::<_, 4096>(&snapshot)
+        .map_err(|err| GraphCacheError::Archive(err.to_string()))?;
+
+    if let Some(parent) = cache_path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let mut file = File::create(cache_path)?;
+    file.write_all(&bytes)?;
+    Ok(())
+}
+
+/// Load `DbtGraph` from `cache_path` if it's still valid for `manifest`, otherwise build
+/// it with [`DbtGraph::from`] and (best-effort) refresh the cache for next time.
+pub fn load_or_build(manifest: &DbtManifestV12, cache_path: &Path) -> DbtGraph {
+    let hash = manifest_hash(manifest);
+
+    if let Some(graph) = try_load(cache_path, hash) {
+        return graph;
+    }
+
+    let graph = DbtGraph::from(manifest);
+    let _ = save(cache_path, &graph, hash);
+    graph
+}
@@ -0,0 +1,851 @@
+//! Incremental linting: skip re-checking models whose inputs haven't changed since the
+//! last run.
+//!
+//! [`dependency_hashes`] computes a stable `dependency_hash` per node, combining the
+//! node's own spec with the hashes of its direct upstream dependencies in topological
+//! order, so a change anywhere upstream invalidates every descendant. [`IncrementalCache`]
+//! persists `unique_id -> (dependency_hash, failures, pending fix changes)` between runs;
+//! [`IncrementalCache::plan`] compares freshly computed hashes against it to decide which
+//! models actually need rechecking, folding in any model downstream of a deleted upstream
+//! node (a hash alone can't capture a dependency disappearing).
+//!
+//! The cache file itself is an `rkyv` archive (see [`graph_cache`](crate::graph_cache) for
+//! the same pattern) so a warm run can mmap and validate it without a full parse. Only the
+//! lookup structure is archived directly, though: `ModelFailureEntry`/`ModelChanges` nest
+//! enums from several modules that would all need `rkyv::Archive` derived to archive
+//! directly, so each node's failures and changes are carried as JSON blobs inside the
+//! otherwise zero-copy envelope.
+//!
+//! Sources get the same treatment via [`source_fingerprint`]: since sources have no
+//! `depends_on` chain to fold into a `dependency_hash`, their fingerprint instead combines
+//! [`source_spec_digest`] (the source's own description/columns) with a digest of the
+//! cross-source state `duplicate_source`/`unused_source`/`source_fanout` read but the
+//! source's own spec doesn't capture -- every other source's `(source_name, identifier)`
+//! pair and the source's `child_map` entry. A change to either invalidates the fingerprint,
+//! so those checks are only skipped when nothing they read actually changed.
+
+use crate::change_descriptors::{ModelChanges, SourceChanges};
+use crate::check::{ModelFailureEntry, SourceFailureEntry, nodes_in_dag_order};
+use crate::config::Config;
+use crate::graph::DbtGraph;
+use dbt_schemas::schemas::manifest::{DbtManifestV12, DbtNode, ManifestSource};
+use memmap2::Mmap;
+use rkyv::{Archive, Deserialize as RkyvDeserialize, Serialize as RkyvSerialize};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs::File;
+use std::hash::{Hash, Hasher};
+use std::io::Write;
+use std::path::Path;
+use thiserror::Error;
+
+/// Bumped whenever [`CacheSnapshot`]'s shape changes, so an archive written by an older
+/// binary is rejected instead of misread as the new shape.
+const CACHE_FORMAT_VERSION: u32 = 3;
+
+#[derive(Debug, Error)]
+pub enum IncrementalCacheError {
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("failed to (de)serialize cached failures: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("incremental cache failed validation: {0}")]
+    Validation(String),
+    #[error("failed to archive incremental cache: {0}")]
+    Archive(String),
+}
+
+#[derive(Debug, Archive, RkyvSerialize, RkyvDeserialize)]
+#[archive(check_bytes)]
+struct CachedNodeSnapshot {
+    model_id: String,
+    dependency_hash: String,
+    failures_json: String,
+    /// `Some` when the model had pending `--fix` edits at cache-write time (see
+    /// `ModelChanges`); carried the same way as `failures_json` since `ModelChange`
+    /// nests enums from `writeback::properties` not worth deriving `rkyv::Archive` for.
+    changes_json: Option<String>,
+}
+
+#[derive(Debug, Archive, RkyvSerialize, RkyvDeserialize)]
+#[archive(check_bytes)]
+struct CachedSourceSnapshot {
+    source_id: String,
+    fingerprint: String,
+    failures_json: String,
+    /// `Some` when the source had pending `--fix` edits at cache-write time, carried the
+    /// same way as `CachedNodeSnapshot::changes_json`.
+    changes_json: Option<String>,
+}
+
+#[derive(Debug, Archive, RkyvSerialize, RkyvDeserialize)]
+#[archive(check_bytes)]
+struct CacheSnapshot {
+    format_version: u32,
+    nodes: Vec<CachedNodeSnapshot>,
+    sources: Vec<CachedSourceSnapshot>,
+}
+
+/// What a run needs to do with each model, decided by comparing freshly computed
+/// `dependency_hash`es against the cache.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IncrementalPlan {
+    /// Models to pass through `check_model`/`recheck_models`: new, hash-changed, or
+    /// downstream of a node whose upstream dependency was deleted.
+    pub dirty: BTreeSet<String>,
+    /// Models whose cached result can be reused as-is.
+    pub clean: BTreeSet<String>,
+}
+
+/// What a run needs to do with each source, decided by comparing freshly computed
+/// [`source_fingerprint`]s against the cache. Mirrors [`IncrementalPlan`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SourcePlan {
+    /// Sources to pass through `check_source`: new, or whose fingerprint changed because
+    /// either their own spec or the cross-source state it folds in (sibling identifiers,
+    /// `child_map`) changed.
+    pub dirty: BTreeSet<String>,
+    /// Sources whose cached result can be reused as-is.
+    pub clean: BTreeSet<String>,
+    /// Every source's freshly computed fingerprint, so a dirty source's recheck result can
+    /// be [`record`](IncrementalCache::record_source)ed under the fingerprint this plan was
+    /// computed from.
+    pub fingerprints: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct IncrementalCache {
+    nodes: BTreeMap<String, CachedNode>,
+    sources: BTreeMap<String, CachedSource>,
+}
+
+#[derive(Debug, Clone)]
+struct CachedNode {
+    dependency_hash: String,
+    failures: Vec<ModelFailureEntry>,
+    changes: Option<ModelChanges>,
+}
+
+#[derive(Debug, Clone)]
+struct CachedSource {
+    fingerprint: String,
+    failures: Vec<SourceFailureEntry>,
+    changes: Option<SourceChanges>,
+}
+
+impl IncrementalCache {
+    pub fn load(path: &Path) -> Result<Self, IncrementalCacheError> {
+        let file = File::open(path)?;
+        let mmap = unsafe { Mmap::map(&file)? };
+        let archived = rkyv::check_archived_root::<CacheSnapshot>(&mmap)
+            .map_err(|_| IncrementalCacheError::Validation("corrupt cache bytes".to_string()))?;
+
+        if archived.format_version != CACHE_FORMAT_VERSION {
+            return Err(IncrementalCacheError::Validation(format!(
+                "cache format version {} unsupported (expected {CACHE_FORMAT_VERSION})",
+                archived.format_version
+            )));
+        }
+
+        let snapshot: CacheSnapshot = archived.deserialize(&mut rkyv::Infallible).unwrap();
+        let mut nodes = BTreeMap::new();
+        for node in snapshot.nodes {
+            let failures: Vec<ModelFailureEntry> = serde_json::from_str(&node.failures_json)?;
+            let changes: Option<ModelChanges> = node
+                .changes_json
+                .as_deref()
+                .map(serde_json::from_str)
+                .transpose()?;
+            nodes.insert(
+                node.model_id,
+                CachedNode {
+                    dependency_hash: node.dependency_hash,
+                    failures,
+                    changes,
+                },
+            );
+        }
+
+        let mut sources = BTreeMap::new();
+        for source in snapshot.sources {
+            let failures: Vec<SourceFailureEntry> = serde_json::from_str(&source.failures_json)?;
+            let changes: Option<SourceChanges> = source
+                .changes_json
+                .as_deref()
+                .map(serde_json::from_str)
+                .transpose()?;
+            sources.insert(
+                source.source_id,
+                CachedSource {
+                    fingerprint: source.fingerprint,
+                    failures,
+                    changes,
+                },
+            );
+        }
+
+        Ok(IncrementalCache { nodes, sources })
+    }
+
+    pub fn write(&self, path: &Path) -> Result<(), IncrementalCacheError> {
+        let nodes = self
+            .nodes
+            .iter()
+            .map(|(model_id, cached)| {
+                Ok(CachedNodeSnapshot {
+                    model_id: model_id.clone(),
+                    dependency_hash: cached.dependency_hash.clone(),
+                    failures_json: serde_json::to_string(&cached.failures)?,
+                    changes_json: cached
+                        .changes
+                        .as_ref()
+                        .map(serde_json::to_string)
+                        .transpose()?,
+                })
+            })
+            .collect::<Result<Vec<_>, serde_json::Error>>()?;
+
+        let sources = self
+            .sources
+            .iter()
+            .map(|(source_id, cached)| {
+                Ok(CachedSourceSnapshot {
+                    source_id: source_id.clone(),
+                    fingerprint: cached.fingerprint.clone(),
+                    failures_json: serde_json::to_string(&cached.failures)?,
+                    changes_json: cached
+                        .changes
+                        .as_ref()
+                        .map(serde_json::to_string)
+                        .transpose()?,
+                })
+            })
+            .collect::<Result<Vec<_>, serde_json::Error>>()?;
+
+        let snapshot = CacheSnapshot {
+            format_version: CACHE_FORMAT_VERSION,
+            nodes,
+            sources,
+        };
+        let bytes = rkyv::to_bytes::<_, 4096>(&snapshot)
+            .map_err(|err| IncrementalCacheError::Archive(err.to_string()))?;
+
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let mut file = File::create(path)?;
+        file.write_all(&bytes)?;
+        Ok(())
+    }
+
+    /// Decide which models in `manifest` need rechecking, given `hashes` freshly
+    /// computed by [`dependency_hashes`] and the set of models downstream of a deleted
+    /// upstream node (also from [`dependency_hashes`]).
+    pub fn plan(
+        &self,
+        manifest: &DbtManifestV12,
+        hashes: &BTreeMap<String, String>,
+        force_dirty: &BTreeSet<String>,
+    ) -> IncrementalPlan {
+        let mut plan = IncrementalPlan::default();
+
+        for node_id in manifest.nodes.keys() {
+            if !matches!(manifest.nodes.get(node_id), Some(DbtNode::Model(_))) {
+                continue;
+            }
+
+            let Some(hash) = hashes.get(node_id) else {
+                continue;
+            };
+
+            let is_clean = !force_dirty.contains(node_id)
+                && self
+                    .nodes
+                    .get(node_id)
+                    .is_some_and(|cached| &cached.dependency_hash == hash);
+
+            if is_clean {
+                plan.clean.insert(node_id.clone());
+            } else {
+                plan.dirty.insert(node_id.clone());
+            }
+        }
+
+        plan
+    }
+
+    /// The cached failures for a model left untouched by [`plan`], for replaying into a
+    /// fresh `CheckResult` without re-running `check_model`.
+    pub fn cached_failures(&self, model_id: &str) -> &[ModelFailureEntry] {
+        self.nodes
+            .get(model_id)
+            .map(|cached| cached.failures.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// The cached `--fix` edits for a model left untouched by [`plan`], for replaying
+    /// into a fresh `CheckResult` so a clean cache hit doesn't drop pending fixes.
+    pub fn cached_changes(&self, model_id: &str) -> Option<&ModelChanges> {
+        self.nodes.get(model_id).and_then(|cached| cached.changes.as_ref())
+    }
+
+    /// Record a freshly (re)checked model's hash, failures, and any pending `--fix`
+    /// edits, overwriting whatever was cached for it before.
+    pub fn record(
+        &mut self,
+        model_id: String,
+        dependency_hash: String,
+        failures: Vec<ModelFailureEntry>,
+        changes: Option<ModelChanges>,
+    ) {
+        self.nodes.insert(
+            model_id,
+            CachedNode {
+                dependency_hash,
+                failures,
+                changes,
+            },
+        );
+    }
+
+    /// Drop cache entries for models no longer present in `manifest`, so a deleted
+    /// model's stale result isn't replayed if it's ever re-added with the same id.
+    pub fn prune(&mut self, manifest: &DbtManifestV12) {
+        self.nodes
+            .retain(|model_id, _| manifest.nodes.contains_key(model_id));
+        self.sources
+            .retain(|source_id, _| manifest.sources.contains_key(source_id));
+    }
+
+    /// Decide which sources in `manifest` need rechecking, by comparing a freshly
+    /// computed [`source_fingerprint`] against the cache. Unlike [`plan`](Self::plan),
+    /// this both decides *and* computes the fingerprints, since sources have no
+    /// `dependency_hashes`-style precomputation step of their own.
+    pub fn plan_sources(&self, manifest: &DbtManifestV12, config: &Config) -> SourcePlan {
+        let mut plan = SourcePlan::default();
+
+        for source in manifest.sources.values() {
+            let source_id = source.__common_attr__.unique_id.clone();
+            let fingerprint = source_fingerprint(manifest, source, config);
+
+            let is_clean = self
+                .sources
+                .get(&source_id)
+                .is_some_and(|cached| cached.fingerprint == fingerprint);
+
+            if is_clean {
+                plan.clean.insert(source_id.clone());
+            } else {
+                plan.dirty.insert(source_id.clone());
+            }
+            plan.fingerprints.insert(source_id, fingerprint);
+        }
+
+        plan
+    }
+
+    /// The cached failures for a source left untouched by [`plan_sources`](Self::plan_sources),
+    /// for replaying into a fresh `CheckResult` without re-running `check_source`.
+    pub fn cached_source_failures(&self, source_id: &str) -> &[SourceFailureEntry] {
+        self.sources
+            .get(source_id)
+            .map(|cached| cached.failures.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// The cached `--fix` edits for a source left untouched by
+    /// [`plan_sources`](Self::plan_sources).
+    pub fn cached_source_changes(&self, source_id: &str) -> Option<&SourceChanges> {
+        self.sources.get(source_id).and_then(|cached| cached.changes.as_ref())
+    }
+
+    /// Record a freshly (re)checked source's fingerprint, failures, and any pending
+    /// `--fix` edits, overwriting whatever was cached for it before.
+    pub fn record_source(
+        &mut self,
+        source_id: String,
+        fingerprint: String,
+        failures: Vec<SourceFailureEntry>,
+        changes: Option<SourceChanges>,
+    ) {
+        self.sources.insert(
+            source_id,
+            CachedSource {
+                fingerprint,
+                failures,
+                changes,
+            },
+        );
+    }
+}
+
+/// The node ids a node directly depends on, for the manifest node kinds that carry a
+/// `depends_on` (mirrors `check::nodes_in_dag_order`'s node-kind filter).
+fn depends_on(node: &DbtNode) -> Option<&Vec<String>> {
+    match node {
+        DbtNode::Model(model) => Some(&model.__base_attr__.depends_on.nodes),
+        DbtNode::Seed(seed) => Some(&seed.__base_attr__.depends_on.nodes),
+        DbtNode::Snapshot(snapshot) => Some(&snapshot.__base_attr__.depends_on.nodes),
+        DbtNode::Analysis(analysis) => Some(&analysis.__base_attr__.depends_on.nodes),
+        _ => None,
+    }
+}
+
+/// A digest of everything about `node_id` that a check could key off of, independent of
+/// its upstream dependencies: description, tags, columns, depends_on, and where it's
+/// defined in the project.
+fn node_spec_digest(node: &DbtNode) -> u64 {
+    let mut hasher = DefaultHasher::new();
+
+    match node {
+        DbtNode::Model(model) => {
+            model.__common_attr__.unique_id.hash(&mut hasher);
+            model.__common_attr__.description.hash(&mut hasher);
+            model.__common_attr__.patch_path.hash(&mut hasher);
+            model.__common_attr__.original_file_path.hash(&mut hasher);
+            model.config.tags.hash(&mut hasher);
+            model.__base_attr__.contract.enforced.hash(&mut hasher);
+            model.__base_attr__.depends_on.nodes.hash(&mut hasher);
+            model.__base_attr__.raw_code.hash(&mut hasher);
+            hash_columns(&model.__base_attr__.columns, &mut hasher);
+        }
+        DbtNode::Seed(seed) => {
+            seed.__common_attr__.unique_id.hash(&mut hasher);
+            seed.__common_attr__.description.hash(&mut hasher);
+            seed.__common_attr__.patch_path.hash(&mut hasher);
+            seed.__common_attr__.original_file_path.hash(&mut hasher);
+            seed.__base_attr__.depends_on.nodes.hash(&mut hasher);
+            hash_columns(&seed.__base_attr__.columns, &mut hasher);
+        }
+        DbtNode::Snapshot(snapshot) => {
+            snapshot.__common_attr__.unique_id.hash(&mut hasher);
+            snapshot.__common_attr__.description.hash(&mut hasher);
+            snapshot.__common_attr__.patch_path.hash(&mut hasher);
+            snapshot.__common_attr__.original_file_path.hash(&mut hasher);
+            snapshot.__base_attr__.depends_on.nodes.hash(&mut hasher);
+            hash_columns(&snapshot.__base_attr__.columns, &mut hasher);
+        }
+        DbtNode::Analysis(analysis) => {
+            analysis.__common_attr__.unique_id.hash(&mut hasher);
+            analysis.__base_attr__.depends_on.nodes.hash(&mut hasher);
+        }
+        _ => {}
+    }
+
+    hasher.finish()
+}
+
+fn hash_columns<'a>(
+    columns: impl IntoIterator<Item = &'a dbt_schemas::schemas::dbt_column::DbtColumnRef>,
+    hasher: &mut DefaultHasher,
+) {
+    let mut entries: Vec<(String, Option<String>)> = columns
+        .into_iter()
+        .map(|col| (col.as_ref().name.clone(), col.as_ref().description.clone()))
+        .collect();
+    entries.sort();
+    entries.hash(hasher);
+}
+
+/// A digest of a source table's own spec: its description and column descriptions.
+/// Sources don't go through `dependency_hashes`' topological walk (they have no
+/// `depends_on` of their own), so a model depending directly on a source folds this in
+/// as a leaf hash instead of looking one up from a prior iteration.
+fn source_spec_digest(source: &ManifestSource) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    source.__common_attr__.unique_id.hash(&mut hasher);
+    source.__common_attr__.description.hash(&mut hasher);
+    hash_columns(source.columns.iter(), &mut hasher);
+    hasher.finish()
+}
+
+/// A digest of the cross-source state `duplicate_source`/`unused_source`/`source_fanout`
+/// read but [`source_spec_digest`] doesn't capture: every *other* source's
+/// `(source_name, identifier)` pair (what `duplicate_source` scans for a collision) and
+/// this source's `child_map` entry (what `unused_source`/`source_fanout` count). Folded
+/// into [`source_fingerprint`] so a change anywhere in that cross-source state invalidates
+/// the cache even though this source's own spec didn't change.
+fn source_cross_refs_digest(manifest: &DbtManifestV12, source: &ManifestSource) -> u64 {
+    let mut hasher = DefaultHasher::new();
+
+    let mut siblings: Vec<(String, String)> = manifest
+        .sources
+        .values()
+        .filter(|other| other.__common_attr__.unique_id != source.__common_attr__.unique_id)
+        .map(|other| (other.source_name.clone(), other.identifier.clone()))
+        .collect();
+    siblings.sort();
+    siblings.hash(&mut hasher);
+
+    manifest
+        .child_map
+        .get(&source.__common_attr__.unique_id)
+        .cloned()
+        .unwrap_or_default()
+        .hash(&mut hasher);
+
+    hasher.finish()
+}
+
+/// A stable fingerprint for `source`, combining its own spec ([`source_spec_digest`]),
+/// the cross-source state that affects it ([`source_cross_refs_digest`]), and the current
+/// `Config` ([`config_digest`]). [`IncrementalCache::plan_sources`] compares this against
+/// what was cached to decide whether `check_source` can be skipped.
+pub fn source_fingerprint(manifest: &DbtManifestV12, source: &ManifestSource, config: &Config) -> String {
+    let mut hasher = DefaultHasher::new();
+    source_spec_digest(source).hash(&mut hasher);
+    source_cross_refs_digest(manifest, source).hash(&mut hasher);
+    config_digest(config).hash(&mut hasher);
+    format!("{:016x}", hasher.finish())
+}
+
+/// A digest of the parts of `Config` that influence a check's outcome (selectors,
+/// severities, required tests, layers, ...), so changing `dbt_lint.toml` invalidates
+/// every cached hash instead of silently replaying stale results computed under a
+/// different configuration. Serialized via `serde_json` rather than hand-picking fields,
+/// the same way `IncrementalCache` carries failures/changes it doesn't derive `rkyv`
+/// archiving for -- `Config` has no `Hash` impl and isn't worth adding one just for this.
+fn config_digest(config: &Config) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    serde_json::to_string(config)
+        .unwrap_or_default()
+        .hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Compute a stable per-node `dependency_hash` over every model/seed/snapshot/analysis in
+/// `manifest`, in topological order so a node's hash folds in its upstream dependencies'
+/// hashes. Also folds in a digest of `config`, so a changed selector/severity/required-test
+/// invalidates every node's hash even though none of their specs changed. Also returns the
+/// set of models transitively downstream of a node whose `depends_on` references an id no
+/// longer present in `manifest.nodes` -- a deleted dependency can change a check's outcome
+/// (e.g. `missing_required_tests`) in ways the hash chain alone won't capture, so those
+/// models are always reported dirty.
+pub fn dependency_hashes(
+    manifest: &DbtManifestV12,
+    graph: &DbtGraph,
+    config: &Config,
+) -> (BTreeMap<String, String>, BTreeSet<String>) {
+    let mut hashes: BTreeMap<String, String> = BTreeMap::new();
+    let mut deleted_upstream_of: BTreeSet<String> = BTreeSet::new();
+    let config_digest = config_digest(config);
+
+    for node_id in nodes_in_dag_order(manifest) {
+        let Some(node) = manifest.nodes.get(&node_id) else {
+            continue;
+        };
+        let Some(upstream_ids) = depends_on(node) else {
+            continue;
+        };
+
+        let mut upstream_hashes: Vec<String> = Vec::new();
+        for upstream_id in upstream_ids {
+            if let Some(hash) = hashes.get(upstream_id) {
+                upstream_hashes.push(hash.clone());
+            } else if let Some(source) = manifest.sources.get(upstream_id) {
+                upstream_hashes.push(format!("{:016x}", source_spec_digest(source)));
+            } else {
+                // neither a previously-hashed node nor a source: this dependency was
+                // deleted out from under `node_id`.
+                deleted_upstream_of.insert(node_id.clone());
+            }
+        }
+        upstream_hashes.sort();
+
+        let mut hasher = DefaultHasher::new();
+        node_spec_digest(node).hash(&mut hasher);
+        config_digest.hash(&mut hasher);
+        upstream_hashes.hash(&mut hasher);
+        hashes.insert(node_id, format!("{:016x}", hasher.finish()));
+    }
+
+    // propagate the "has a deleted upstream somewhere in its lineage" taint forward
+    // through the graph, so every transitive descendant is marked dirty too.
+    let mut frontier: Vec<String> = deleted_upstream_of.iter().cloned().collect();
+    while let Some(node_id) = frontier.pop() {
+        for child in graph.children(&node_id) {
+            if deleted_upstream_of.insert(child.clone()) {
+                frontier.push(child);
+            }
+        }
+    }
+
+    (hashes, deleted_upstream_of)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dbt_schemas::schemas::manifest::DbtNode;
+
+    fn manifest_with_upstream_downstream() -> DbtManifestV12 {
+        let mut manifest = DbtManifestV12::default();
+
+        manifest.nodes.insert(
+            "model.test.upstream".to_string(),
+            DbtNode::Model(Default::default()),
+        );
+        manifest.nodes.insert(
+            "model.test.downstream".to_string(),
+            DbtNode::Model(Default::default()),
+        );
+
+        if let Some(DbtNode::Model(upstream)) = manifest.nodes.get_mut("model.test.upstream") {
+            upstream.__common_attr__.unique_id = "model.test.upstream".to_string();
+            upstream.__common_attr__.description = Some("Upstream model".to_string());
+        }
+        if let Some(DbtNode::Model(downstream)) = manifest.nodes.get_mut("model.test.downstream") {
+            downstream.__common_attr__.unique_id = "model.test.downstream".to_string();
+            downstream.__base_attr__.depends_on.nodes = vec!["model.test.upstream".to_string()];
+        }
+
+        manifest.child_map.insert(
+            "model.test.upstream".to_string(),
+            vec!["model.test.downstream".to_string()],
+        );
+
+        manifest
+    }
+
+    #[test]
+    fn unchanged_manifest_hashes_identically_across_runs() {
+        let manifest = manifest_with_upstream_downstream();
+        let graph = DbtGraph::from(&manifest);
+        let config = Config::default();
+
+        let (first, _) = dependency_hashes(&manifest, &graph, &config);
+        let (second, _) = dependency_hashes(&manifest, &graph, &config);
+
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn upstream_change_invalidates_downstream_hash() {
+        let manifest = manifest_with_upstream_downstream();
+        let graph = DbtGraph::from(&manifest);
+        let config = Config::default();
+        let (before, _) = dependency_hashes(&manifest, &graph, &config);
+
+        let mut changed = manifest;
+        if let Some(DbtNode::Model(upstream)) = changed.nodes.get_mut("model.test.upstream") {
+            upstream.__common_attr__.description = Some("Changed description".to_string());
+        }
+        let (after, _) = dependency_hashes(&changed, &graph, &config);
+
+        assert_ne!(
+            before.get("model.test.upstream"),
+            after.get("model.test.upstream")
+        );
+        assert_ne!(
+            before.get("model.test.downstream"),
+            after.get("model.test.downstream"),
+            "downstream hash should change when upstream does"
+        );
+    }
+
+    #[test]
+    fn config_change_invalidates_every_hash() {
+        let manifest = manifest_with_upstream_downstream();
+        let graph = DbtGraph::from(&manifest);
+        let (before, _) = dependency_hashes(&manifest, &graph, &Config::default());
+
+        let changed_config = Config {
+            required_tests: vec!["unique".to_string()],
+            ..Default::default()
+        };
+        let (after, _) = dependency_hashes(&manifest, &graph, &changed_config);
+
+        assert_ne!(
+            before.get("model.test.upstream"),
+            after.get("model.test.upstream"),
+            "a config change should invalidate even a root model's hash"
+        );
+        assert_ne!(
+            before.get("model.test.downstream"),
+            after.get("model.test.downstream")
+        );
+    }
+
+    #[test]
+    fn plan_marks_only_changed_models_dirty() {
+        let manifest = manifest_with_upstream_downstream();
+        let graph = DbtGraph::from(&manifest);
+        let (hashes, force_dirty) = dependency_hashes(&manifest, &graph, &Config::default());
+
+        let mut cache = IncrementalCache::default();
+        for (model_id, hash) in &hashes {
+            cache.record(model_id.clone(), hash.clone(), Vec::new(), None);
+        }
+
+        // nothing changed: both models are clean.
+        let plan = cache.plan(&manifest, &hashes, &force_dirty);
+        assert!(plan.dirty.is_empty());
+        assert_eq!(
+            plan.clean,
+            BTreeSet::from([
+                "model.test.upstream".to_string(),
+                "model.test.downstream".to_string()
+            ])
+        );
+
+        // invalidate just the upstream model's cached hash, as if its spec changed.
+        cache.record("model.test.upstream".to_string(), "stale".to_string(), Vec::new(), None);
+        let plan = cache.plan(&manifest, &hashes, &force_dirty);
+        assert_eq!(plan.dirty, BTreeSet::from(["model.test.upstream".to_string()]));
+        assert_eq!(plan.clean, BTreeSet::from(["model.test.downstream".to_string()]));
+    }
+
+    #[test]
+    fn deleted_upstream_forces_downstream_dirty() {
+        let mut manifest = manifest_with_upstream_downstream();
+        manifest.nodes.remove("model.test.upstream");
+        let graph = DbtGraph::from(&manifest);
+
+        let (hashes, force_dirty) = dependency_hashes(&manifest, &graph, &Config::default());
+
+        assert!(force_dirty.contains("model.test.downstream"));
+        assert!(hashes.contains_key("model.test.downstream"));
+    }
+
+    #[allow(clippy::field_reassign_with_default)]
+    fn manifest_with_source() -> (DbtManifestV12, ManifestSource) {
+        let mut manifest = DbtManifestV12::default();
+        let mut source = ManifestSource::default();
+        source.identifier = "orders".to_string();
+        source.source_name = "raw".to_string();
+        source.__common_attr__.unique_id = "source.raw.orders".to_string();
+        manifest
+            .sources
+            .insert(source.__common_attr__.unique_id.clone(), source.clone());
+        (manifest, source)
+    }
+
+    #[test]
+    fn unchanged_source_fingerprints_identically_across_runs() {
+        let (manifest, source) = manifest_with_source();
+        let config = Config::default();
+
+        let first = source_fingerprint(&manifest, &source, &config);
+        let second = source_fingerprint(&manifest, &source, &config);
+
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn source_description_change_invalidates_fingerprint() {
+        let (manifest, source) = manifest_with_source();
+        let config = Config::default();
+        let before = source_fingerprint(&manifest, &source, &config);
+
+        let mut changed_source = source;
+        changed_source.__common_attr__.description = Some("Orders table".to_string());
+        let mut changed_manifest = manifest;
+        changed_manifest.sources.insert(
+            changed_source.__common_attr__.unique_id.clone(),
+            changed_source.clone(),
+        );
+
+        let after = source_fingerprint(&changed_manifest, &changed_source, &config);
+        assert_ne!(before, after);
+    }
+
+    #[test]
+    #[allow(clippy::field_reassign_with_default)]
+    fn new_sibling_source_invalidates_fingerprint() {
+        let (manifest, source) = manifest_with_source();
+        let config = Config::default();
+        let before = source_fingerprint(&manifest, &source, &config);
+
+        let mut with_sibling = manifest;
+        let mut sibling = ManifestSource::default();
+        sibling.identifier = "orders".to_string();
+        sibling.source_name = "raw".to_string();
+        sibling.__common_attr__.unique_id = "source.raw.orders_dupe".to_string();
+        with_sibling
+            .sources
+            .insert(sibling.__common_attr__.unique_id.clone(), sibling);
+
+        let after = source_fingerprint(&with_sibling, &source, &config);
+        assert_ne!(
+            before, after,
+            "a sibling source appearing should invalidate the fingerprint, since it could \
+             turn `source` into a duplicate"
+        );
+    }
+
+    #[test]
+    fn child_map_change_invalidates_fingerprint() {
+        let (manifest, source) = manifest_with_source();
+        let config = Config::default();
+        let before = source_fingerprint(&manifest, &source, &config);
+
+        let mut with_child = manifest;
+        with_child.child_map.insert(
+            source.__common_attr__.unique_id.clone(),
+            vec!["model.test.downstream".to_string()],
+        );
+
+        let after = source_fingerprint(&with_child, &source, &config);
+        assert_ne!(
+            before, after,
+            "a new downstream consumer should invalidate the fingerprint, since it affects \
+             unused_source/source_fanout"
+        );
+    }
+
+    #[test]
+    fn plan_sources_marks_only_changed_sources_dirty() {
+        let (manifest, source) = manifest_with_source();
+        let config = Config::default();
+
+        let mut cache = IncrementalCache::default();
+        let fingerprint = source_fingerprint(&manifest, &source, &config);
+        cache.record_source(
+            source.__common_attr__.unique_id.clone(),
+            fingerprint,
+            Vec::new(),
+            None,
+        );
+
+        // nothing changed: the source is clean.
+        let plan = cache.plan_sources(&manifest, &config);
+        assert!(plan.dirty.is_empty());
+        assert_eq!(
+            plan.clean,
+            BTreeSet::from([source.__common_attr__.unique_id.clone()])
+        );
+
+        // invalidate the cached fingerprint, as if the source's spec changed.
+        cache.record_source(
+            source.__common_attr__.unique_id.clone(),
+            "stale".to_string(),
+            Vec::new(),
+            None,
+        );
+        let plan = cache.plan_sources(&manifest, &config);
+        assert_eq!(
+            plan.dirty,
+            BTreeSet::from([source.__common_attr__.unique_id.clone()])
+        );
+        assert!(plan.clean.is_empty());
+    }
+
+    #[test]
+    fn prune_drops_cached_sources_no_longer_in_the_manifest() {
+        let (manifest, source) = manifest_with_source();
+
+        let mut cache = IncrementalCache::default();
+        cache.record_source(
+            source.__common_attr__.unique_id.clone(),
+            "fingerprint".to_string(),
+            Vec::new(),
+            None,
+        );
+        cache.record_source("source.raw.deleted".to_string(), "fingerprint".to_string(), Vec::new(), None);
+
+        cache.prune(&manifest);
+
+        assert_eq!(cache.sources.len(), 1);
+        assert!(cache.sources.contains_key(&source.__common_attr__.unique_id));
+    }
+}
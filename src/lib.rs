@@ -1,9 +1,21 @@
+pub mod baseline;
 pub mod change_descriptors;
 pub mod check;
 pub mod codegen;
 pub mod config;
+pub mod graph;
+pub mod graph_cache;
+pub mod incremental;
+pub mod manifest_cache;
 pub mod osmosis;
+pub mod ownership;
 pub mod project;
+pub mod reporter;
+pub mod suggest;
+pub mod suppressions;
+pub mod watch;
 pub mod writeback;
-pub use check::{CheckEvent, check_all, check_all_with_report};
-pub use project::{DbtContext, load_project_from_cli_args};
+pub use check::{
+    CheckEvent, check_all, check_all_incremental, check_all_with_graph_cache, check_all_with_report,
+};
+pub use project::{DbtContext, load_project_from_cli_args, load_project_from_cli_args_with_cache};
@@ -1,72 +1,323 @@
-use dbt_common::FsResult;
+use clap::{Arg, ArgAction, ArgMatches, Command};
+use dbt_common::{FsResult, cancellation::CancellationTokenSource};
 use dbt_lint_yaml::{
-    change_descriptors::ColumnChange,
-    check::{CheckEvent, check_all_with_report},
+    baseline::Baseline,
+    change_descriptors::{ColumnChange, ModelChange},
+    check::{
+        CheckEvent, check_all, check_all_incremental, check_all_with_graph_cache,
+        check_all_with_report,
+    },
+    codegen::write_generated_model,
     config::ConfigFile,
-    project::load_project_from_cli_args,
+    graph::DbtGraph,
+    incremental::IncrementalCache,
+    project::load_project_from_cli_args_with_cache,
+    reporter::{
+        self, GithubActionsReporter, JsonReporter, JunitReporter, Reporter, SarifReporter,
+        render_event_json_line,
+    },
+    suggest::{self, SuggestReport, SuggestedFix},
+    suppressions::Suppressions,
+    watch,
     writeback,
 };
 use std::ffi::OsString;
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum OutputFormat {
+    Human,
+    Json,
+    /// The full `CheckResult` (every model/source/exposure, passing ones included)
+    /// rather than `Json`'s flattened failures-only list.
+    JsonFull,
+    /// One NDJSON line per `CheckEvent`, printed live as each model/source/exposure is
+    /// checked instead of buffered until the run finishes -- see
+    /// `reporter::render_event_json_line`.
+    JsonLines,
+    Sarif,
+    Junit,
+    /// GitHub Actions workflow commands (`::error file=...,line=...::...`), for inline
+    /// PR annotations -- see `reporter::GithubActionsReporter`.
+    GithubActions,
+}
+
+impl OutputFormat {
+    fn parse(raw: &str) -> Self {
+        match raw {
+            "json" => OutputFormat::Json,
+            "json-full" => OutputFormat::JsonFull,
+            "json-lines" => OutputFormat::JsonLines,
+            "sarif" => OutputFormat::Sarif,
+            "junit" => OutputFormat::Junit,
+            "github-actions" => OutputFormat::GithubActions,
+            _ => OutputFormat::Human,
+        }
+    }
+}
 
 const PKG_NAME: &str = env!("CARGO_PKG_NAME");
 const PKG_VERSION: &str = env!("CARGO_PKG_VERSION");
 
-fn maybe_handle_version_override() {
-    use std::ffi::OsStr;
+/// Extra args meant for the underlying dbt parser (`--project-dir`, `--profiles-dir`,
+/// ...) rather than this binary itself -- forwarded verbatim after `--`, the same
+/// passthrough convention `cargo run -- <args for the binary>` uses. Declared the same
+/// way on every subcommand that needs it.
+fn dbt_passthrough_arg() -> Arg {
+    Arg::new("dbt_args")
+        .help("Args forwarded as-is to the underlying dbt parser, after `--`")
+        .trailing_var_arg(true)
+        .allow_hyphen_values(true)
+        .num_args(0..)
+}
+
+/// Builds the top-level command tree, following the same `cli()`-returns-a-`Command`
+/// shape cargo's own subcommands (e.g. `cargo add`) use: one `Command` per subcommand,
+/// each owning its own args plus a trailing `--` passthrough for whatever it forwards on.
+fn cli() -> Command {
+    Command::new(PKG_NAME)
+        .version(PKG_VERSION)
+        .subcommand_required(false)
+        .arg_required_else_help(false)
+        .subcommand(
+            Command::new("check")
+                .about("Lint a dbt project's YAML properties (the default command)")
+                .arg(Arg::new("verbose").long("verbose").short('v').action(ArgAction::SetTrue))
+                .arg(Arg::new("fix").long("fix").action(ArgAction::SetTrue))
+                .arg(Arg::new("format").long("format").value_name("FORMAT"))
+                .arg(Arg::new("write_baseline").long("write-baseline").value_name("PATH"))
+                .arg(Arg::new("baseline").long("baseline").value_name("PATH"))
+                .arg(Arg::new("watch").long("watch").action(ArgAction::SetTrue))
+                .arg(Arg::new("incremental").long("incremental").value_name("PATH"))
+                .arg(Arg::new("graph_cache").long("graph-cache").value_name("PATH"))
+                .arg(Arg::new("lint_extras").long("lint-extras").action(ArgAction::SetTrue))
+                .arg(Arg::new("suggest").long("suggest").action(ArgAction::SetTrue))
+                .arg(Arg::new("blame").long("blame").action(ArgAction::SetTrue))
+                .arg(Arg::new("no_cache").long("no-cache").action(ArgAction::SetTrue))
+                .arg(dbt_passthrough_arg()),
+        )
+        .subcommand(
+            Command::new("generate")
+                .alias("gen")
+                .about(
+                    "Generate a properties YAML file for one or more models from their \
+                     compiled parquet output",
+                )
+                .arg(
+                    Arg::new("select")
+                        .long("select")
+                        .value_name("MODEL")
+                        .action(ArgAction::Append)
+                        .help("Only generate for this model (repeatable); default is every model"),
+                )
+                .arg(
+                    Arg::new("force")
+                        .long("force")
+                        .action(ArgAction::SetTrue)
+                        .help("Overwrite an already-generated properties file"),
+                )
+                .arg(Arg::new("no_cache").long("no-cache").action(ArgAction::SetTrue))
+                .arg(dbt_passthrough_arg()),
+        )
+}
+
+/// Backwards compatibility: a bare invocation (or one whose first token isn't a known
+/// subcommand/top-level flag) is treated as `check`, the same way this CLI always
+/// behaved before subcommands existed.
+fn args_with_implicit_check(mut args: Vec<OsString>) -> Vec<OsString> {
+    let recognized = args.get(1).is_some_and(|arg| {
+        matches!(
+            arg.to_str(),
+            Some("check" | "generate" | "gen" | "help" | "--help" | "-h" | "--version" | "-V")
+        )
+    });
+    if !recognized {
+        args.insert(1, OsString::from("check"));
+    }
+    args
+}
+
+/// Args forwarded on to `load_project_from_cli_args_with_cache`, which expects a
+/// dbt-sa-cli-shaped invocation -- i.e. `["<prog>", "parse", ...dbt args...]`.
+fn dbt_parse_args(sub_matches: &ArgMatches) -> Vec<OsString> {
+    let mut args = vec![OsString::from(PKG_NAME), OsString::from("parse")];
+    if let Some(extra) = sub_matches.get_many::<String>("dbt_args") {
+        args.extend(extra.map(OsString::from));
+    }
+    args
+}
+
+struct CheckArgs {
+    verbose: bool,
+    fix: bool,
+    format: OutputFormat,
+    write_baseline: Option<PathBuf>,
+    baseline: Option<PathBuf>,
+    watch: bool,
+    incremental: Option<PathBuf>,
+    graph_cache: Option<PathBuf>,
+    lint_extras: bool,
+    suggest: bool,
+    blame: bool,
+    no_cache: bool,
+}
+
+impl CheckArgs {
+    fn from_matches(matches: &ArgMatches) -> Self {
+        CheckArgs {
+            verbose: matches.get_flag("verbose"),
+            fix: matches.get_flag("fix"),
+            format: matches
+                .get_one::<String>("format")
+                .map(|value| OutputFormat::parse(value))
+                .unwrap_or(OutputFormat::Human),
+            write_baseline: matches.get_one::<String>("write_baseline").map(PathBuf::from),
+            baseline: matches.get_one::<String>("baseline").map(PathBuf::from),
+            watch: matches.get_flag("watch"),
+            incremental: matches.get_one::<String>("incremental").map(PathBuf::from),
+            graph_cache: matches.get_one::<String>("graph_cache").map(PathBuf::from),
+            lint_extras: matches.get_flag("lint_extras"),
+            suggest: matches.get_flag("suggest"),
+            blame: matches.get_flag("blame"),
+            no_cache: matches.get_flag("no_cache"),
+        }
+    }
+}
 
-    let mut args = std::env::args_os();
-    // skip program name
-    let _ = args.next();
+/// Reads every properties file reachable from `patch_path`s in the manifest and warns
+/// about `extras` keys outside the known dbt schema for their level (typos like
+/// `descrption:`, tests misplaced under the wrong nesting level, and so on).
+fn lint_extras(project_dir: &std::path::Path, manifest: &dbt_schemas::schemas::manifest::DbtManifestV12) {
+    use dbt_schemas::schemas::manifest::DbtNode;
+    use std::collections::BTreeSet;
 
-    for arg in args {
-        if arg == OsStr::new("--") {
-            break;
+    let mut patch_paths: BTreeSet<PathBuf> = BTreeSet::new();
+    for node in manifest.nodes.values() {
+        if let DbtNode::Model(model) = node
+            && let Some(patch_path) = &model.__common_attr__.patch_path
+        {
+            patch_paths.insert(patch_path.clone());
         }
+    }
 
-        if arg == OsStr::new("--version") || arg == OsStr::new("-V") {
-            println!("{PKG_NAME} {PKG_VERSION}");
-            std::process::exit(0);
+    for patch_path in patch_paths {
+        let resolved = if patch_path.is_absolute() {
+            patch_path.clone()
+        } else {
+            project_dir.join(&patch_path)
+        };
+        match writeback::rust::lint_property_file(&writeback::fs::RealFs, &resolved) {
+            Ok(warnings) => {
+                for warning in warnings {
+                    match &warning.suggestion {
+                        Some(suggestion) => println!(
+                            "warning: {}: unknown key `{}` in {} (did you mean `{suggestion}`?)",
+                            resolved.display(),
+                            warning.key,
+                            warning.path
+                        ),
+                        None => println!(
+                            "warning: {}: unknown key `{}` in {}",
+                            resolved.display(),
+                            warning.key,
+                            warning.path
+                        ),
+                    }
+                }
+            }
+            Err(err) => {
+                eprintln!("Failed to lint properties file {}: {err}", resolved.display());
+            }
         }
     }
 }
 
-fn extract_shimmed_flags(args: Vec<OsString>) -> (Vec<OsString>, bool, bool) {
-    let mut verbose = false;
-    let mut fix = false;
-    let mut filtered = Vec::new();
-    let mut iter = args.into_iter();
+/// Prints a `SuggestReport` as plain text: one `blame:` line per culprit node, then one
+/// `fix:` line per suggested edit, in the order `suggest::suggest` produced them.
+fn print_suggestions(report: &SuggestReport) {
+    for (model_id, trails) in &report.blame {
+        for trail in trails {
+            println!("blame: {model_id} <- {trail}");
+        }
+    }
 
-    if let Some(program) = iter.next() {
-        filtered.push(program);
+    for fix in &report.fixes {
+        match fix {
+            SuggestedFix::ModelEdit { model_id, change } => {
+                println!("fix: {model_id}: {}", describe_model_change(change));
+            }
+            SuggestedFix::AddPrimaryKeyTest { model_id, column, .. } => {
+                println!("fix: {model_id}: add a unique + not_null data test to column `{column}`");
+            }
+            SuggestedFix::AddSourceFreshnessBlock { source_id, table_name } => {
+                println!("fix: {source_id}: add a freshness block to table `{table_name}`");
+            }
+            SuggestedFix::Manual { model_id, description } => {
+                println!("fix: {model_id}: {description}");
+            }
+        }
     }
+}
 
-    let mut passthrough = false;
-    for arg in iter {
-        if passthrough {
-            filtered.push(arg);
-            continue;
+fn describe_model_change(change: &ModelChange) -> String {
+    match change {
+        ModelChange::MovePropertiesFile { new_path, .. } => {
+            format!("move properties file to {}", new_path.display())
         }
-        if arg == "--" {
-            passthrough = true;
-            filtered.push(arg);
-            continue;
+        ModelChange::MoveModelFile { new_path, .. } => {
+            format!("move model file to {}", new_path.display())
         }
-        if arg == "--verbose" || arg == "-v" {
-            verbose = true;
-            continue;
+        ModelChange::GeneratePropertiesFile { .. } => "generate a properties file".to_string(),
+        ModelChange::ChangePropertiesFile { .. } => "update properties file".to_string(),
+        ModelChange::NormalizePropertiesLayout { expected_patch, .. } => {
+            format!("normalize properties layout to {}", expected_patch.display())
         }
-        if arg == "--fix" {
-            fix = true;
-            continue;
+    }
+}
+
+/// The default preview shown whenever `--fix` isn't passed: the fixes above are only
+/// ever computed with `config.fix` set (see `Config::is_fixable`), so previewing them
+/// means re-checking with a hypothetical fix-enabled config, then running
+/// `writeback::plan::plan_model_changes` against the real filesystem -- the exact same
+/// staging pass `--fix` would commit from -- so the printed diff is guaranteed to match
+/// what a real run would write.
+fn print_dry_run_diff(
+    project_dir: &std::path::Path,
+    model_changes: &std::collections::BTreeMap<String, dbt_lint_yaml::change_descriptors::ModelChanges>,
+    config: &dbt_lint_yaml::config::Config,
+    graph: &DbtGraph,
+) {
+    if model_changes.is_empty() {
+        println!("No fixes available.");
+        return;
+    }
+
+    let plan = match writeback::plan::plan_model_changes(
+        &writeback::fs::RealFs,
+        project_dir,
+        model_changes,
+        config,
+        graph,
+    ) {
+        Ok(plan) => plan,
+        Err(err) => {
+            eprintln!("Failed to compute fix preview: {err}");
+            return;
         }
-        if arg == "parse" {
-            // skip parse so it's backwards compatible with prior CLI
+    };
+
+    for file in &plan.files {
+        if file.diff.is_empty() {
             continue;
         }
-        filtered.push(arg);
+        for (model_id, columns) in &file.models {
+            if columns.is_empty() {
+                continue;
+            }
+            println!("  Model: {model_id} would be changed ({columns:?})");
+        }
+        print!("{}", file.diff);
     }
-
-    (filtered, verbose, fix)
 }
 
 fn report_event(event: CheckEvent<'_>, verbose: bool) {
@@ -116,42 +367,314 @@ fn report_event(event: CheckEvent<'_>, verbose: bool) {
                 }
             }
         }
+        CheckEvent::Doc(doc_result) => {
+            if doc_result.is_pass() {
+                if verbose {
+                    println!("\x1b[32msuccess:\x1b[0m {} passed", doc_result.doc_name);
+                }
+            } else {
+                println!("\x1b[31merror:\x1b[0m {} failed", doc_result.doc_name);
+                for reason in doc_result.failure_reasons() {
+                    println!("    * {reason}");
+                }
+            }
+        }
     }
 }
 
 #[tokio::main]
 async fn main() -> FsResult<()> {
-    maybe_handle_version_override();
-
     let raw_args: Vec<OsString> = std::env::args_os().collect();
-    let (mut filtered_args, verbose, fix_flag) = extract_shimmed_flags(raw_args);
-    filtered_args.insert(1, OsString::from("parse"));
+    let matches = cli().get_matches_from(args_with_implicit_check(raw_args));
+
+    match matches.subcommand() {
+        Some(("generate", sub_matches)) => run_generate(sub_matches).await,
+        Some(("check", sub_matches)) => run_check(sub_matches).await,
+        _ => unreachable!("args_with_implicit_check always inserts a known subcommand"),
+    }
+}
+
+/// Generates a properties YAML file (see `codegen::write_generated_model`) for every
+/// model matching `--select`, or every model if it wasn't given.
+async fn run_generate(sub_matches: &ArgMatches) -> FsResult<()> {
+    use dbt_schemas::schemas::manifest::DbtNode;
+
+    let no_cache = sub_matches.get_flag("no_cache");
+    let project =
+        load_project_from_cli_args_with_cache(dbt_parse_args(sub_matches), no_cache).await?;
+
+    let select: Vec<&String> = sub_matches
+        .get_many::<String>("select")
+        .map(|values| values.collect())
+        .unwrap_or_default();
+    let force = sub_matches.get_flag("force");
+
+    let mut generated = 0;
+    for node in project.manifest.nodes.values() {
+        let DbtNode::Model(model) = node else { continue };
+        if !select.is_empty() && !select.iter().any(|name| **name == model.__common_attr__.name) {
+            continue;
+        }
+
+        match write_generated_model(model, Some(&project.project_dir), force) {
+            Ok(path) => {
+                println!("generated: {} -> {}", model.__common_attr__.name, path.display());
+                generated += 1;
+            }
+            Err(err) => {
+                eprintln!("Failed to generate {}: {err}", model.__common_attr__.name);
+            }
+        }
+    }
+
+    if generated == 0 {
+        println!("No models generated.");
+    }
+    Ok(())
+}
 
-    let project = load_project_from_cli_args(filtered_args).await?;
+async fn run_check(sub_matches: &ArgMatches) -> FsResult<()> {
+    let flags = CheckArgs::from_matches(sub_matches);
+    let project =
+        load_project_from_cli_args_with_cache(dbt_parse_args(sub_matches), flags.no_cache).await?;
 
     // where I come in
     let config = match ConfigFile::resolve(&project.invocation_args) {
-        Ok(cfg) => cfg.with_fix(fix_flag),
+        Ok(cfg) => cfg.with_fix(flags.fix).with_blame(flags.blame),
         Err(err) => {
             eprintln!("Failed to load configuration: {err}");
             std::process::exit(2);
         }
     };
-    let check_result = check_all_with_report(&project.manifest, &config, |event| {
-        report_event(event, verbose);
-    });
 
-    for (model, model_changes) in check_result.model_changes.iter() {
-        println!("Model: {model} has found changes");
-        for (column, column_changes) in model_changes.column_changes.iter() {
-            for change in column_changes {
-                match change {
-                    ColumnChange::ChangePropertiesFile => {
-                        println!("  Column: {column} - properties file will be regenerated");
+    if flags.lint_extras {
+        lint_extras(&project.project_dir, &project.manifest);
+    }
+
+    if flags.watch {
+        println!("Watching {} for changes...", project.project_dir.display());
+
+        let cts = CancellationTokenSource::new();
+        let cancellation = cts.token();
+        tokio::spawn(async move {
+            if tokio::signal::ctrl_c().await.is_ok() {
+                cts.cancel();
+            }
+        });
+
+        if let Err(err) = watch::watch(
+            &project.project_dir,
+            project.manifest,
+            config,
+            &cancellation,
+            |model_result| report_event(CheckEvent::Model(model_result), flags.verbose),
+        ) {
+            eprintln!("Watch mode failed: {err}");
+            std::process::exit(2);
+        }
+        return Ok(());
+    }
+
+    let graph = DbtGraph::from(&project.manifest);
+
+    let baseline = match flags.baseline {
+        Some(path) => match Baseline::load(&path) {
+            Ok(baseline) => Some(baseline),
+            Err(err) => {
+                eprintln!("Failed to load baseline {}: {err}", path.display());
+                std::process::exit(2);
+            }
+        },
+        None => None,
+    };
+
+    // Scanned up front (it only reads the manifest's node/patch-path source files, not
+    // the check itself), same as baseline is loaded up front.
+    let suppressions = Suppressions::build(&project.manifest, &config);
+    for warning in suppressions.warnings() {
+        eprintln!("{warning}");
+    }
+    let filtering_active = baseline.is_some() || !suppressions.is_empty();
+
+    // Collected from the same `CheckEvent` stream `report` below already walks, so
+    // `Json`/`JsonFull`/`Sarif` output doesn't need a second pass over the finished
+    // `CheckResult` to build its findings -- unless a baseline or suppressions are
+    // active, since those mutate `check_result` after this pass finishes and
+    // `collect_findings` has to re-walk it anyway (same reason `report_event` re-runs
+    // for `Human` below).
+    let mut findings_collector = reporter::FindingsCollector::new();
+    let report = |event: CheckEvent<'_>| {
+        if !filtering_active
+            && matches!(
+                flags.format,
+                OutputFormat::Json
+                    | OutputFormat::JsonFull
+                    | OutputFormat::Sarif
+                    | OutputFormat::GithubActions
+            )
+        {
+            findings_collector.record(event, &project.manifest, &config);
+        }
+
+        // Streamed live, same live-vs-re-walk split as `Human` below: a baseline or
+        // suppressions mutate `check_result` after this pass, so a model event here
+        // would be stale and is instead re-streamed post-filter further down.
+        let is_stale_model_event = filtering_active && matches!(event, CheckEvent::Model(_));
+        if flags.format == OutputFormat::JsonLines && !is_stale_model_event {
+            println!("{}", render_event_json_line(event));
+        }
+
+        if flags.format != OutputFormat::Human {
+            return;
+        }
+        // Model events are reported once baselining/suppressions have filtered out
+        // already-known/suppressed failures, further down.
+        if filtering_active && matches!(event, CheckEvent::Model(_)) {
+            return;
+        }
+        report_event(event, flags.verbose);
+    };
+
+    let mut check_result = if let Some(cache_path) = &flags.incremental {
+        let mut cache = if cache_path.exists() {
+            match IncrementalCache::load(cache_path) {
+                Ok(cache) => cache,
+                Err(err) => {
+                    eprintln!(
+                        "Failed to load incremental cache {}: {err}",
+                        cache_path.display()
+                    );
+                    std::process::exit(2);
+                }
+            }
+        } else {
+            IncrementalCache::default()
+        };
+        let check_result = check_all_incremental(&project.manifest, &config, &mut cache, report);
+        if let Err(err) = cache.write(cache_path) {
+            eprintln!(
+                "Failed to write incremental cache {}: {err}",
+                cache_path.display()
+            );
+            std::process::exit(2);
+        }
+        check_result
+    } else if let Some(cache_path) = &flags.graph_cache {
+        check_all_with_graph_cache(&project.manifest, cache_path, &config, report)
+    } else {
+        check_all_with_report(&project.manifest, &config, report)
+    };
+
+    if let Some(path) = flags.write_baseline {
+        let snapshot = Baseline::capture(&check_result);
+        if let Err(err) = snapshot.write(&path) {
+            eprintln!("Failed to write baseline {}: {err}", path.display());
+            std::process::exit(2);
+        }
+    }
+
+    if let Some(baseline) = &baseline {
+        baseline.apply(&mut check_result);
+    }
+    suppressions.apply(&project.manifest, &mut check_result);
+
+    if filtering_active && flags.format == OutputFormat::Human {
+        // Model events are suppressed above until baselining/suppressions have
+        // filtered out already-known/suppressed failures, so report the survivors now.
+        for model_result in check_result.models.values() {
+            report_event(CheckEvent::Model(model_result), flags.verbose);
+        }
+    }
+    if filtering_active && flags.format == OutputFormat::JsonLines {
+        // Mirrors the `Human` re-report above: model events were held back from the
+        // live stream until baselining/suppressions settled, so stream them now.
+        for model_result in check_result.models.values() {
+            println!("{}", render_event_json_line(CheckEvent::Model(model_result)));
+        }
+    }
+
+    if flags.suggest {
+        let report = suggest::suggest(&project.manifest, &config, &check_result);
+        print_suggestions(&report);
+    }
+
+    match flags.format {
+        OutputFormat::Human => {
+            for (model, model_changes) in check_result.model_changes.iter() {
+                println!("Model: {model} has found changes");
+                for (column, column_changes) in model_changes.column_changes.iter() {
+                    for change in column_changes {
+                        match change {
+                            ColumnChange::ChangePropertiesFile => {
+                                println!(
+                                    "  Column: {column} - properties file will be regenerated"
+                                );
+                            }
+                            ColumnChange::AddDataTest => {
+                                println!("  Column: {column} - required test will be added");
+                            }
+                            ColumnChange::DescriptionChanged { new, .. } => {
+                                println!(
+                                    "  Column: {column} - description will be {}",
+                                    match new {
+                                        Some(new) => format!("set to \"{new}\""),
+                                        None => "cleared".to_string(),
+                                    }
+                                );
+                            }
+                        }
                     }
                 }
             }
         }
+        OutputFormat::Json => {
+            // If a baseline filtered `check_result` after the collector's pass, the
+            // collected findings are stale -- re-walk the (now baseline-adjusted)
+            // result instead, same as `Human`'s post-baseline re-report above.
+            let findings = if filtering_active {
+                reporter::collect_findings(&project.manifest, &config, &check_result)
+            } else {
+                findings_collector.finish()
+            };
+            println!("{}", JsonReporter.render(&findings));
+        }
+        OutputFormat::JsonFull => {
+            println!("{}", reporter::render_full_result_json(&check_result));
+        }
+        // Already streamed line-by-line from `report` above; nothing left to print.
+        OutputFormat::JsonLines => {}
+        OutputFormat::Sarif => {
+            let findings = if filtering_active {
+                reporter::collect_findings(&project.manifest, &config, &check_result)
+            } else {
+                findings_collector.finish()
+            };
+            println!("{}", SarifReporter.render(&findings));
+        }
+        OutputFormat::GithubActions => {
+            let findings = if filtering_active {
+                reporter::collect_findings(&project.manifest, &config, &check_result)
+            } else {
+                findings_collector.finish()
+            };
+            println!("{}", GithubActionsReporter.render(&findings));
+        }
+        OutputFormat::Junit => {
+            let mut junit = JunitReporter::new();
+            for model_result in check_result.models.values() {
+                junit.record(CheckEvent::Model(model_result), &project.manifest);
+            }
+            for source_result in check_result.sources.values() {
+                junit.record(CheckEvent::Source(source_result), &project.manifest);
+            }
+            for exposure_result in check_result.exposures.values() {
+                junit.record(CheckEvent::Exposure(exposure_result), &project.manifest);
+            }
+            for doc_result in check_result.docs.values() {
+                junit.record(CheckEvent::Doc(doc_result), &project.manifest);
+            }
+            println!("{}", junit.finish());
+        }
     }
 
     if config.fix {
@@ -159,16 +682,18 @@ async fn main() -> FsResult<()> {
             (!check_result.model_changes.is_empty()).then_some(&check_result.model_changes)
         {
             match writeback::apply_model_changes(
+                &writeback::fs::RealFs,
                 project.project_dir.as_path(),
                 model_changes,
                 &config,
+                &graph,
             ) {
                 Ok(applied) => {
                     for (model_id, columns) in applied {
                         if columns.is_empty() {
                             continue;
                         }
-                        println!("Applied ruamel.yaml updates for {model_id}: {columns:?}");
+                        println!("Applied updates for {model_id}: {columns:?}");
                     }
                 }
                 Err(err) => {
@@ -176,8 +701,45 @@ async fn main() -> FsResult<()> {
                 }
             }
         }
-    } else if !check_result.model_changes.is_empty() {
-        println!("Fixes available; re-run with --fix to apply them.");
+
+        if !check_result.docs.is_empty() {
+            match writeback::apply_doc_changes(
+                &writeback::fs::RealFs,
+                project.project_dir.as_path(),
+                &project.manifest,
+                &check_result.docs,
+            ) {
+                Ok(applied) => {
+                    for doc_name in applied {
+                        println!("Consolidated duplicate docs block: {doc_name}");
+                    }
+                }
+                Err(err) => {
+                    eprintln!("Failed to apply docs updates: {err}");
+                }
+            }
+        }
+    } else {
+        // Default preview (no `--fix`): `check_result.model_changes` is always empty
+        // here since fixes are only ever computed with `config.fix` set (see
+        // `Config::is_fixable`), so re-check with a hypothetical fix-enabled config and
+        // print the unified diff `--fix` would write -- the same preview `cargo fix`
+        // shows before it touches anything.
+        match ConfigFile::resolve(&project.invocation_args) {
+            Ok(preview_config) => {
+                let preview_config = preview_config.with_fix(true);
+                let preview_result = check_all(&project.manifest, &preview_config);
+                print_dry_run_diff(
+                    project.project_dir.as_path(),
+                    &preview_result.model_changes,
+                    &preview_config,
+                    &graph,
+                );
+            }
+            Err(err) => {
+                eprintln!("Failed to load configuration for fix preview: {err}");
+            }
+        }
     }
 
     if check_result.has_failures() {
@@ -0,0 +1,169 @@
+//! On-disk cache for the fully-resolved [`DbtManifestV12`], keyed by a fingerprint of
+//! everything that can change its shape: `dbt_project.yml`, every model `.sql`/`.yml`
+//! file (by mtime+size rather than content, to keep fingerprinting cheap), and the
+//! `InvocationArgs` fields that feed `resolve`.
+//!
+//! `load`/`resolve`/`build_manifest` (see [`crate::project::load_project_from_cli_args`])
+//! dominate wall-clock time on a large project even when nothing relevant changed since
+//! the last run. A cold run resolves as usual and archives the manifest (as a JSON blob,
+//! the same way [`crate::incremental`] carries its nested enums -- `DbtManifestV12` is an
+//! external type with no `rkyv::Archive` impl to derive) inside a small zero-copy
+//! envelope next to the fingerprint; a warm run with an unchanged fingerprint memory-maps
+//! that archive, validates it, and deserializes the manifest straight out of the JSON
+//! blob, skipping `load`/`resolve`/`build_manifest` entirely. Any I/O failure, a cache
+//! written by a different [`CACHE_FORMAT_VERSION`], a fingerprint mismatch, or bytes that
+//! fail `rkyv`'s validation falls back to a full rebuild rather than panicking -- this is
+//! purely an optimization and must never be load-bearing for correctness.
+
+use dbt_jinja_utils::invocation_args::InvocationArgs;
+use dbt_schemas::schemas::manifest::DbtManifestV12;
+use memmap2::Mmap;
+use rkyv::{Archive, Deserialize, Serialize};
+use std::collections::hash_map::DefaultHasher;
+use std::fs::File;
+use std::hash::{Hash, Hasher};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// Bumped whenever [`ManifestSnapshot`]'s shape changes, so an archive written by an
+/// older binary is rejected instead of misread as the new shape.
+const CACHE_FORMAT_VERSION: u32 = 1;
+
+/// Env var overriding where the manifest cache file lives; falls back to
+/// `<project_dir>/.dbt_lint_yaml_cache/manifest.rkyv` when unset.
+const CACHE_DIR_ENV: &str = "DBT_LINT_YAML_CACHE_DIR";
+
+#[derive(Debug, Error)]
+pub enum ManifestCacheError {
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("failed to (de)serialize cached manifest: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("failed to archive manifest cache: {0}")]
+    Archive(String),
+}
+
+#[derive(Debug, Archive, Serialize, Deserialize)]
+#[archive(check_bytes)]
+struct ManifestSnapshot {
+    format_version: u32,
+    fingerprint: u64,
+    manifest_json: String,
+}
+
+/// Where the manifest cache file should live for `project_dir`, honoring
+/// [`CACHE_DIR_ENV`] if set.
+pub fn cache_path(project_dir: &Path) -> PathBuf {
+    let dir = std::env::var_os(CACHE_DIR_ENV)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| project_dir.join(".dbt_lint_yaml_cache"));
+    dir.join("manifest.rkyv")
+}
+
+/// Recursively collects `(path, mtime_secs, len)` for every `.sql`/`.yml`/`.yaml` file
+/// under `dir`, skipping dotdirs like `.git`/`.dbt_lint_yaml_cache` so the cache doesn't
+/// invalidate itself. Unreadable entries are silently skipped -- a missed file just means
+/// a stale cache lingers one run longer than ideal, which `--no-cache` can always work
+/// around.
+fn collect_model_files(dir: &Path, out: &mut Vec<(PathBuf, u64, u64)>) {
+    let Ok(read_dir) = std::fs::read_dir(dir) else {
+        return;
+    };
+
+    for entry in read_dir.filter_map(|entry| entry.ok()) {
+        let path = entry.path();
+        let Ok(file_type) = entry.file_type() else {
+            continue;
+        };
+
+        if file_type.is_dir() {
+            if path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with('.') || name == "target")
+            {
+                continue;
+            }
+            collect_model_files(&path, out);
+            continue;
+        }
+
+        let is_model_file = matches!(
+            path.extension().and_then(|ext| ext.to_str()),
+            Some("sql") | Some("yml") | Some("yaml")
+        );
+        if !is_model_file {
+            continue;
+        }
+
+        let Ok(metadata) = entry.metadata() else {
+            continue;
+        };
+        let modified_secs = metadata
+            .modified()
+            .ok()
+            .and_then(|modified| modified.duration_since(std::time::UNIX_EPOCH).ok())
+            .map(|duration| duration.as_secs())
+            .unwrap_or_default();
+
+        out.push((path, modified_secs, metadata.len()));
+    }
+}
+
+/// Fingerprints everything that can change the resolved manifest: `dbt_project.yml`,
+/// every model `.sql`/`.yml` file's mtime+size, and the `InvocationArgs` fields that
+/// affect resolution (`project_dir`, `profile`, `target`).
+pub fn fingerprint(project_dir: &Path, invocation_args: &InvocationArgs) -> u64 {
+    let mut hasher = DefaultHasher::new();
+
+    if let Ok(contents) = std::fs::read_to_string(project_dir.join("dbt_project.yml")) {
+        contents.hash(&mut hasher);
+    }
+
+    let mut entries = Vec::new();
+    collect_model_files(project_dir, &mut entries);
+    entries.sort();
+    entries.hash(&mut hasher);
+
+    invocation_args.project_dir.hash(&mut hasher);
+    invocation_args.profile.hash(&mut hasher);
+    invocation_args.target.hash(&mut hasher);
+
+    hasher.finish()
+}
+
+/// Returns the cached manifest at `path` if it exists, matches `expected_fingerprint`,
+/// and validates. Any mismatch or corruption falls back to `None` rather than an error.
+pub fn try_load(path: &Path, expected_fingerprint: u64) -> Option<DbtManifestV12> {
+    let file = File::open(path).ok()?;
+    let mmap = unsafe { Mmap::map(&file).ok()? };
+    let archived = rkyv::check_archived_root::<ManifestSnapshot>(&mmap).ok()?;
+
+    if archived.format_version != CACHE_FORMAT_VERSION || archived.fingerprint != expected_fingerprint {
+        return None;
+    }
+
+    let snapshot: ManifestSnapshot = archived.deserialize(&mut rkyv::Infallible).ok()?;
+    serde_json::from_str(&snapshot.manifest_json).ok()
+}
+
+/// Best-effort archive of `manifest` to `path`; a write failure is silently dropped by
+/// the caller, since a missed cache write just means the next run rebuilds.
+pub fn save(path: &Path, manifest: &DbtManifestV12, fingerprint: u64) -> Result<(), ManifestCacheError> {
+    let manifest_json = serde_json::to_string(manifest)?;
+    let snapshot = ManifestSnapshot {
+        format_version: CACHE_FORMAT_VERSION,
+        fingerprint,
+        manifest_json,
+    };
+    let bytes = rkyv::to_bytes::<_, 4096>(&snapshot)
+        .map_err(|err| ManifestCacheError::Archive(err.to_string()))?;
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let mut file = File::create(path)?;
+    file.write_all(&bytes)?;
+    Ok(())
+}
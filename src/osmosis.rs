@@ -1,86 +1,293 @@
-use crate::{change_descriptors::ColumnChange, check::ModelChanges, config::Config};
-use dbt_schemas::schemas::manifest::{DbtManifestV12, DbtNode};
+use crate::{
+    change_descriptors::ColumnChange,
+    check::ModelChanges,
+    config::{
+        ColumnInheritanceMode, Config, SourceInheritanceConflictPolicy, SourceInheritanceDirection,
+    },
+    graph::DbtGraph,
+};
+use dbt_schemas::schemas::manifest::{DbtManifestV12, DbtNode, ManifestSource};
 use std::collections::BTreeMap;
 
-pub(crate) fn get_upstream_col_desc(
+/// The outcome of resolving a column field from upstream lineage.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum InheritedValue<T> {
+    /// No ancestor, at any depth, had the field set.
+    NotFound,
+    /// The nearest ancestor depth with the field set agreed on a single value.
+    Resolved(T),
+    /// More than one distinct value was found among ancestors at the nearest depth
+    /// that had the field set. Callers should skip the fix rather than guess.
+    Ambiguous(Vec<T>),
+}
+
+/// Resolve a missing description for `col_name` on `node_id` from upstream lineage via
+/// `graph`, either direct parents only or the full transitive lineage breadth-first
+/// depending on `config.column_inheritance_mode` (see `DbtGraph::ancestors`). Stops at
+/// the first depth where any ancestor has a valid (non-empty, non-placeholder)
+/// description for the column: if they all agree, that's the resolved value; if they
+/// disagree, it's reported as ambiguous instead of silently picking one.
+pub(crate) fn resolve_upstream_col_desc(
     manifest: &DbtManifestV12,
     model_changes: Option<&BTreeMap<String, ModelChanges>>,
+    graph: &DbtGraph,
     node_id: &str,
     col_name: &str,
     config: &Config,
-) -> Option<String> {
-    let upstream_ids = manifest.nodes.get(node_id).and_then(|node| match node {
-        DbtNode::Model(model) => Some(model.__base_attr__.depends_on.nodes.clone()),
-        _ => None,
-    })?;
+) -> InheritedValue<String> {
+    let levels: Vec<Vec<String>> = match config.column_inheritance_mode {
+        ColumnInheritanceMode::DirectParent => {
+            let mut direct_parents: Vec<String> = graph.parents(node_id).collect();
+            direct_parents.sort();
+            vec![direct_parents]
+        }
+        ColumnInheritanceMode::TransitiveNearestAncestor => {
+            graph.ancestors(node_id, config.max_inheritance_depth)
+        }
+    };
+
+    resolve_from_levels(levels, |ancestor_id| {
+        column_description_at(manifest, model_changes, ancestor_id, col_name, config)
+    })
+}
 
-    // check the changes first on the assumption that manifest will be much bigger than changes
-    if let Some(changes) = model_changes {
-        for upstream_id in &upstream_ids {
-            if let Some(desc) = lookup_model_change_description(changes, upstream_id, col_name) {
-                return Some(desc);
+/// Walk `levels` (breadth-first, grouped by depth) looking up a candidate description at
+/// each node with `extract`, stopping at the first depth with any match: if every match
+/// at that depth agrees, that's the resolved value; if they disagree, it's ambiguous
+/// rather than silently picking one. Shared by [`resolve_upstream_col_desc`] (walking
+/// ancestors) and the source resolvers below (walking descendants), which differ only in
+/// which direction `levels` came from and what `extract` looks up.
+fn resolve_from_levels(
+    levels: Vec<Vec<String>>,
+    extract: impl Fn(&str) -> Option<String>,
+) -> InheritedValue<String> {
+    for level in levels {
+        let mut found: Vec<String> = Vec::new();
+        for node_id in &level {
+            if let Some(desc) = extract(node_id)
+                && !found.contains(&desc)
+            {
+                found.push(desc);
             }
         }
+
+        match found.len() {
+            0 => {}
+            1 => return InheritedValue::Resolved(found.into_iter().next().unwrap()),
+            _ => return InheritedValue::Ambiguous(found),
+        }
     }
 
-    let desc = upstream_ids
-        .iter()
-        .filter_map(|upstream_id| {
-            // the upstream id can be a node or a source
-            manifest
-                .nodes
-                .get(upstream_id)
-                .and_then(|upstream_node| match upstream_node {
-                    DbtNode::Model(upstream_model) => {
-                        upstream_model.__base_attr__.columns.get(col_name)
-                    }
-                    DbtNode::Seed(upstream_seed) => {
-                        upstream_seed.__base_attr__.columns.get(col_name)
-                    }
-                    DbtNode::Snapshot(upstream_snapshot) => {
-                        upstream_snapshot.__base_attr__.columns.get(col_name)
+    InheritedValue::NotFound
+}
+
+/// Resolve a missing description for `col_name` on `source`'s table from the rest of the
+/// manifest: a downstream model column that already documents it, or a sibling source
+/// table exposing the same `identifier`/column name.
+/// `config.source_description_inheritance_direction` picks which origin is tried first;
+/// `config.source_description_conflict_policy` decides what happens when both answer and
+/// disagree.
+pub(crate) fn resolve_source_col_desc(
+    manifest: &DbtManifestV12,
+    graph: &DbtGraph,
+    source: &ManifestSource,
+    col_name: &str,
+    config: &Config,
+) -> InheritedValue<String> {
+    let downstream = resolve_from_levels(
+        graph.descendants(&source.__common_attr__.unique_id, config.max_inheritance_depth),
+        |descendant_id| column_description_at(manifest, None, descendant_id, col_name, config),
+    );
+    let sibling = resolve_sibling_source(manifest, source, |other| {
+        let col = other.columns.get(col_name)?;
+        valid_description(col.as_ref().description.as_deref(), config)
+            .map(|desc| render_description(manifest, desc, config))
+    });
+
+    combine_source_candidates(downstream, sibling, config)
+}
+
+/// Resolve a missing table-level description for `source` from the rest of the manifest,
+/// same origins and policy as [`resolve_source_col_desc`] but for the source table's own
+/// description rather than one of its columns.
+pub(crate) fn resolve_source_table_desc(
+    manifest: &DbtManifestV12,
+    graph: &DbtGraph,
+    source: &ManifestSource,
+    config: &Config,
+) -> InheritedValue<String> {
+    let downstream = resolve_from_levels(
+        graph.descendants(&source.__common_attr__.unique_id, config.max_inheritance_depth),
+        |descendant_id| node_level_description_at(manifest, descendant_id, config),
+    );
+    let sibling = resolve_sibling_source(manifest, source, |other| {
+        valid_description(other.__common_attr__.description.as_deref(), config)
+            .map(|desc| render_description(manifest, desc, config))
+    });
+
+    combine_source_candidates(downstream, sibling, config)
+}
+
+/// Search every other source table sharing `source`'s `identifier` (the same physical
+/// table registered under a different source name) for a candidate via `extract`,
+/// applying the same nearest-depth-agreement-or-ambiguous rule as [`resolve_from_levels`]
+/// (there's only one "depth" here, since siblings aren't graph-ordered).
+fn resolve_sibling_source(
+    manifest: &DbtManifestV12,
+    source: &ManifestSource,
+    extract: impl Fn(&ManifestSource) -> Option<String>,
+) -> InheritedValue<String> {
+    let mut found: Vec<String> = Vec::new();
+    for other in manifest.sources.values() {
+        if other.__common_attr__.unique_id == source.__common_attr__.unique_id
+            || other.identifier != source.identifier
+        {
+            continue;
+        }
+        if let Some(desc) = extract(other)
+            && !found.contains(&desc)
+        {
+            found.push(desc);
+        }
+    }
+
+    match found.len() {
+        0 => InheritedValue::NotFound,
+        1 => InheritedValue::Resolved(found.into_iter().next().unwrap()),
+        _ => InheritedValue::Ambiguous(found),
+    }
+}
+
+/// Combine a `downstream` and a `sibling` candidate into one resolution, per
+/// `config.source_description_inheritance_direction`/`source_description_conflict_policy`.
+/// The preferred direction's own ambiguity (several disagreeing descendants/siblings) is
+/// never overridden by the other direction -- only a clean single answer on each side can
+/// conflict.
+fn combine_source_candidates(
+    downstream: InheritedValue<String>,
+    sibling: InheritedValue<String>,
+    config: &Config,
+) -> InheritedValue<String> {
+    let (primary, secondary) = match config.source_description_inheritance_direction {
+        SourceInheritanceDirection::Downstream => (downstream, sibling),
+        SourceInheritanceDirection::Sibling => (sibling, downstream),
+    };
+
+    match primary {
+        InheritedValue::NotFound => secondary,
+        InheritedValue::Ambiguous(_) => primary,
+        InheritedValue::Resolved(ref primary_value) => match secondary {
+            InheritedValue::Resolved(ref secondary_value) if secondary_value != primary_value => {
+                match config.source_description_conflict_policy {
+                    SourceInheritanceConflictPolicy::SkipAmbiguous => {
+                        InheritedValue::Ambiguous(vec![
+                            primary_value.clone(),
+                            secondary_value.clone(),
+                        ])
                     }
-                    _ => None,
-                })
-                .or_else(|| {
-                    manifest
-                        .sources
-                        .get(upstream_id)
-                        .and_then(|source| source.columns.get(col_name))
-                })
-        })
-        .filter_map(|dep_col| {
-            dep_col.as_ref().description.as_ref().and_then(|d| {
-                let trimmed = d.trim();
-                if trimmed.is_empty()
-                    || config
-                        .invalid_descriptions
-                        .iter()
-                        .any(|bad| bad.eq_ignore_ascii_case(trimmed))
-                {
-                    None
-                } else {
-                    Some(d.clone())
+                    SourceInheritanceConflictPolicy::PreferDirection => primary,
                 }
-            })
+            }
+            _ => primary,
+        },
+    }
+}
+
+/// `desc` trimmed, or `None` if missing, empty, or one of `config.invalid_descriptions`.
+fn valid_description(desc: Option<&str>, config: &Config) -> Option<String> {
+    let trimmed = desc?.trim();
+    if trimmed.is_empty()
+        || config
+            .invalid_descriptions
+            .iter()
+            .any(|bad| bad.eq_ignore_ascii_case(trimmed))
+    {
+        return None;
+    }
+    Some(trimmed.to_string())
+}
+
+/// The valid table-level description for a single ancestor/descendant `node_id`, for the
+/// node kinds that carry one. Mirrors [`column_description_at`] but for the node's own
+/// description rather than a column's.
+fn node_level_description_at(
+    manifest: &DbtManifestV12,
+    node_id: &str,
+    config: &Config,
+) -> Option<String> {
+    let desc = manifest.nodes.get(node_id).and_then(|node| match node {
+        DbtNode::Model(model) => model.__common_attr__.description.clone(),
+        DbtNode::Seed(seed) => seed.__common_attr__.description.clone(),
+        DbtNode::Snapshot(snapshot) => snapshot.__common_attr__.description.clone(),
+        _ => None,
+    })?;
+
+    valid_description(Some(&desc), config).map(|desc| render_description(manifest, desc, config))
+}
+
+/// The valid description for `col_name` on a single ancestor `node_id`, preferring a
+/// not-yet-written-back value from `model_changes` over what's already in the
+/// manifest, and rendered as a docs block reference when it matches one exactly.
+fn column_description_at(
+    manifest: &DbtManifestV12,
+    model_changes: Option<&BTreeMap<String, ModelChanges>>,
+    node_id: &str,
+    col_name: &str,
+    config: &Config,
+) -> Option<String> {
+    if let Some(changes) = model_changes
+        && let Some(desc) = lookup_model_change_description(changes, node_id, col_name)
+    {
+        return Some(render_description(manifest, desc, config));
+    }
+
+    // the ancestor can be a model/seed/snapshot node or a source
+    let dep_col = manifest
+        .nodes
+        .get(node_id)
+        .and_then(|node| match node {
+            DbtNode::Model(model) => model.__base_attr__.columns.get(col_name),
+            DbtNode::Seed(seed) => seed.__base_attr__.columns.get(col_name),
+            DbtNode::Snapshot(snapshot) => snapshot.__base_attr__.columns.get(col_name),
+            _ => None,
         })
-        .next();
+        .or_else(|| {
+            manifest
+                .sources
+                .get(node_id)
+                .and_then(|source| source.columns.get(col_name))
+        })?;
+
+    let trimmed = dep_col.as_ref().description.as_ref()?.trim();
+    if trimmed.is_empty()
+        || config
+            .invalid_descriptions
+            .iter()
+            .any(|bad| bad.eq_ignore_ascii_case(trimmed))
+    {
+        return None;
+    }
+
+    Some(render_description(manifest, trimmed.to_string(), config))
+}
 
+/// Prefer a docs block reference over the raw text when it matches one exactly,
+/// unless the config asks for descriptions to always be rendered in full.
+fn render_description(manifest: &DbtManifestV12, description: String, config: &Config) -> String {
     if config.render_descriptions {
-        return desc;
+        return description;
     }
-    // Prefer a docs block reference when the description matches exactly.
-    desc.map(|d| {
-        let doc_reference = manifest.docs.values().find_map(|doc| {
-            if doc.block_contents == d {
+    manifest
+        .docs
+        .values()
+        .find_map(|doc| {
+            if doc.block_contents == description {
                 Some(format!("{{{{doc('{name}')}}}}", name = doc.name))
             } else {
                 None
             }
-        });
-
-        doc_reference.unwrap_or(d)
-    })
+        })
+        .unwrap_or(description)
 }
 
 fn lookup_model_change_description(
@@ -90,21 +297,24 @@ fn lookup_model_change_description(
 ) -> Option<String> {
     model_changes.get(upstream_id).and_then(|change| {
         change.column_changes.get(col_name).and_then(|changes| {
-            changes
-                .iter()
-                .find_map(|column_change| match column_change {
-                    ColumnChange::DescriptionChanged { new, .. } => new.clone(),
-                })
+            changes.iter().find_map(|column_change| match column_change {
+                ColumnChange::DescriptionChanged { new, .. } => new.clone(),
+                ColumnChange::ChangePropertiesFile | ColumnChange::AddDataTest => None,
+            })
         })
     })
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{get_upstream_col_desc, lookup_model_change_description};
+    use super::{
+        InheritedValue, combine_source_candidates, lookup_model_change_description,
+        resolve_source_col_desc, resolve_source_table_desc, resolve_upstream_col_desc,
+    };
     use crate::{
         check::{ColumnChange, ModelChanges},
-        config::Config,
+        config::{Config, SourceInheritanceConflictPolicy, SourceInheritanceDirection},
+        graph::DbtGraph,
     };
     use dbt_schemas::schemas::{
         dbt_column::DbtColumn,
@@ -113,6 +323,26 @@ mod tests {
     use std::collections::{BTreeMap, BTreeSet};
     use std::sync::Arc;
 
+    /// Mirror `depends_on.nodes` into `child_map` (parent -> children) for every model,
+    /// since `DbtGraph::from` builds its edges from `child_map`, not `depends_on.nodes`.
+    fn populate_child_map(manifest: &mut DbtManifestV12) {
+        for (node_id, node) in manifest.nodes.iter() {
+            let upstream = match node {
+                DbtNode::Model(model) => &model.__base_attr__.depends_on.nodes,
+                DbtNode::Seed(seed) => &seed.__base_attr__.depends_on.nodes,
+                DbtNode::Snapshot(snapshot) => &snapshot.__base_attr__.depends_on.nodes,
+                _ => continue,
+            };
+            for parent in upstream {
+                manifest
+                    .child_map
+                    .entry(parent.clone())
+                    .or_default()
+                    .push(node_id.clone());
+            }
+        }
+    }
+
     // FIXTURES
     fn model_changes_fixture() -> BTreeMap<String, ModelChanges> {
         let mut customers_columns = BTreeSet::new();
@@ -324,70 +554,153 @@ mod tests {
             _ => unreachable!(),
         }
 
+        populate_child_map(&mut manifest);
         manifest
     }
 
-    // get_upstream_col_desc tests
+    // resolve_upstream_col_desc tests
     #[test]
     fn prefers_model_changes_over_manifest_columns() {
         let manifest = manifest_fixture();
+        let graph = DbtGraph::from(&manifest);
         let model_changes_map = model_changes_fixture();
 
-        let result = get_upstream_col_desc(
+        let result = resolve_upstream_col_desc(
             &manifest,
             Some(&model_changes_map),
+            &graph,
             "model.jaffle_shop.orders",
             "customer_id",
             &Config::default(),
         );
 
-        assert_eq!(result.as_deref(), Some("Fresh description"));
+        assert_eq!(result, InheritedValue::Resolved("Fresh description".to_string()));
     }
 
     #[test]
     fn returns_description_from_upstream_model_column() {
         let manifest = manifest_fixture();
+        let graph = DbtGraph::from(&manifest);
 
-        let result = get_upstream_col_desc(
+        let result = resolve_upstream_col_desc(
             &manifest,
             None,
+            &graph,
             "model.jaffle_shop.orders",
             "customer_id",
             &Config::default(),
         );
 
-        assert_eq!(result.as_deref(), Some("Customer id from manifest"));
+        assert_eq!(
+            result,
+            InheritedValue::Resolved("Customer id from manifest".to_string())
+        );
     }
 
     #[test]
     fn returns_description_from_upstream_source_column() {
         let manifest = manifest_fixture();
+        let graph = DbtGraph::from(&manifest);
 
-        let result = get_upstream_col_desc(
+        let result = resolve_upstream_col_desc(
             &manifest,
             None,
+            &graph,
             "model.jaffle_shop.base_customers",
             "customer_id",
             &Config::default(),
         );
 
-        assert_eq!(result.as_deref(), Some("Customer id from source"));
+        assert_eq!(
+            result,
+            InheritedValue::Resolved("Customer id from source".to_string())
+        );
     }
 
     #[test]
-    fn returns_none_when_no_upstream_description_found() {
+    fn returns_not_found_when_no_upstream_description_found() {
         let manifest = manifest_fixture();
+        let graph = DbtGraph::from(&manifest);
         let model_changes_map = model_changes_fixture();
 
-        let result = get_upstream_col_desc(
+        let result = resolve_upstream_col_desc(
             &manifest,
             Some(&model_changes_map),
+            &graph,
             "model.jaffle_shop.payments",
             "payment_id",
             &Config::default(),
         );
 
-        assert!(result.is_none());
+        assert_eq!(result, InheritedValue::NotFound);
+    }
+
+    #[test]
+    fn returns_ambiguous_when_sibling_ancestors_at_same_depth_disagree() {
+        // Two direct parents of "downstream" disagree on the column's description, so
+        // neither should win silently.
+        let mut manifest = DbtManifestV12::default();
+
+        manifest.nodes.insert(
+            "model.upstream_a".to_string(),
+            DbtNode::Model(Default::default()),
+        );
+        manifest.nodes.insert(
+            "model.upstream_b".to_string(),
+            DbtNode::Model(Default::default()),
+        );
+        manifest.nodes.insert(
+            "model.downstream".to_string(),
+            DbtNode::Model(Default::default()),
+        );
+
+        match manifest.nodes.get_mut("model.upstream_a").unwrap() {
+            DbtNode::Model(model) => {
+                model.__base_attr__.columns.insert(
+                    "col".to_string(),
+                    column_with_description("col", "Description A"),
+                );
+            }
+            _ => unreachable!(),
+        }
+
+        match manifest.nodes.get_mut("model.upstream_b").unwrap() {
+            DbtNode::Model(model) => {
+                model.__base_attr__.columns.insert(
+                    "col".to_string(),
+                    column_with_description("col", "Description B"),
+                );
+            }
+            _ => unreachable!(),
+        }
+
+        match manifest.nodes.get_mut("model.downstream").unwrap() {
+            DbtNode::Model(model) => {
+                model.__base_attr__.depends_on.nodes =
+                    vec!["model.upstream_a".to_string(), "model.upstream_b".to_string()];
+            }
+            _ => unreachable!(),
+        }
+
+        populate_child_map(&mut manifest);
+        let graph = DbtGraph::from(&manifest);
+
+        let result = resolve_upstream_col_desc(
+            &manifest,
+            None,
+            &graph,
+            "model.downstream",
+            "col",
+            &Config::default(),
+        );
+
+        match result {
+            InheritedValue::Ambiguous(mut values) => {
+                values.sort();
+                assert_eq!(values, vec!["Description A".to_string(), "Description B".to_string()]);
+            }
+            other => panic!("expected Ambiguous, got {other:?}"),
+        }
     }
 
     #[test]
@@ -422,14 +735,18 @@ mod tests {
             _ => unreachable!(),
         }
 
-        let result = get_upstream_col_desc(
+        populate_child_map(&mut manifest);
+        let graph = DbtGraph::from(&manifest);
+
+        let result = resolve_upstream_col_desc(
             &manifest,
             None,
+            &graph,
             "model.downstream",
             "col",
             &Config::default(),
         );
-        assert!(result.is_none());
+        assert_eq!(result, InheritedValue::NotFound);
     }
 
     #[test]
@@ -462,14 +779,18 @@ mod tests {
             _ => unreachable!(),
         }
 
-        let result = get_upstream_col_desc(
+        populate_child_map(&mut manifest);
+        let graph = DbtGraph::from(&manifest);
+
+        let result = resolve_upstream_col_desc(
             &manifest,
             None,
+            &graph,
             "model.downstream",
             "col",
             &Config::default(),
         );
-        assert!(result.is_none());
+        assert_eq!(result, InheritedValue::NotFound);
     }
 
     // lookup_model_change_description tests
@@ -504,4 +825,167 @@ mod tests {
         );
         assert!(missing_model.is_none());
     }
+
+    // resolve_source_col_desc / resolve_source_table_desc tests
+    #[test]
+    fn resolves_source_column_from_downstream_model() {
+        let mut manifest = DbtManifestV12::default();
+
+        manifest
+            .sources
+            .insert("source.jaffle_shop.raw_customers".to_string(), Default::default());
+
+        manifest.nodes.insert(
+            "model.jaffle_shop.stg_customers".to_string(),
+            DbtNode::Model(Default::default()),
+        );
+        match manifest
+            .nodes
+            .get_mut("model.jaffle_shop.stg_customers")
+            .unwrap()
+        {
+            DbtNode::Model(model) => {
+                model.__base_attr__.columns.insert(
+                    "email".to_string(),
+                    column_with_description("email", "Customer email address"),
+                );
+            }
+            _ => unreachable!(),
+        }
+
+        manifest.child_map.insert(
+            "source.jaffle_shop.raw_customers".to_string(),
+            vec!["model.jaffle_shop.stg_customers".to_string()],
+        );
+
+        let graph = DbtGraph::from(&manifest);
+        let source = manifest
+            .sources
+            .get("source.jaffle_shop.raw_customers")
+            .unwrap();
+
+        let result =
+            resolve_source_col_desc(&manifest, &graph, source, "email", &Config::default());
+
+        assert_eq!(
+            result,
+            InheritedValue::Resolved("Customer email address".to_string())
+        );
+    }
+
+    #[test]
+    fn resolves_source_column_from_sibling_source_with_same_identifier() {
+        let mut manifest = DbtManifestV12::default();
+
+        manifest
+            .sources
+            .insert("source.jaffle_shop.raw_customers".to_string(), Default::default());
+        manifest
+            .sources
+            .get_mut("source.jaffle_shop.raw_customers")
+            .unwrap()
+            .identifier = "customers".to_string();
+
+        manifest
+            .sources
+            .insert("source.other_project.customers".to_string(), Default::default());
+        {
+            let sibling = manifest
+                .sources
+                .get_mut("source.other_project.customers")
+                .unwrap();
+            sibling.identifier = "customers".to_string();
+            sibling
+                .columns
+                .insert("email".to_string(), column_with_description("email", "Email address"));
+        }
+
+        let graph = DbtGraph::from(&manifest);
+        let source = manifest
+            .sources
+            .get("source.jaffle_shop.raw_customers")
+            .unwrap();
+
+        let result =
+            resolve_source_col_desc(&manifest, &graph, source, "email", &Config::default());
+
+        assert_eq!(result, InheritedValue::Resolved("Email address".to_string()));
+    }
+
+    #[test]
+    fn resolves_source_table_description_from_downstream_model() {
+        let mut manifest = DbtManifestV12::default();
+
+        manifest
+            .sources
+            .insert("source.jaffle_shop.raw_customers".to_string(), Default::default());
+
+        manifest.nodes.insert(
+            "model.jaffle_shop.stg_customers".to_string(),
+            DbtNode::Model(Default::default()),
+        );
+        match manifest
+            .nodes
+            .get_mut("model.jaffle_shop.stg_customers")
+            .unwrap()
+        {
+            DbtNode::Model(model) => {
+                model.__common_attr__.description = Some("Staged customer records".to_string());
+            }
+            _ => unreachable!(),
+        }
+
+        manifest.child_map.insert(
+            "source.jaffle_shop.raw_customers".to_string(),
+            vec!["model.jaffle_shop.stg_customers".to_string()],
+        );
+
+        let graph = DbtGraph::from(&manifest);
+        let source = manifest
+            .sources
+            .get("source.jaffle_shop.raw_customers")
+            .unwrap();
+
+        let result = resolve_source_table_desc(&manifest, &graph, source, &Config::default());
+
+        assert_eq!(
+            result,
+            InheritedValue::Resolved("Staged customer records".to_string())
+        );
+    }
+
+    #[test]
+    fn combine_source_candidates_skips_ambiguous_on_conflict_by_default() {
+        let downstream = InheritedValue::Resolved("From model".to_string());
+        let sibling = InheritedValue::Resolved("From sibling".to_string());
+
+        let result = combine_source_candidates(downstream, sibling, &Config::default());
+
+        match result {
+            InheritedValue::Ambiguous(mut values) => {
+                values.sort();
+                assert_eq!(
+                    values,
+                    vec!["From model".to_string(), "From sibling".to_string()]
+                );
+            }
+            other => panic!("expected Ambiguous, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn combine_source_candidates_prefers_configured_direction_on_conflict() {
+        let downstream = InheritedValue::Resolved("From model".to_string());
+        let sibling = InheritedValue::Resolved("From sibling".to_string());
+
+        let config = Config {
+            source_description_conflict_policy: SourceInheritanceConflictPolicy::PreferDirection,
+            source_description_inheritance_direction: SourceInheritanceDirection::Sibling,
+            ..Default::default()
+        };
+
+        let result = combine_source_candidates(downstream, sibling, &config);
+
+        assert_eq!(result, InheritedValue::Resolved("From sibling".to_string()));
+    }
 }
@@ -0,0 +1,130 @@
+//! Git-blame ownership attribution, so a failure can be routed to whoever last
+//! touched the offending code. Everything here is best-effort: outside a git repo,
+//! without `git` on `PATH`, or on any git error, lookups quietly return `None` rather
+//! than failing the check run. Gated behind `Config::blame` -- when it's off, callers
+//! shouldn't even construct a `BlameCache`.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::Mutex;
+
+/// Who last touched a line (or, failing that, a file), and in which commit.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Ownership {
+    pub author: String,
+    pub email: String,
+    pub commit: String,
+    pub timestamp: String,
+}
+
+impl std::fmt::Display for Ownership {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} <{}> ({})", self.author, self.email, self.commit)
+    }
+}
+
+/// Caches blame/log lookups per `(file, line)`, so a model with several failing
+/// columns in the same file only spawns one `git` process per distinct line (or, for
+/// whole-file lookups, one per file) instead of one per failure.
+#[derive(Debug, Default)]
+pub struct BlameCache {
+    entries: Mutex<HashMap<(PathBuf, Option<u32>), Option<Ownership>>>,
+}
+
+impl BlameCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Attribute `file`. `line` (1-indexed) attributes that specific line via `git
+    /// blame`, used for checks that point at a single definition (e.g. a missing
+    /// description); `None` attributes the file's most recent commit via `git log`,
+    /// used for structural checks with no single owning line.
+    pub fn attribute(&self, file: &Path, line: Option<u32>) -> Option<Ownership> {
+        let key = (file.to_path_buf(), line);
+        let mut entries = self.entries.lock().expect("blame cache poisoned");
+        if let Some(cached) = entries.get(&key) {
+            return cached.clone();
+        }
+        let owner = match line {
+            Some(line) => blame_line(file, line),
+            None => most_recent_commit(file),
+        };
+        entries.insert(key, owner.clone());
+        owner
+    }
+}
+
+fn blame_line(file: &Path, line: u32) -> Option<Ownership> {
+    let range = format!("{line},{line}");
+    let output = run_git(file, &["blame", "--line-porcelain", "-L", &range, "--"])?;
+    parse_porcelain_header(&output)
+}
+
+fn most_recent_commit(file: &Path) -> Option<Ownership> {
+    let output = run_git(
+        file,
+        &["log", "-1", "--format=%H%x1f%an%x1f%ae%x1f%aI", "--"],
+    )?;
+    let mut fields = output.trim().split('\x1f');
+    Some(Ownership {
+        commit: fields.next()?.to_string(),
+        author: fields.next()?.to_string(),
+        email: fields.next()?.to_string(),
+        timestamp: fields.next()?.to_string(),
+    })
+}
+
+/// Runs `git <args> <file>` from `file`'s parent directory, returning stdout on
+/// success. `None` on any I/O error, non-zero exit (e.g. untracked file, no repo), or
+/// a file with no parent directory.
+fn run_git(file: &Path, args: &[&str]) -> Option<String> {
+    let dir = file.parent()?;
+    let output = Command::new("git")
+        .current_dir(dir)
+        .args(args)
+        .arg(file)
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    Some(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Parses the header block of a single `git blame --line-porcelain` hunk -- stops at
+/// the first tab-prefixed source line, since `-L line,line` only ever emits one hunk.
+/// https://git-scm.com/docs/git-blame#_the_porcelain_format
+fn parse_porcelain_header(text: &str) -> Option<Ownership> {
+    let mut commit = None;
+    let mut author = None;
+    let mut email = None;
+    let mut timestamp = None;
+
+    for line in text.lines() {
+        if line.starts_with('\t') {
+            break;
+        }
+        if commit.is_none() && line.split_whitespace().next().is_some_and(is_commit_sha) {
+            commit = line.split_whitespace().next().map(str::to_string);
+        } else if let Some(rest) = line.strip_prefix("author ") {
+            author = Some(rest.to_string());
+        } else if let Some(rest) = line.strip_prefix("author-mail ") {
+            email = Some(rest.trim_matches(['<', '>']).to_string());
+        } else if let Some(rest) = line.strip_prefix("author-time ") {
+            timestamp = Some(rest.to_string());
+        }
+    }
+
+    Some(Ownership {
+        commit: commit?,
+        author: author?,
+        email: email?,
+        timestamp: timestamp?,
+    })
+}
+
+fn is_commit_sha(token: &str) -> bool {
+    token.len() == 40 && token.bytes().all(|b| b.is_ascii_hexdigit())
+}
@@ -1,6 +1,7 @@
 //! Module for loading a dbt project based on CLI arguments.
 //! Steals heavily from dbt-sa-cli's project loading logic.
 //! The main purpose is to just get the manifest (since serde can't read it from the manifest.json)
+use crate::manifest_cache;
 use clap::Parser;
 use dbt_common::{CodeLocation, FsResult, cancellation::CancellationTokenSource};
 use dbt_jinja_utils::{
@@ -36,6 +37,17 @@ pub struct DbtContext {
 }
 
 pub async fn load_project_from_cli_args(filtered_args: Vec<OsString>) -> FsResult<DbtContext> {
+    load_project_from_cli_args_with_cache(filtered_args, false).await
+}
+
+/// Same as [`load_project_from_cli_args`], but honors `no_cache` (the `--no-cache`
+/// escape hatch) -- when it's `false`, a resolved manifest is read from (and written to)
+/// [`crate::manifest_cache`] keyed by a fingerprint of the project's inputs, skipping
+/// `load`/`resolve`/`build_manifest` entirely on a cache hit.
+pub async fn load_project_from_cli_args_with_cache(
+    filtered_args: Vec<OsString>,
+    no_cache: bool,
+) -> FsResult<DbtContext> {
     let cli = Cli::parse_from(filtered_args);
     let system_args = from_main(&cli);
 
@@ -46,6 +58,17 @@ pub async fn load_project_from_cli_args(filtered_args: Vec<OsString>) -> FsResul
     let project_dir = load_args.io.in_dir.clone();
 
     let invocation_args = InvocationArgs::from_eval_args(&eval_args);
+
+    let cache_path = manifest_cache::cache_path(&project_dir);
+    let cache_fingerprint = manifest_cache::fingerprint(&project_dir, &invocation_args);
+    if !no_cache && let Some(manifest) = manifest_cache::try_load(&cache_path, cache_fingerprint) {
+        return Ok(DbtContext {
+            manifest,
+            invocation_args,
+            project_dir,
+        });
+    }
+
     let _cts = CancellationTokenSource::new();
     let token = _cts.token();
 
@@ -68,6 +91,10 @@ pub async fn load_project_from_cli_args(filtered_args: Vec<OsString>) -> FsResul
 
     let manifest = build_manifest(&invocation_id, &resolved_state);
 
+    if !no_cache {
+        let _ = manifest_cache::save(&cache_path, &manifest, cache_fingerprint);
+    }
+
     Ok(DbtContext {
         manifest,
         invocation_args,
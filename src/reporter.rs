@@ -0,0 +1,772 @@
+//! Structured reporting for `CheckResult`.
+//!
+//! `ModelResult`/`SourceResult`/`ModelFailure` only implement `Display`, which is fine
+//! for a human reading stdout but unusable by code-scanning tooling. This module
+//! flattens a `CheckResult` into a flat list of `Finding`s and renders them in
+//! whichever format the caller needs: human text, JSON, SARIF for CI code-scanning
+//! integrations, or JUnit XML for test dashboards.
+
+use crate::change_descriptors::{ModelChanges, SourceChanges};
+use crate::check::{
+    BlameTrail, CheckEvent, CheckResult, ColumnFailure, DocChange, ExposureChange, SourceFailure,
+};
+use crate::config::{Config, Selector, Severity};
+use crate::ownership::Ownership;
+use dbt_schemas::schemas::manifest::{DbtManifestV12, DbtNode};
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+
+/// Which kind of dbt resource a `Finding` is about. Drives `JunitReporter`'s
+/// `<testsuite>` grouping.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ResourceKind {
+    Model,
+    Source,
+    Exposure,
+}
+
+impl std::fmt::Display for ResourceKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            ResourceKind::Model => "model",
+            ResourceKind::Source => "source",
+            ResourceKind::Exposure => "exposure",
+        })
+    }
+}
+
+/// A single structured finding: one failure, located in the file that owns it.
+#[derive(Debug, Clone, Serialize)]
+pub struct Finding {
+    pub rule_id: String,
+    pub resource: ResourceKind,
+    pub model_id: String,
+    pub message: String,
+    pub file: PathBuf,
+    /// The 1-indexed line of `file` this finding's node starts at (its `- name:` entry,
+    /// or that column's entry if `column` is set), resolved via
+    /// `writeback::splice::locate_model_entry`/`locate_source_entry`. `None` if the node
+    /// has no properties file yet, the file couldn't be read, or (for exposures, which
+    /// have no writeback path of their own) location isn't resolved at all.
+    pub line: Option<usize>,
+    pub severity: Severity,
+    /// Whether `--fix` can clear this finding on its own, i.e. `Config::is_fixable` for
+    /// the `Selector` it's gated behind. `false` for findings with no backing selector
+    /// (e.g. `missing_required_tests`) or where no writeback path exists at all.
+    pub fixable: bool,
+    /// Set when this finding is about one column of `model_id` rather than the model as
+    /// a whole, so reporters that key a unit of output on "one thing" (e.g.
+    /// `JunitReporter`'s `<testcase>`) can tell a column failure apart from a model-level
+    /// one instead of folding both under the same name.
+    pub column: Option<String>,
+    /// For graph-derived checks (e.g. `rejoining_of_upstream_concepts`, `model_fanout`,
+    /// `dead_model`), the specific upstream/downstream nodes responsible. Empty for
+    /// checks that aren't graph-derived, or where no specific culprit node applies.
+    pub blame: Vec<BlameTrail>,
+    /// Who last touched the failing file, per `Config::blame`. Empty when blame
+    /// attribution is off (the default).
+    pub owners: Vec<Ownership>,
+}
+
+/// Accumulates `Finding`s from a live `CheckEvent` stream -- the same stream
+/// `check_all_with_report`'s `report` callback and `JunitReporter::record` already
+/// consume -- so JSON/SARIF output can be produced from that one pass instead of
+/// re-walking the finished `CheckResult` a second time.
+#[derive(Debug, Default)]
+pub struct FindingsCollector {
+    findings: Vec<Finding>,
+}
+
+impl FindingsCollector {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record one `CheckEvent`. `manifest`/`config` locate the file and resolve the
+    /// severity each failure is attributed to, same as `collect_findings`.
+    pub fn record(&mut self, event: CheckEvent<'_>, manifest: &DbtManifestV12, config: &Config) {
+        match event {
+            CheckEvent::Model(model_result) => {
+                let file = model_file(manifest, model_result.model_id());
+                self.findings
+                    .extend(model_result.failures().iter().map(|entry| Finding {
+                        rule_id: entry.failure.as_ref().to_string(),
+                        resource: ResourceKind::Model,
+                        model_id: model_result.model_id().to_string(),
+                        message: entry.failure.to_string(),
+                        file: file.clone(),
+                        line: model_entry_line(manifest, config, model_result.model_id(), None),
+                        severity: entry.severity,
+                        fixable: entry
+                            .failure
+                            .selector()
+                            .is_some_and(|selector| config.is_fixable(selector)),
+                        column: None,
+                        blame: entry.blame.clone(),
+                        owners: entry.owners.clone(),
+                    }));
+
+                for column_result in model_result.column_results.values() {
+                    self.findings.extend(
+                        column_result
+                            .failures
+                            .iter()
+                            .zip(column_result.failure_reasons())
+                            .map(|(failure, reason)| Finding {
+                                rule_id: column_rule_id(failure).to_string(),
+                                resource: ResourceKind::Model,
+                                model_id: model_result.model_id().to_string(),
+                                message: reason,
+                                file: file.clone(),
+                                line: model_entry_line(
+                                    manifest,
+                                    config,
+                                    model_result.model_id(),
+                                    Some(&column_result.column_name),
+                                ),
+                                severity: config.severity(Selector::MissingColumnDescriptions),
+                                fixable: config.is_fixable(Selector::MissingColumnDescriptions),
+                                column: Some(column_result.column_name.clone()),
+                                blame: Vec::new(),
+                                owners: Vec::new(),
+                            }),
+                    );
+                }
+            }
+            CheckEvent::Source(source_result) => {
+                let file = source_file(manifest, source_result.source_id());
+                self.findings
+                    .extend(source_result.failures.iter().map(|entry| Finding {
+                        rule_id: source_rule_id(&entry.failure).to_string(),
+                        resource: ResourceKind::Source,
+                        model_id: source_result.source_id().to_string(),
+                        message: entry.failure.to_string(),
+                        file: file.clone(),
+                        line: source_entry_line(manifest, config, source_result.source_id(), None),
+                        severity: entry.severity,
+                        fixable: entry
+                            .failure
+                            .selector()
+                            .is_some_and(|selector| config.is_fixable(selector)),
+                        column: None,
+                        blame: Vec::new(),
+                        owners: entry.owners.clone(),
+                    }));
+            }
+            CheckEvent::Exposure(exposure_result) => {
+                let file = exposure_file(manifest, &exposure_result.exposure_id);
+                self.findings
+                    .extend(exposure_result.failures.iter().map(|entry| Finding {
+                        rule_id: entry.failure.as_ref().to_string(),
+                        resource: ResourceKind::Exposure,
+                        model_id: exposure_result.exposure_id.clone(),
+                        message: entry.failure.to_string(),
+                        file: file.clone(),
+                        // No writeback path exists for an exposure's own properties file
+                        // (see `check::exposures`), so there's nothing to locate a line
+                        // against.
+                        line: None,
+                        severity: entry.severity,
+                        fixable: entry
+                            .failure
+                            .selector()
+                            .is_some_and(|selector| config.is_fixable(selector)),
+                        column: None,
+                        blame: Vec::new(),
+                        owners: Vec::new(),
+                    }));
+            }
+            // Docs don't have a finding-worthy "owning resource" the way a model/source/
+            // exposure does -- `DuplicateDocsBlock` is surfaced via `CheckResult.docs` and
+            // the human/JSON-full reporters instead of the flattened `Finding` list.
+            CheckEvent::Doc(_) => {}
+        }
+    }
+
+    pub fn finish(self) -> Vec<Finding> {
+        self.findings
+    }
+}
+
+/// Flatten every model, column, source, and exposure failure in a finished
+/// `CheckResult` into structured findings. Prefer feeding a [`FindingsCollector`]
+/// straight from the `CheckEvent` stream instead where one is already available (e.g.
+/// in `check_all_with_report`'s callback) -- this re-walks `result` from scratch, which
+/// only earns its keep when the result was mutated after the fact (e.g. by baseline
+/// filtering) and there's no live stream left to collect from.
+pub fn collect_findings(manifest: &DbtManifestV12, config: &Config, result: &CheckResult) -> Vec<Finding> {
+    let mut collector = FindingsCollector::new();
+    for model_result in result.models.values() {
+        collector.record(CheckEvent::Model(model_result), manifest, config);
+    }
+    for source_result in result.sources.values() {
+        collector.record(CheckEvent::Source(source_result), manifest, config);
+    }
+    for exposure_result in result.exposures.values() {
+        collector.record(CheckEvent::Exposure(exposure_result), manifest, config);
+    }
+    collector.finish()
+}
+
+fn model_file(manifest: &DbtManifestV12, model_id: &str) -> PathBuf {
+    match manifest.nodes.get(model_id) {
+        Some(DbtNode::Model(model)) => model
+            .__common_attr__
+            .patch_path
+            .clone()
+            .unwrap_or_else(|| model.__common_attr__.original_file_path.clone()),
+        _ => PathBuf::new(),
+    }
+}
+
+fn source_file(manifest: &DbtManifestV12, source_id: &str) -> PathBuf {
+    manifest
+        .sources
+        .get(source_id)
+        .and_then(|source| source.__common_attr__.patch_path.clone())
+        .unwrap_or_default()
+}
+
+/// The 1-indexed line `model_id`'s entry (or, if `column_name` is given, that column's
+/// entry) starts at in its properties file, via `writeback::splice::locate_model_entry`.
+/// `None` if the model has no properties file yet (e.g. a bare `.sql` file with no
+/// matching `schema.yml` entry) or the file can't be read -- reporters fall back to just
+/// `Finding::file` in that case.
+fn model_entry_line(
+    manifest: &DbtManifestV12,
+    config: &Config,
+    model_id: &str,
+    column_name: Option<&str>,
+) -> Option<usize> {
+    let Some(DbtNode::Model(model)) = manifest.nodes.get(model_id) else {
+        return None;
+    };
+    let patch_path = model.__common_attr__.patch_path.as_ref()?;
+    let contents = std::fs::read_to_string(resolve_path(config, patch_path)).ok()?;
+    crate::writeback::splice::locate_model_entry(&contents, &model.__common_attr__.name, column_name)
+}
+
+/// Like `model_entry_line`, one level deeper for a source table (or one of its columns),
+/// via `writeback::splice::locate_source_entry`.
+fn source_entry_line(
+    manifest: &DbtManifestV12,
+    config: &Config,
+    source_id: &str,
+    column_name: Option<&str>,
+) -> Option<usize> {
+    let source = manifest.sources.get(source_id)?;
+    let patch_path = source.__common_attr__.patch_path.as_ref()?;
+    let contents = std::fs::read_to_string(resolve_path(config, patch_path)).ok()?;
+    crate::writeback::splice::locate_source_entry(
+        &contents,
+        &source.source_name,
+        &source.__common_attr__.name,
+        column_name,
+    )
+}
+
+/// Joins `relative` onto `config.project_dir` the same way `check::models::model_file`
+/// does, so a patch path is read relative to the project root rather than the process's
+/// current directory.
+fn resolve_path(config: &Config, relative: &Path) -> PathBuf {
+    match &config.project_dir {
+        Some(project_dir) => project_dir.join(relative),
+        None => relative.to_path_buf(),
+    }
+}
+
+fn exposure_file(manifest: &DbtManifestV12, exposure_id: &str) -> PathBuf {
+    manifest
+        .exposures
+        .get(exposure_id)
+        .and_then(|exposure| exposure.__common_attr__.patch_path.clone())
+        .unwrap_or_default()
+}
+
+/// Both `ColumnFailure` variants come from the same `missing_column_descriptions`
+/// check (see `check::columns`), so they share one rule id.
+fn column_rule_id(failure: &ColumnFailure) -> &'static str {
+    match failure {
+        ColumnFailure::DescriptionMissing => "missing_column_descriptions",
+        ColumnFailure::AmbiguousInheritance(_) => "missing_column_descriptions",
+    }
+}
+
+/// `SourceFailure` doesn't derive `AsRefStr` in `snake_case` the way `ModelFailure`
+/// does (its `Display` needs the `PascalCase` variant name for `DuplicateDefinition`'s
+/// `DuplicateDefinition:{id}` rendering), so the stable, tool-facing rule id is kept
+/// separate here rather than changing user-facing output to get it.
+fn source_rule_id(failure: &SourceFailure) -> &'static str {
+    match failure {
+        SourceFailure::MissingDescription => "missing_description",
+        SourceFailure::AmbiguousSourceDescription(_) => "missing_description",
+        SourceFailure::DuplicateDefinition(_) => "duplicate_definition",
+        SourceFailure::UnusedSource => "unused_source",
+        SourceFailure::MissingFreshness => "missing_freshness",
+        SourceFailure::MissingSourceDescription => "missing_source_description",
+        SourceFailure::SourceTableColumnDescriptions => "source_table_column_descriptions",
+        SourceFailure::SourceFanout => "source_fanout",
+    }
+}
+
+/// `(rule_id, doc_url)` pairs for every rule_id that has a published
+/// dbt-project-evaluator writeup, taken from the same URLs already cited above each
+/// rule's check function in `check::models`/`check::sources`.
+const RULE_DOCS: &[(&str, &str)] = &[
+    (
+        "multiple_sources_joined",
+        "https://dbt-labs.github.io/dbt-project-evaluator/latest/rules/modeling/#multiple-sources-joined",
+    ),
+    (
+        "direct_join_to_source",
+        "https://dbt-labs.github.io/dbt-project-evaluator/latest/rules/modeling/#direct-join-to-source",
+    ),
+    (
+        "model_fanout",
+        "https://dbt-labs.github.io/dbt-project-evaluator/latest/rules/modeling/#model-fanout",
+    ),
+    (
+        "root_model",
+        "https://dbt-labs.github.io/dbt-project-evaluator/latest/rules/modeling/#root-models",
+    ),
+    (
+        "missing_primary_key",
+        "https://dbt-labs.github.io/dbt-project-evaluator/latest/rules/testing/#missing-primary-key-tests",
+    ),
+    (
+        "rejoining_of_upstream_concepts",
+        "https://dbt-labs.github.io/dbt-project-evaluator/latest/rules/modeling/#rejoining-of-upstream-concepts",
+    ),
+    (
+        "missing_source_description",
+        "https://dbt-labs.github.io/dbt-project-evaluator/latest/rules/documentation/#undocumented-sources",
+    ),
+    (
+        "source_fanout",
+        "https://dbt-labs.github.io/dbt-project-evaluator/latest/rules/modeling/#source-fanout",
+    ),
+    (
+        "duplicate_definition",
+        "https://dbt-labs.github.io/dbt-project-evaluator/latest/rules/modeling/#duplicate-sources",
+    ),
+    (
+        "unused_source",
+        "https://dbt-labs.github.io/dbt-project-evaluator/latest/rules/modeling/#unused-sources",
+    ),
+    (
+        "missing_freshness",
+        "https://dbt-labs.github.io/dbt-project-evaluator/latest/rules/testing/#missing-source-freshness",
+    ),
+];
+
+fn rule_doc_url(rule_id: &str) -> Option<&'static str> {
+    RULE_DOCS
+        .iter()
+        .find(|(id, _)| *id == rule_id)
+        .map(|(_, url)| *url)
+}
+
+/// Renders a set of findings in a particular output format.
+pub trait Reporter {
+    fn render(&self, findings: &[Finding]) -> String;
+}
+
+pub struct HumanReporter;
+
+impl Reporter for HumanReporter {
+    fn render(&self, findings: &[Finding]) -> String {
+        findings
+            .iter()
+            .map(|f| {
+                let mut line = format!("{}: {} ({})", f.model_id, f.message, f.file.display());
+                for trail in &f.blame {
+                    line.push_str(&format!("; {trail}"));
+                }
+                for owner in &f.owners {
+                    line.push_str(&format!("; owner: {owner}"));
+                }
+                line
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+pub struct JsonReporter;
+
+impl Reporter for JsonReporter {
+    fn render(&self, findings: &[Finding]) -> String {
+        serde_json::to_string_pretty(findings).expect("findings should always serialize")
+    }
+}
+
+/// Serializes the whole `CheckResult` -- every model/source/exposure, passing ones
+/// included, plus `model_changes` -- rather than `JsonReporter`'s flattened failures
+/// list. Useful for callers that want the full run's shape (e.g. to diff two runs)
+/// instead of just what went wrong.
+pub fn render_full_result_json(result: &CheckResult) -> String {
+    serde_json::to_string_pretty(result).expect("CheckResult should always serialize")
+}
+
+/// One line of NDJSON per `CheckEvent`, printed as each model/source/exposure is
+/// checked rather than buffered until the run finishes -- the streaming counterpart to
+/// `render_full_result_json`'s single end-of-run object, analogous to cargo's
+/// line-delimited `--message-format=json` output. Each line carries its own
+/// pass/fail verdict, failure reasons, and the `ModelChange`/`SourceChange`/
+/// `ExposureChange` (with `ColumnChange` variants nested in a model's
+/// `column_changes`) that resource would get from `--fix`, so CI tooling can key off
+/// a single resource without waiting for or parsing the rest of the stream.
+#[derive(Serialize)]
+#[serde(tag = "resource", rename_all = "snake_case")]
+enum StreamEvent<'a> {
+    Model {
+        id: &'a str,
+        pass: bool,
+        failure_reasons: Vec<String>,
+        changes: Option<&'a ModelChanges>,
+    },
+    Source {
+        id: &'a str,
+        pass: bool,
+        failure_reasons: Vec<String>,
+        changes: Option<&'a SourceChanges>,
+    },
+    Exposure {
+        id: &'a str,
+        pass: bool,
+        failure_reasons: Vec<String>,
+        changes: &'a [ExposureChange],
+    },
+    Doc {
+        id: &'a str,
+        pass: bool,
+        failure_reasons: Vec<String>,
+        changes: &'a [DocChange],
+    },
+}
+
+pub fn render_event_json_line(event: CheckEvent<'_>) -> String {
+    let stream_event = match event {
+        CheckEvent::Model(result) => StreamEvent::Model {
+            id: result.model_id(),
+            pass: result.is_pass(),
+            failure_reasons: result.failure_reasons(),
+            changes: result.changes(),
+        },
+        CheckEvent::Source(result) => StreamEvent::Source {
+            id: result.source_id(),
+            pass: result.is_pass(),
+            failure_reasons: result.failure_reasons(),
+            changes: result.changes(),
+        },
+        CheckEvent::Exposure(result) => StreamEvent::Exposure {
+            id: &result.exposure_id,
+            pass: result.is_pass(),
+            failure_reasons: result.failures.iter().map(ToString::to_string).collect(),
+            changes: &result.changes,
+        },
+        CheckEvent::Doc(result) => StreamEvent::Doc {
+            id: &result.doc_name,
+            pass: result.is_pass(),
+            failure_reasons: result.failure_reasons(),
+            changes: &result.changes,
+        },
+    };
+    serde_json::to_string(&stream_event).expect("stream event should always serialize")
+}
+
+/// https://docs.oasis-open.org/sarif/sarif/v2.1.0/
+///
+/// Each `Finding`'s `rule_id` is a `Selector` in `snake_case` (or the equivalent source
+/// rule id for checks no `Selector` backs yet), rendered as one SARIF `rule`.
+/// `physicalLocation` carries a `region` with `f.line` when one resolved (see
+/// `model_entry_line`/`source_entry_line`); otherwise just the file path.
+pub struct SarifReporter;
+
+impl Reporter for SarifReporter {
+    fn render(&self, findings: &[Finding]) -> String {
+        let mut rule_ids: Vec<&str> = findings.iter().map(|f| f.rule_id.as_str()).collect();
+        rule_ids.sort_unstable();
+        rule_ids.dedup();
+        let rules: Vec<serde_json::Value> = rule_ids
+            .iter()
+            .map(|rule_id| {
+                let mut rule = serde_json::json!({ "id": rule_id });
+                if let Some(url) = rule_doc_url(rule_id) {
+                    rule["helpUri"] = serde_json::Value::String(url.to_string());
+                }
+                rule
+            })
+            .collect();
+
+        let results: Vec<serde_json::Value> = findings
+            .iter()
+            .map(|f| {
+                let level = match f.severity {
+                    Severity::Error => "error",
+                    Severity::Warn => "warning",
+                    Severity::Info => "note",
+                    // Unreachable in practice -- `Off` findings are dropped before they
+                    // become a `Finding` (see `check::models::record_failure`) -- but
+                    // `none` is SARIF's own suppressed-level value.
+                    Severity::Off => "none",
+                };
+                let related_locations: Vec<serde_json::Value> = f
+                    .blame
+                    .iter()
+                    .map(|trail| {
+                        serde_json::json!({
+                            "message": { "text": trail.to_string() }
+                        })
+                    })
+                    .collect();
+                let owners: Vec<serde_json::Value> = f
+                    .owners
+                    .iter()
+                    .map(|owner| {
+                        serde_json::json!({
+                            "author": owner.author,
+                            "email": owner.email,
+                            "commit": owner.commit,
+                            "timestamp": owner.timestamp,
+                        })
+                    })
+                    .collect();
+                let mut physical_location = serde_json::json!({
+                    "artifactLocation": { "uri": f.file.to_string_lossy() }
+                });
+                if let Some(line) = f.line {
+                    physical_location["region"] = serde_json::json!({ "startLine": line });
+                }
+                serde_json::json!({
+                    "ruleId": f.rule_id,
+                    "level": level,
+                    "message": { "text": f.message },
+                    "locations": [{ "physicalLocation": physical_location }],
+                    "relatedLocations": related_locations,
+                    "properties": { "owners": owners }
+                })
+            })
+            .collect();
+
+        let sarif = serde_json::json!({
+            "$schema": "https://raw.githubusercontent.com/oasis-tcs/sarif-spec/master/Schemata/sarif-schema-2.1.0.json",
+            "version": "2.1.0",
+            "runs": [{
+                "tool": {
+                    "driver": {
+                        "name": env!("CARGO_PKG_NAME"),
+                        "version": env!("CARGO_PKG_VERSION"),
+                        "rules": rules,
+                    }
+                },
+                "results": results
+            }]
+        });
+
+        serde_json::to_string_pretty(&sarif).expect("sarif value should always serialize")
+    }
+}
+
+/// https://docs.github.com/en/actions/using-workflows/workflow-commands-for-github-actions#setting-an-error-message
+///
+/// One workflow command per finding (`::error`/`::warning`/`::notice file=...,line=...::
+/// message`), so a run surfaces as inline PR annotations instead of plain log lines.
+/// `line` is only included when `f.line` resolved.
+pub struct GithubActionsReporter;
+
+impl Reporter for GithubActionsReporter {
+    fn render(&self, findings: &[Finding]) -> String {
+        findings
+            .iter()
+            .map(|f| {
+                let command = match f.severity {
+                    Severity::Error => "error",
+                    Severity::Warn => "warning",
+                    // Workflow commands don't have an "off" level; `Info` and the
+                    // (in practice unreachable, see `SarifReporter`) `Off` both render
+                    // as GitHub's lowest-severity `notice`.
+                    Severity::Info | Severity::Off => "notice",
+                };
+                let mut params = vec![
+                    format!("file={}", f.file.display()),
+                    format!("title={}", f.rule_id),
+                ];
+                if let Some(line) = f.line {
+                    params.push(format!("line={line}"));
+                }
+                format!(
+                    "::{command} {}::{}",
+                    params.join(","),
+                    escape_workflow_command_value(&f.message)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// Workflow command values are percent-escaped for `%`, `\r`, and `\n` so a message
+/// doesn't get truncated or split across its own command's delimiters.
+fn escape_workflow_command_value(value: &str) -> String {
+    value.replace('%', "%25").replace('\r', "%0D").replace('\n', "%0A")
+}
+
+/// https://github.com/testmoapp/junitxml (the de facto JUnit XML schema CI dashboards
+/// parse). Built directly on the `CheckEvent` stream `check_all_with_report` already
+/// emits, rather than on the pre-flattened `&[Finding]` list `JsonReporter`/
+/// `SarifReporter` render from: a passing node still needs its own (empty) `<testcase>`
+/// so CI can count it as a passing test, and a `Finding`-only view has no way to tell
+/// "passed" from "never checked" since a passing node never produces a `Finding`.
+/// One `<testsuite>` per resource category (models/sources/exposures), one `<testcase>`
+/// per node keyed by its id, plus one further `<testcase>` per failing column keyed
+/// `model.column` so tools that don't understand custom `<property>` tags still surface
+/// column-level failures.
+#[derive(Debug, Default)]
+pub struct JunitReporter {
+    models: JunitSuite,
+    sources: JunitSuite,
+    exposures: JunitSuite,
+}
+
+#[derive(Debug, Default)]
+struct JunitSuite {
+    tests: usize,
+    failures: usize,
+    testcases: String,
+}
+
+impl JunitSuite {
+    /// Append one `<testcase>` -- empty if `entries` is empty -- and bump this suite's
+    /// running counts. `entries` is `(rule_id, message, body)` per failure on this node.
+    fn push_testcase(&mut self, classname: &str, name: &str, entries: &[(String, String, String)]) {
+        self.tests += 1;
+        if !entries.is_empty() {
+            self.failures += 1;
+        }
+
+        self.testcases.push_str(&format!(
+            "    <testcase classname=\"{}\" name=\"{}\">\n",
+            escape_xml(classname),
+            escape_xml(name),
+        ));
+        for (rule_id, message, body) in entries {
+            self.testcases.push_str(&format!(
+                "      <failure type=\"{}\" message=\"{}\">{}</failure>\n",
+                escape_xml(rule_id),
+                escape_xml(message),
+                escape_xml(body),
+            ));
+        }
+        self.testcases.push_str("    </testcase>\n");
+    }
+
+    fn render(&self, suite_name: &str, xml: &mut String) {
+        xml.push_str(&format!(
+            "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\">\n",
+            escape_xml(suite_name),
+            self.tests,
+            self.failures,
+        ));
+        xml.push_str(&self.testcases);
+        xml.push_str("  </testsuite>\n");
+    }
+}
+
+impl JunitReporter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record one `CheckEvent`. `manifest` locates the file each failure is attributed
+    /// to, the same way `collect_findings` does for the other reporters.
+    pub fn record(&mut self, event: CheckEvent<'_>, manifest: &DbtManifestV12) {
+        match event {
+            CheckEvent::Model(model_result) => {
+                let file = model_file(manifest, model_result.model_id());
+                let entries: Vec<(String, String, String)> = model_result
+                    .failures()
+                    .iter()
+                    .map(|entry| {
+                        let mut body = file.to_string_lossy().to_string();
+                        for owner in &entry.owners {
+                            body.push_str(&format!("\nowner: {owner}"));
+                        }
+                        (entry.failure.as_ref().to_string(), entry.failure.to_string(), body)
+                    })
+                    .collect();
+                self.models.push_testcase("models", model_result.model_id(), &entries);
+
+                for column_result in model_result.column_results.values() {
+                    if column_result.is_pass() {
+                        continue;
+                    }
+                    let name = format!("{}.{}", model_result.model_id(), column_result.column_name);
+                    let entries: Vec<(String, String, String)> = column_result
+                        .failures
+                        .iter()
+                        .zip(column_result.failure_reasons())
+                        .map(|(failure, reason)| {
+                            (column_rule_id(failure).to_string(), reason, file.to_string_lossy().to_string())
+                        })
+                        .collect();
+                    self.models.push_testcase("models", &name, &entries);
+                }
+            }
+            CheckEvent::Source(source_result) => {
+                let file = source_file(manifest, source_result.source_id());
+                let entries: Vec<(String, String, String)> = source_result
+                    .failures
+                    .iter()
+                    .map(|entry| {
+                        let mut body = file.to_string_lossy().to_string();
+                        for owner in &entry.owners {
+                            body.push_str(&format!("\nowner: {owner}"));
+                        }
+                        (source_rule_id(&entry.failure).to_string(), entry.failure.to_string(), body)
+                    })
+                    .collect();
+                self.sources.push_testcase("sources", source_result.source_id(), &entries);
+            }
+            CheckEvent::Exposure(exposure_result) => {
+                let file = exposure_file(manifest, &exposure_result.exposure_id);
+                let entries: Vec<(String, String, String)> = exposure_result
+                    .failures
+                    .iter()
+                    .map(|entry| {
+                        (
+                            entry.failure.as_ref().to_string(),
+                            entry.failure.to_string(),
+                            file.to_string_lossy().to_string(),
+                        )
+                    })
+                    .collect();
+                self.exposures
+                    .push_testcase("exposures", &exposure_result.exposure_id, &entries);
+            }
+            // See the matching arm in `FindingsCollector::record` -- docs don't get a
+            // `<testsuite>` of their own yet.
+            CheckEvent::Doc(_) => {}
+        }
+    }
+
+    /// Render every suite recorded so far into one `<testsuites>` document, writing the
+    /// XML header exactly once now that every event has been accounted for.
+    pub fn finish(self) -> String {
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<testsuites>\n");
+        self.models.render("models", &mut xml);
+        self.sources.render("sources", &mut xml);
+        self.exposures.render("exposures", &mut xml);
+        xml.push_str("</testsuites>\n");
+        xml
+    }
+}
+
+fn escape_xml(raw: &str) -> String {
+    raw.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
@@ -0,0 +1,337 @@
+//! Turns a `CheckResult`'s raw failures into an edit list. Fixes are listed in the order
+//! models and then sources are visited; they are neither ranked nor deduplicated.
+//!
+//! `ModelFailureEntry::blame` already carries the culprit node(s) for graph-derived
+//! checks (see `check::models::blame_for`). This module adds the one case that isn't
+//! computed there -- an unresolved missing column description is "caused by" the
+//! nearest upstream model that also lacks it, so fixing that model fixes every
+//! downstream column too -- and unifies every selector's remedy, whether or not
+//! `--fix` already knows how to apply it, into a single list instead of a flat
+//! `failure_reasons()` dump.
+
+use crate::change_descriptors::ModelChange;
+use crate::check::{BlameTrail, CheckResult, ModelFailure, SourceFailure, missing_description};
+use crate::config::Config;
+use crate::graph::DbtGraph;
+use dbt_schemas::schemas::manifest::{DbtManifestV12, DbtNode};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// A remedy for one finding. `ModelEdit` wraps the same `ModelChange` writeback already
+/// knows how to apply under `--fix`; the other variants describe a fix `--fix` can't
+/// produce on its own, either because it needs a human judgment call (which column is
+/// the primary key) or because there's no writeback path for that selector at all.
+#[derive(Debug, Clone)]
+pub enum SuggestedFix {
+    /// An edit writeback already knows how to make (or has made, if `config.fix` was
+    /// set) to a model's properties file.
+    ModelEdit { model_id: String, change: ModelChange },
+    /// `model_id` has no `primary_key` configured. `column` is the best guess at which
+    /// column it should be, to add a `unique` + `not_null` data test to.
+    AddPrimaryKeyTest {
+        model_id: String,
+        model_name: String,
+        column: String,
+    },
+    /// `source_id`/`table_name` has no `freshness:` block at all, or one with neither
+    /// `warn_after` nor `error_after` set.
+    AddSourceFreshnessBlock { source_id: String, table_name: String },
+    /// No structured fix exists for this finding; `description` is what a human needs
+    /// to do instead.
+    Manual { model_id: String, description: String },
+}
+
+/// `blame`: every node responsible for a model's finding(s), keyed by the model that
+/// failed. `fixes`: every suggested edit across every model and source, in visit order;
+/// repeated edits are not merged.
+#[derive(Debug, Clone, Default)]
+pub struct SuggestReport {
+    pub blame: BTreeMap<String, Vec<BlameTrail>>,
+    pub fixes: Vec<SuggestedFix>,
+}
+
+/// Build a `SuggestReport` from a finished `CheckResult`.
+pub fn suggest(manifest: &DbtManifestV12, config: &Config, result: &CheckResult) -> SuggestReport {
+    let graph = DbtGraph::from(manifest);
+    let mut blame: BTreeMap<String, Vec<BlameTrail>> = BTreeMap::new();
+    let mut fixes: Vec<SuggestedFix> = Vec::new();
+
+    for model_result in result.models.values() {
+        let model_id = model_result.model_id().to_string();
+
+        for entry in model_result.failures() {
+            if !entry.blame.is_empty() {
+                blame
+                    .entry(model_id.clone())
+                    .or_default()
+                    .extend(entry.blame.iter().cloned());
+            }
+
+            if entry.failure == ModelFailure::MissingPrimaryKey {
+                fixes.push(primary_key_fix(manifest, &model_id));
+            }
+        }
+
+        for (column_name, column_result) in &model_result.column_results {
+            if column_result.is_failure()
+                && let Some(trail) =
+                    blame_for_missing_column(manifest, &graph, config, &model_id, column_name)
+            {
+                blame.entry(model_id.clone()).or_default().push(trail);
+            }
+        }
+
+        if let Some(changes) = model_result.changes() {
+            fixes.extend(changes.changes.iter().map(|change| SuggestedFix::ModelEdit {
+                model_id: model_id.clone(),
+                change: change.clone(),
+            }));
+        }
+    }
+
+    for source_result in result.sources.values() {
+        if source_result
+            .failures
+            .iter()
+            .any(|entry| matches!(entry.failure, SourceFailure::MissingFreshness))
+            && let Some(source) = manifest.sources.get(source_result.source_id())
+        {
+            fixes.push(SuggestedFix::AddSourceFreshnessBlock {
+                source_id: source_result.source_id().to_string(),
+                table_name: source.__common_attr__.name.clone(),
+            });
+        }
+    }
+
+    SuggestReport { blame, fixes }
+}
+
+/// `model_id` has no declared `primary_key`. Guess which column it should be, preferring
+/// an exact `id` column, then one named `<model>_id`; falls back to a manual note when
+/// neither exists, since guessing wrong would point the user at the wrong column.
+fn primary_key_fix(manifest: &DbtManifestV12, model_id: &str) -> SuggestedFix {
+    let model_name = model_id.rsplit('.').next().unwrap_or(model_id).to_string();
+
+    let candidate = match manifest.nodes.get(model_id) {
+        Some(DbtNode::Model(model)) => model
+            .__base_attr__
+            .columns
+            .get("id")
+            .map(|_| "id".to_string())
+            .or_else(|| {
+                let suffixed = format!("{model_name}_id");
+                model
+                    .__base_attr__
+                    .columns
+                    .get(suffixed.as_str())
+                    .map(|_| suffixed)
+            }),
+        _ => None,
+    };
+
+    match candidate {
+        Some(column) => SuggestedFix::AddPrimaryKeyTest {
+            model_id: model_id.to_string(),
+            model_name,
+            column,
+        },
+        None => SuggestedFix::Manual {
+            model_id: model_id.to_string(),
+            description: format!(
+                "{model_name} has no primary_key configured and no column looked like an \
+                 obvious candidate (e.g. `id`, `{model_name}_id`); add one to `primary_key` \
+                 before a uniqueness+not_null test can be suggested."
+            ),
+        },
+    }
+}
+
+/// Walk `model_id`'s ancestors breadth-first (nearest first) for the closest upstream
+/// model that also lacks a valid description for `column_name` -- the same search order
+/// `osmosis::resolve_upstream_col_desc` uses to *find* a description, just inverted to
+/// explain why none was found. Fixing that ancestor fixes every column it feeds.
+fn blame_for_missing_column(
+    manifest: &DbtManifestV12,
+    graph: &DbtGraph,
+    config: &Config,
+    model_id: &str,
+    column_name: &str,
+) -> Option<BlameTrail> {
+    let mut visited: BTreeSet<String> = BTreeSet::from([model_id.to_string()]);
+    let mut frontier: Vec<Vec<String>> = graph
+        .parents(model_id)
+        .map(|parent_id| {
+            visited.insert(parent_id.clone());
+            vec![model_id.to_string(), parent_id]
+        })
+        .collect();
+
+    while !frontier.is_empty() {
+        let mut next_frontier: Vec<Vec<String>> = Vec::new();
+
+        for path in &frontier {
+            let ancestor_id = path.last().expect("path always has at least one hop");
+
+            if let Some(DbtNode::Model(model)) = manifest.nodes.get(ancestor_id)
+                && let Some(column) = model.__base_attr__.columns.get(column_name)
+                && missing_description(column, config).is_err()
+            {
+                let mut reversed = path.clone();
+                reversed.reverse();
+                return Some(BlameTrail {
+                    node_id: ancestor_id.clone(),
+                    path: reversed,
+                });
+            }
+
+            for grandparent in graph.parents(ancestor_id) {
+                if visited.insert(grandparent.clone()) {
+                    let mut next_path = path.clone();
+                    next_path.push(grandparent);
+                    next_frontier.push(next_path);
+                }
+            }
+        }
+
+        frontier = next_frontier;
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Selector;
+    use dbt_schemas::schemas::dbt_column::DbtColumn;
+    use std::sync::Arc;
+
+    fn model_with_column(unique_id: &str, column_name: &str, description: Option<&str>) -> DbtNode {
+        let mut model = dbt_schemas::schemas::manifest::ManifestModel::default();
+        model.__common_attr__.unique_id = unique_id.to_string();
+        model.__base_attr__.columns.push(Arc::new(DbtColumn {
+            name: column_name.to_string(),
+            description: description.map(str::to_string),
+            ..Default::default()
+        }));
+        DbtNode::Model(model)
+    }
+
+    #[test]
+    fn blame_for_missing_column_finds_nearest_ancestor_still_missing_it() {
+        let mut manifest = DbtManifestV12::default();
+        manifest.nodes.insert(
+            "model.test.upstream".to_string(),
+            model_with_column("model.test.upstream", "customer_id", None),
+        );
+        manifest.nodes.insert(
+            "model.test.downstream".to_string(),
+            model_with_column("model.test.downstream", "customer_id", None),
+        );
+        manifest.child_map.insert(
+            "model.test.upstream".to_string(),
+            vec!["model.test.downstream".to_string()],
+        );
+        let graph = DbtGraph::from(&manifest);
+        let config = Config::default();
+
+        let trail = blame_for_missing_column(
+            &manifest,
+            &graph,
+            &config,
+            "model.test.downstream",
+            "customer_id",
+        )
+        .expect("upstream also lacks the description");
+
+        assert_eq!(trail.node_id, "model.test.upstream");
+        assert_eq!(
+            trail.path,
+            vec![
+                "model.test.upstream".to_string(),
+                "model.test.downstream".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn blame_for_missing_column_stops_when_ancestor_has_it() {
+        let mut manifest = DbtManifestV12::default();
+        manifest.nodes.insert(
+            "model.test.upstream".to_string(),
+            model_with_column("model.test.upstream", "customer_id", Some("has one")),
+        );
+        manifest.nodes.insert(
+            "model.test.downstream".to_string(),
+            model_with_column("model.test.downstream", "customer_id", None),
+        );
+        manifest.child_map.insert(
+            "model.test.upstream".to_string(),
+            vec!["model.test.downstream".to_string()],
+        );
+        let graph = DbtGraph::from(&manifest);
+        let config = Config::default();
+
+        assert!(
+            blame_for_missing_column(
+                &manifest,
+                &graph,
+                &config,
+                "model.test.downstream",
+                "customer_id",
+            )
+            .is_none()
+        );
+    }
+
+    #[test]
+    fn primary_key_fix_prefers_exact_id_column() {
+        let mut manifest = DbtManifestV12::default();
+        manifest.nodes.insert(
+            "model.test.orders".to_string(),
+            model_with_column("model.test.orders", "id", None),
+        );
+
+        let fix = primary_key_fix(&manifest, "model.test.orders");
+        match fix {
+            SuggestedFix::AddPrimaryKeyTest { column, .. } => assert_eq!(column, "id"),
+            other => panic!("expected AddPrimaryKeyTest, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn primary_key_fix_falls_back_to_manual_without_a_candidate() {
+        let mut manifest = DbtManifestV12::default();
+        manifest.nodes.insert(
+            "model.test.orders".to_string(),
+            model_with_column("model.test.orders", "amount", None),
+        );
+
+        let fix = primary_key_fix(&manifest, "model.test.orders");
+        assert!(matches!(fix, SuggestedFix::Manual { .. }));
+    }
+
+    #[test]
+    fn suggest_collects_model_edits_from_the_check_result() {
+        let mut manifest = DbtManifestV12::default();
+        manifest.nodes.insert(
+            "model.test.orders".to_string(),
+            model_with_column("model.test.orders", "id", None),
+        );
+
+        let config = Config {
+            select: vec![Selector::MissingModelDescriptions],
+            ..Default::default()
+        }
+        .with_fix(true);
+        let result = crate::check::check_all(&manifest, &config);
+
+        let report = suggest(&manifest, &config, &result);
+        assert!(
+            report.fixes.iter().any(|fix| matches!(
+                fix,
+                SuggestedFix::ModelEdit { model_id, .. } if model_id == "model.test.orders"
+            )),
+            "expected a model edit suggestion for the missing model description fix"
+        );
+    }
+}
@@ -0,0 +1,317 @@
+//! Inline suppression directives, modeled on `baseline::Baseline`: both are a post-check
+//! filtering pass over a finished `CheckResult`, just keyed on something discovered
+//! outside of it instead of the check itself.
+//!
+//! Two directive forms are recognized, each a plain-text comment (works in `.sql`'s
+//! `--`/`/* */` or a properties file's `#`, since only the marker after the comment
+//! syntax is parsed):
+//! - `dbt-lint: disable=missing_column_descriptions,missing_primary_key` in a model's
+//!   own `.sql` file suppresses those selectors for that model alone.
+//! - `dbt-lint: disable-file=missing_model_descriptions` anywhere in a properties file
+//!   (or, for a `DuplicateDocsBlock` finding, a doc's own `.md` file) suppresses that
+//!   selector for every model/source/doc documented there.
+//!
+//! An unrecognized selector name in either directive doesn't fail the run -- it's
+//! collected into `Suppressions::warnings` for the caller to print, with a "did you
+//! mean" hint from `config::find_selector_suggestion` the same way an unknown config key
+//! gets one.
+
+use crate::check::CheckResult;
+use crate::config::{Config, Selector, find_selector_suggestion};
+use dbt_schemas::schemas::manifest::{DbtManifestV12, DbtNode};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+use strum::IntoEnumIterator;
+
+const NODE_DIRECTIVE: &str = "dbt-lint: disable=";
+const FILE_DIRECTIVE: &str = "dbt-lint: disable-file=";
+
+#[derive(Debug, Clone, Default)]
+pub struct Suppressions {
+    per_node: BTreeMap<String, BTreeSet<Selector>>,
+    per_file: BTreeMap<PathBuf, BTreeSet<Selector>>,
+    warnings: Vec<String>,
+}
+
+impl Suppressions {
+    pub fn is_empty(&self) -> bool {
+        self.per_node.is_empty() && self.per_file.is_empty()
+    }
+
+    /// Unknown-selector warnings collected while scanning for directives, meant to be
+    /// printed by the caller (see `find_selector_suggestion`).
+    pub fn warnings(&self) -> &[String] {
+        &self.warnings
+    }
+
+    /// Scan every model's `.sql` file for a `disable=` directive, and every model/source
+    /// properties file for a `disable-file=` one.
+    pub fn build(manifest: &DbtManifestV12, config: &Config) -> Self {
+        let mut suppressions = Suppressions::default();
+
+        for (node_id, node) in &manifest.nodes {
+            let DbtNode::Model(model) = node else {
+                continue;
+            };
+            let Some(contents) = read_file(config, &model.__common_attr__.original_file_path) else {
+                continue;
+            };
+            let selectors = scan_directives(&contents, NODE_DIRECTIVE, &mut suppressions.warnings);
+            if !selectors.is_empty() {
+                suppressions.per_node.insert(node_id.clone(), selectors);
+            }
+        }
+
+        let mut patch_paths: BTreeSet<PathBuf> = manifest
+            .nodes
+            .values()
+            .filter_map(|node| match node {
+                DbtNode::Model(model) => model.__common_attr__.patch_path.clone(),
+                _ => None,
+            })
+            .collect();
+        patch_paths.extend(
+            manifest
+                .sources
+                .values()
+                .filter_map(|source| source.__common_attr__.patch_path.clone()),
+        );
+        patch_paths.extend(manifest.docs.values().map(|doc| doc.original_file_path.clone()));
+
+        for patch_path in patch_paths {
+            let Some(contents) = read_file(config, &patch_path) else {
+                continue;
+            };
+            let selectors = scan_directives(&contents, FILE_DIRECTIVE, &mut suppressions.warnings);
+            if !selectors.is_empty() {
+                suppressions.per_file.insert(patch_path, selectors);
+            }
+        }
+
+        suppressions
+    }
+
+    /// Remove any failure whose selector is suppressed for its model/source/doc, same as
+    /// `Baseline::apply`. Clears a model's `column_results` failures too when
+    /// `MissingColumnDescriptions` itself is suppressed, since those don't carry a
+    /// `Selector` of their own (see `check::columns::ColumnFailure`).
+    pub fn apply(&self, manifest: &DbtManifestV12, result: &mut CheckResult) {
+        if self.is_empty() {
+            return;
+        }
+
+        for (model_id, model_result) in result.models.iter_mut() {
+            let suppressed = self.suppressed_for_model(manifest, model_id);
+            if suppressed.is_empty() {
+                continue;
+            }
+            model_result
+                .failures
+                .retain(|entry| !entry.failure.selector().is_some_and(|s| suppressed.contains(&s)));
+            if suppressed.contains(&Selector::MissingColumnDescriptions) {
+                for column_result in model_result.column_results.values_mut() {
+                    column_result.failures.clear();
+                }
+            }
+        }
+
+        for (source_id, source_result) in result.sources.iter_mut() {
+            let suppressed = self.suppressed_for_source(manifest, source_id);
+            if suppressed.is_empty() {
+                continue;
+            }
+            source_result
+                .failures
+                .retain(|entry| !entry.failure.selector().is_some_and(|s| suppressed.contains(&s)));
+        }
+
+        for (doc_id, doc_result) in result.docs.iter_mut() {
+            let suppressed = self.suppressed_for_doc(manifest, doc_id);
+            if suppressed.is_empty() {
+                continue;
+            }
+            doc_result
+                .failures
+                .retain(|failure| !failure.selector().is_some_and(|s| suppressed.contains(&s)));
+        }
+    }
+
+    fn suppressed_for_model(
+        &self,
+        manifest: &DbtManifestV12,
+        model_id: &str,
+    ) -> BTreeSet<Selector> {
+        let mut combined = self.per_node.get(model_id).cloned().unwrap_or_default();
+        if let Some(DbtNode::Model(model)) = manifest.nodes.get(model_id)
+            && let Some(patch_path) = &model.__common_attr__.patch_path
+            && let Some(file_selectors) = self.per_file.get(patch_path)
+        {
+            combined.extend(file_selectors.iter().copied());
+        }
+        combined
+    }
+
+    fn suppressed_for_source(
+        &self,
+        manifest: &DbtManifestV12,
+        source_id: &str,
+    ) -> BTreeSet<Selector> {
+        manifest
+            .sources
+            .get(source_id)
+            .and_then(|source| source.__common_attr__.patch_path.as_ref())
+            .and_then(|patch_path| self.per_file.get(patch_path))
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Unlike a model/source, a doc has no separate `patch_path` -- its own
+    /// `original_file_path` (the `.md` file defining its `{% docs %}` block) is the file
+    /// a `disable-file=` directive would live in, so that's what's looked up here.
+    fn suppressed_for_doc(&self, manifest: &DbtManifestV12, doc_id: &str) -> BTreeSet<Selector> {
+        manifest
+            .docs
+            .get(doc_id)
+            .and_then(|doc| self.per_file.get(&doc.original_file_path))
+            .cloned()
+            .unwrap_or_default()
+    }
+}
+
+/// Extract every selector name out of each `marker=...` directive line in `contents`,
+/// recording an unknown name (instead of failing the scan) as a warning.
+fn scan_directives(contents: &str, marker: &str, warnings: &mut Vec<String>) -> BTreeSet<Selector> {
+    let mut selectors = BTreeSet::new();
+    for line in contents.lines() {
+        let Some(rest) = extract_directive(line, marker) else {
+            continue;
+        };
+        for token in rest.split(',') {
+            let token = token.trim();
+            if token.is_empty() {
+                continue;
+            }
+            match Selector::iter().find(|selector| selector.as_ref() == token) {
+                Some(selector) => {
+                    selectors.insert(selector);
+                }
+                None => warnings.push(unknown_selector_warning(token)),
+            }
+        }
+    }
+    selectors
+}
+
+/// The comma-separated selector list after `marker` on `line`, with a trailing comment
+/// closer (`-->`, `*/`) trimmed off so it isn't parsed as part of the last selector name.
+fn extract_directive<'a>(line: &'a str, marker: &str) -> Option<&'a str> {
+    let after = &line[line.find(marker)? + marker.len()..];
+    Some(
+        after
+            .trim()
+            .trim_end_matches("-->")
+            .trim_end_matches("*/")
+            .trim(),
+    )
+}
+
+fn unknown_selector_warning(token: &str) -> String {
+    match find_selector_suggestion(token) {
+        Some(suggestion) => format!(
+            "dbt-lint: unknown selector `{token}` in suppression directive. \
+             Did you mean `{suggestion}`?"
+        ),
+        None => format!("dbt-lint: unknown selector `{token}` in suppression directive."),
+    }
+}
+
+/// Joins `relative` onto `config.project_dir`, same as `reporter::resolve_path`, so a
+/// node's file is read relative to the project root rather than the process's cwd.
+fn read_file(config: &Config, relative: &Path) -> Option<String> {
+    let resolved = match &config.project_dir {
+        Some(project_dir) => project_dir.join(relative),
+        None => relative.to_path_buf(),
+    };
+    std::fs::read_to_string(resolved).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::check::{ModelFailure, ModelFailureEntry, ModelResult};
+    use crate::config::Severity;
+    use dbt_schemas::schemas::manifest::ManifestModel;
+    use std::io::Write;
+
+    fn write_temp(contents: &str) -> tempfile::NamedTempFile {
+        let mut file = tempfile::NamedTempFile::new().expect("failed to create temp file");
+        file.write_all(contents.as_bytes()).expect("failed to write temp file");
+        file
+    }
+
+    #[test]
+    fn scan_directives_parses_node_level_disable() {
+        let mut warnings = Vec::new();
+        let selectors = scan_directives(
+            "select 1\n-- dbt-lint: disable=missing_primary_key,missing_model_descriptions\n",
+            NODE_DIRECTIVE,
+            &mut warnings,
+        );
+        assert!(warnings.is_empty());
+        assert!(selectors.contains(&Selector::MissingPrimaryKey));
+        assert!(selectors.contains(&Selector::MissingModelDescriptions));
+    }
+
+    #[test]
+    fn scan_directives_warns_on_unknown_selector_with_suggestion() {
+        let mut warnings = Vec::new();
+        scan_directives(
+            "# dbt-lint: disable-file=model_fanut\n",
+            FILE_DIRECTIVE,
+            &mut warnings,
+        );
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("model_fanut"));
+        assert!(warnings[0].contains("model_fanout"));
+    }
+
+    #[test]
+    fn build_and_apply_suppresses_a_model_level_failure() {
+        let model_file = write_temp("select 1\n-- dbt-lint: disable=missing_model_descriptions\n");
+
+        let mut manifest = DbtManifestV12::default();
+        let mut model = ManifestModel::default();
+        model.__common_attr__.unique_id = "model.test.orders".to_string();
+        model.__common_attr__.original_file_path = model_file.path().to_path_buf();
+        manifest
+            .nodes
+            .insert(model.__common_attr__.unique_id.clone(), DbtNode::Model(model));
+
+        let config = Config::default();
+        let suppressions = Suppressions::build(&manifest, &config);
+        assert!(suppressions.warnings().is_empty());
+
+        let mut result = CheckResult::default();
+        result.models.insert(
+            "model.test.orders".to_string(),
+            ModelResult {
+                model_id: "model.test.orders".to_string(),
+                failures: vec![ModelFailureEntry {
+                    failure: ModelFailure::DescriptionMissing,
+                    severity: Severity::Error,
+                    blame: Vec::new(),
+                    owners: Vec::new(),
+                }],
+                column_results: BTreeMap::new(),
+                changes: None,
+            },
+        );
+
+        suppressions.apply(&manifest, &mut result);
+
+        assert!(
+            result.models.get("model.test.orders").unwrap().failures.is_empty(),
+            "the suppressed selector's failure should be removed"
+        );
+    }
+}
@@ -0,0 +1,283 @@
+//! Watch mode: re-run checks incrementally as model/property files change, instead of
+//! reparsing and re-checking the whole project on every edit.
+//!
+//! [`WatchSession`] keeps the `DbtGraph`/`Reachability`/`CheckResult` produced by an
+//! initial full pass. When a watched file changes, [`WatchSession::recheck`] re-runs
+//! `check_model` for just the model that owns the file plus its graph-downstream
+//! dependents via [`recheck_models`] -- column inheritance and fanout/dead-model
+//! results can propagate to a model's dependents, but an edit can't affect an unrelated
+//! model, so there's no reason to recheck the whole project. [`watch`] wires this up to
+//! a filesystem watcher with a debounce so a burst of saves triggers one recheck.
+
+use crate::change_descriptors::ModelChanges;
+use crate::check::{CheckResult, ModelResult, check_all, detect_circular_dependencies, recheck_models};
+use crate::config::Config;
+use crate::graph::{DbtGraph, Reachability};
+use crate::writeback::{self, WriteBackError, fs::RealFs};
+use dbt_common::cancellation::CancellationToken;
+use dbt_schemas::schemas::manifest::{DbtManifestV12, DbtNode};
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{RecvTimeoutError, channel};
+use std::time::Duration;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum WatchError {
+    #[error("failed to watch {}: {source}", path.display())]
+    Setup {
+        path: PathBuf,
+        #[source]
+        source: notify::Error,
+    },
+}
+
+/// How long to wait after the last filesystem event before re-checking, so a burst of
+/// saves (e.g. a formatter rewriting several files at once) triggers one recheck
+/// instead of one per file.
+const DEBOUNCE: Duration = Duration::from_millis(100);
+
+/// A long-running check session that re-checks only the models affected by a file
+/// change instead of the whole project.
+pub struct WatchSession {
+    project_dir: PathBuf,
+    manifest: DbtManifestV12,
+    graph: DbtGraph,
+    reachability: Reachability,
+    cycles: BTreeMap<String, Vec<String>>,
+    config: Config,
+    accumulated_changes: BTreeMap<String, ModelChanges>,
+    result: CheckResult,
+    file_to_model: BTreeMap<PathBuf, String>,
+}
+
+impl WatchSession {
+    /// Run a full check pass over `manifest` and prepare to watch `project_dir` for
+    /// subsequent changes.
+    pub fn new(project_dir: &Path, manifest: DbtManifestV12, config: Config) -> Self {
+        let graph = DbtGraph::from(&manifest);
+        let reachability = Reachability::build(&graph);
+        let cycles = detect_circular_dependencies(&manifest);
+        let result = check_all(&manifest, &config);
+        let accumulated_changes = result.model_changes.clone();
+        let file_to_model = index_model_files(project_dir, &manifest);
+
+        WatchSession {
+            project_dir: project_dir.to_path_buf(),
+            manifest,
+            graph,
+            reachability,
+            cycles,
+            config,
+            accumulated_changes,
+            result,
+            file_to_model,
+        }
+    }
+
+    /// Whether this session is configured to write fixes back to disk (`--fix`), so
+    /// [`watch`] knows whether it's worth calling [`WatchSession::apply_fix`] at all.
+    pub fn fix_enabled(&self) -> bool {
+        self.config.fix
+    }
+
+    /// Write back the accumulated changes for `model_ids` (normally the models just
+    /// returned by [`WatchSession::recheck`]), the same way a one-shot `--fix` run
+    /// would, so `--watch --fix` keeps property files in sync as edits land instead of
+    /// only reporting drift. Returns per-model lists of the columns/fields touched.
+    pub fn apply_fix(&self, model_ids: &BTreeSet<String>) -> Result<Vec<(String, Vec<String>)>, WriteBackError> {
+        let subset: BTreeMap<String, ModelChanges> = model_ids
+            .iter()
+            .filter_map(|id| self.accumulated_changes.get(id).map(|changes| (id.clone(), changes.clone())))
+            .collect();
+
+        if subset.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        writeback::apply_model_changes(&RealFs, &self.project_dir, &subset, &self.config, &self.graph)
+    }
+
+    /// The full, current result set, e.g. for an initial render before any files change.
+    pub fn result(&self) -> &CheckResult {
+        &self.result
+    }
+
+    /// The model(s) a set of changed paths belong to, plus every graph-downstream
+    /// dependent. Paths that don't belong to a known model (e.g. a `.gitignore` edit
+    /// under a watched directory) are silently ignored.
+    fn affected_models(&self, changed_paths: &BTreeSet<PathBuf>) -> BTreeSet<String> {
+        let mut affected: BTreeSet<String> = changed_paths
+            .iter()
+            .filter_map(|path| self.file_to_model.get(path).cloned())
+            .collect();
+
+        let mut frontier: Vec<String> = affected.iter().cloned().collect();
+        while let Some(model_id) = frontier.pop() {
+            for child in self.graph.children(&model_id) {
+                if affected.insert(child.clone()) {
+                    frontier.push(child);
+                }
+            }
+        }
+
+        affected
+    }
+
+    /// Re-check the models affected by `changed_paths` and splice the results into the
+    /// cached `CheckResult`, returning just the `ModelResult`s that changed so the
+    /// caller can re-emit a diff instead of the whole project. Prints a one-line
+    /// summary of everything re-evaluated this cycle (the changed models plus their
+    /// graph-downstream dependents), since most of them will be unaffected reruns.
+    pub fn recheck(&mut self, changed_paths: &BTreeSet<PathBuf>) -> Vec<&ModelResult> {
+        let affected = self.affected_models(changed_paths);
+        if affected.is_empty() {
+            return Vec::new();
+        }
+
+        println!("re-checking {} affected model(s): {}", affected.len(), affected.iter().cloned().collect::<Vec<_>>().join(", "));
+
+        let before: BTreeMap<String, String> = affected
+            .iter()
+            .filter_map(|id| self.result.models.get(id).map(|r| (id.clone(), r.to_string())))
+            .collect();
+
+        recheck_models(
+            &self.manifest,
+            &self.graph,
+            &self.reachability,
+            &affected,
+            &mut self.accumulated_changes,
+            &self.cycles,
+            &self.config,
+            &mut self.result,
+        );
+
+        let changed: Vec<&ModelResult> = affected
+            .into_iter()
+            .filter_map(|id| self.result.models.get(&id))
+            .filter(|model_result| before.get(model_result.model_id()) != Some(&model_result.to_string()))
+            .collect();
+        println!("{} of those changed: {}", changed.len(), changed.iter().map(|r| r.model_id()).collect::<Vec<_>>().join(", "));
+
+        changed
+    }
+}
+
+/// Map every model's SQL/properties file to its unique id, so a filesystem event can be
+/// translated back into "which model does this belong to".
+fn index_model_files(project_dir: &Path, manifest: &DbtManifestV12) -> BTreeMap<PathBuf, String> {
+    manifest
+        .nodes
+        .iter()
+        .filter_map(|(id, node)| match node {
+            DbtNode::Model(model) => Some((id, model)),
+            _ => None,
+        })
+        .flat_map(|(id, model)| {
+            let mut paths = vec![project_dir.join(&model.__common_attr__.original_file_path)];
+            if let Some(patch_path) = &model.__common_attr__.patch_path {
+                paths.push(project_dir.join(patch_path));
+            }
+            paths.into_iter().map(move |path| (path, id.clone()))
+        })
+        .collect()
+}
+
+fn changed_paths_from(event: notify::Result<Event>) -> BTreeSet<PathBuf> {
+    match event {
+        Ok(event) if matches!(event.kind, EventKind::Modify(_) | EventKind::Create(_)) => {
+            event.paths.into_iter().collect()
+        }
+        _ => BTreeSet::new(),
+    }
+}
+
+/// How often to wake up from an idle wait to poll `cancellation`, so a cancelled watch
+/// exits promptly even if no filesystem events are arriving.
+const CANCELLATION_POLL: Duration = Duration::from_millis(200);
+
+/// Watch `project_dir` and call `on_change` with the `ModelResult` for every model
+/// invalidated by a file change, re-checking incrementally rather than from scratch. If
+/// the session was built with `--fix`, each affected model's accumulated changes are
+/// also written back to disk once `on_change` has been called for each of them. Runs until the underlying
+/// filesystem watcher disconnects or `cancellation` is cancelled.
+pub fn watch(
+    project_dir: &Path,
+    manifest: DbtManifestV12,
+    config: Config,
+    cancellation: &CancellationToken,
+    mut on_change: impl FnMut(&ModelResult),
+) -> Result<(), WatchError> {
+    let mut session = WatchSession::new(project_dir, manifest, config);
+
+    let (tx, rx) = channel::<notify::Result<Event>>();
+    let mut watcher: RecommendedWatcher =
+        notify::recommended_watcher(tx).map_err(|source| WatchError::Setup {
+            path: project_dir.to_path_buf(),
+            source,
+        })?;
+    watcher
+        .watch(project_dir, RecursiveMode::Recursive)
+        .map_err(|source| WatchError::Setup {
+            path: project_dir.to_path_buf(),
+            source,
+        })?;
+
+    'outer: loop {
+        if cancellation.is_cancelled() {
+            return Ok(());
+        }
+
+        let mut changed_paths = BTreeSet::new();
+        loop {
+            match rx.recv_timeout(CANCELLATION_POLL) {
+                Ok(event) => {
+                    changed_paths.extend(changed_paths_from(event));
+                    break;
+                }
+                Err(RecvTimeoutError::Timeout) => {
+                    if cancellation.is_cancelled() {
+                        return Ok(());
+                    }
+                    continue;
+                }
+                Err(RecvTimeoutError::Disconnected) => break 'outer,
+            }
+        }
+
+        // Drain anything else arriving within the debounce window so a burst of
+        // saves (e.g. a formatter touching several files) becomes one recheck.
+        loop {
+            match rx.recv_timeout(DEBOUNCE) {
+                Ok(event) => changed_paths.extend(changed_paths_from(event)),
+                Err(RecvTimeoutError::Timeout) => break,
+                Err(RecvTimeoutError::Disconnected) => break 'outer,
+            }
+        }
+
+        if changed_paths.is_empty() {
+            continue;
+        }
+
+        let changed = session.recheck(&changed_paths);
+        let changed_ids: BTreeSet<String> = changed.iter().map(|r| r.model_id().to_string()).collect();
+        for model_result in changed {
+            on_change(model_result);
+        }
+
+        if session.fix_enabled() {
+            match session.apply_fix(&changed_ids) {
+                Ok(applied) => {
+                    for (model_id, fields) in applied.iter().filter(|(_, fields)| !fields.is_empty()) {
+                        println!("applied writeback for {model_id}: {}", fields.join(", "));
+                    }
+                }
+                Err(err) => eprintln!("watch: failed to apply writeback: {err}"),
+            }
+        }
+    }
+
+    Ok(())
+}
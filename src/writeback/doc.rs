@@ -119,6 +119,64 @@ impl ModelsRoot {
     }
 }
 
+/// Applies a `check::docs::DocChange::ConsolidateDocsBlock { keep, remove }`: deletes each
+/// `{% docs name %}...{% enddocs %}` block in `source` whose name is in `remove`, and
+/// rewrites every `{{ doc('name') }}` / `{{ doc("name") }}` reference for those names to
+/// `keep_name` instead. `keep_name`/`remove_names` are docs block *names* (the identifier
+/// used in `{% docs ... %}`/`{{ doc(...) }}`), not `unique_id`s -- resolve those from the
+/// manifest before calling this, the same way the caller already has to for any other
+/// `{{ doc(...) }}` rewrite.
+///
+/// Plain text scanning, no Jinja parser -- matching `splice`'s approach of editing around
+/// `{{ doc(...) }}`/`{{ ref(...) }}` text without understanding it. Returns `source`
+/// unchanged if none of `remove_names` appear in it.
+pub fn apply_consolidate_docs_block(
+    source: &str,
+    keep_name: &str,
+    remove_names: &[String],
+) -> String {
+    let without_blocks = remove_doc_blocks(source, remove_names);
+    rewrite_doc_references(&without_blocks, keep_name, remove_names)
+}
+
+fn remove_doc_blocks(source: &str, remove_names: &[String]) -> String {
+    let mut out = String::with_capacity(source.len());
+    let mut rest = source;
+    while let Some(start) = rest.find("{% docs ") {
+        let (before, from_marker) = rest.split_at(start);
+        let Some(name_end) = from_marker["{% docs ".len()..].find('%') else {
+            out.push_str(rest);
+            return out;
+        };
+        let name = from_marker["{% docs ".len().."{% docs ".len() + name_end].trim();
+        let Some(enddocs_rel) = from_marker.find("{% enddocs %}") else {
+            out.push_str(rest);
+            return out;
+        };
+        let block_end = enddocs_rel + "{% enddocs %}".len();
+
+        out.push_str(before);
+        if !remove_names.iter().any(|removed| removed == name) {
+            out.push_str(&from_marker[..block_end]);
+        }
+        rest = &from_marker[block_end..];
+    }
+    out.push_str(rest);
+    out
+}
+
+fn rewrite_doc_references(source: &str, keep_name: &str, remove_names: &[String]) -> String {
+    let mut result = source.to_string();
+    for removed in remove_names {
+        for quote in ['\'', '"'] {
+            let old = format!("doc({quote}{removed}{quote})");
+            let new = format!("doc({quote}{keep_name}{quote})");
+            result = result.replace(&old, &new);
+        }
+    }
+    result
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -273,4 +331,28 @@ mod tests {
         let m2 = root.find_model_mut("m_x").unwrap();
         assert_eq!(m2.description.as_deref(), Some("new desc"));
     }
+
+    #[test]
+    fn apply_consolidate_docs_block_removes_block_and_rewrites_references() {
+        let source = "{% docs orders_id %}\nThe unique key.\n{% enddocs %}\n\n\
+                       {% docs keep_id %}\nThe unique key.\n{% enddocs %}\n\n\
+                       See {{ doc('orders_id') }} for details.\n";
+
+        let result = apply_consolidate_docs_block(source, "keep_id", &["orders_id".to_string()]);
+
+        assert!(!result.contains("{% docs orders_id %}"), "removed docs block stays gone");
+        assert!(result.contains("{% docs keep_id %}"), "surviving docs block is untouched");
+        assert!(
+            result.contains("{{ doc('keep_id') }}"),
+            "reference rewritten to the survivor"
+        );
+        assert!(!result.contains("orders_id"), "no trace of the removed name remains");
+    }
+
+    #[test]
+    fn apply_consolidate_docs_block_is_a_no_op_when_nothing_to_remove_is_present() {
+        let source = "{% docs keep_id %}\nThe unique key.\n{% enddocs %}\n";
+        let result = apply_consolidate_docs_block(source, "keep_id", &["missing".to_string()]);
+        assert_eq!(result, source);
+    }
 }
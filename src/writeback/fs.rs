@@ -0,0 +1,284 @@
+//! A small `Fs` abstraction so `rust::apply_with_rust` and its helpers don't call
+//! `std::fs` directly, following the approach in Zed's `project/src/fs.rs`. This lets
+//! tests exercise the writeback logic against an in-memory [`FakeFs`] instead of a real
+//! `tempdir`, and lets a caller preview what writeback would do via [`DryRunFs`] without
+//! touching disk.
+
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::io::{self, Write as _};
+use std::path::{Path, PathBuf};
+
+/// The filesystem operations `writeback::rust` needs. Implemented by [`RealFs`] for
+/// production use, [`FakeFs`] for tests, and [`DryRunFs`] for previewing changes.
+///
+/// `write` is expected to be crash-safe: a process kill mid-write must never leave the
+/// target file truncated or partially written. [`RealFs`] achieves this with a
+/// temp-file-and-rename; callers don't need to do anything special to get that guarantee.
+pub trait Fs {
+    fn read_to_string(&self, path: &Path) -> io::Result<String>;
+    fn write(&self, path: &Path, contents: &str) -> io::Result<()>;
+    fn rename(&self, from: &Path, to: &Path) -> io::Result<()>;
+    fn remove_file(&self, path: &Path) -> io::Result<()>;
+    fn create_dir_all(&self, path: &Path) -> io::Result<()>;
+    fn exists(&self, path: &Path) -> bool;
+}
+
+/// Delegates straight to `std::fs`.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct RealFs;
+
+impl Fs for RealFs {
+    fn read_to_string(&self, path: &Path) -> io::Result<String> {
+        std::fs::read_to_string(path)
+    }
+
+    /// Writes via a sibling temp file (`<name>.tmp-<pid>`) that's `fsync`'d and then
+    /// renamed over `path`, so a crash mid-write can never leave `path` truncated --
+    /// same pattern as wgconfd's `fileutil.rs`. The temp file is cleaned up on any error.
+    fn write(&self, path: &Path, contents: &str) -> io::Result<()> {
+        let temp_path = sibling_temp_path(path);
+        let result = (|| {
+            let mut file = std::fs::File::create(&temp_path)?;
+            file.write_all(contents.as_bytes())?;
+            file.sync_all()?;
+            std::fs::rename(&temp_path, path)
+        })();
+        if result.is_err() {
+            let _ = std::fs::remove_file(&temp_path);
+        }
+        result
+    }
+
+    fn rename(&self, from: &Path, to: &Path) -> io::Result<()> {
+        std::fs::rename(from, to)
+    }
+
+    fn remove_file(&self, path: &Path) -> io::Result<()> {
+        std::fs::remove_file(path)
+    }
+
+    fn create_dir_all(&self, path: &Path) -> io::Result<()> {
+        std::fs::create_dir_all(path)
+    }
+
+    fn exists(&self, path: &Path) -> bool {
+        path.exists()
+    }
+}
+
+/// Builds the `<name>.tmp-<pid>` path `RealFs::write` stages its contents under before
+/// renaming over `path`. Keyed on the process id (rather than e.g. a random suffix) so a
+/// crashed run's leftover temp file is easy to recognize and doesn't collide with a
+/// concurrently-running process writing the same file.
+fn sibling_temp_path(path: &Path) -> PathBuf {
+    let file_name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    path.with_file_name(format!("{file_name}.tmp-{}", std::process::id()))
+}
+
+/// An in-memory filesystem for tests: files are just entries in a `BTreeMap`, and
+/// "directories" are implicit (`create_dir_all` is a no-op, and `exists` reports only
+/// paths that were written as files, never a directory). `RefCell` gives interior mutability so `Fs`'s methods can all take
+/// `&self`, matching `RealFs`'s shape.
+#[derive(Debug, Default)]
+pub struct FakeFs {
+    files: RefCell<BTreeMap<PathBuf, String>>,
+}
+
+impl FakeFs {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Seed a file before running the code under test, same as writing it to a real
+    /// `tempdir` would.
+    pub fn with_file(self, path: impl Into<PathBuf>, contents: impl Into<String>) -> Self {
+        self.files.borrow_mut().insert(path.into(), contents.into());
+        self
+    }
+
+    /// Read back a file's final contents for assertions.
+    pub fn read(&self, path: &Path) -> Option<String> {
+        self.files.borrow().get(path).cloned()
+    }
+}
+
+impl Fs for FakeFs {
+    fn read_to_string(&self, path: &Path) -> io::Result<String> {
+        self.files
+            .borrow()
+            .get(path)
+            .cloned()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, path.display().to_string()))
+    }
+
+    fn write(&self, path: &Path, contents: &str) -> io::Result<()> {
+        self.files
+            .borrow_mut()
+            .insert(path.to_path_buf(), contents.to_string());
+        Ok(())
+    }
+
+    fn rename(&self, from: &Path, to: &Path) -> io::Result<()> {
+        let contents = self.files.borrow_mut().remove(from).ok_or_else(|| {
+            io::Error::new(io::ErrorKind::NotFound, from.display().to_string())
+        })?;
+        self.files.borrow_mut().insert(to.to_path_buf(), contents);
+        Ok(())
+    }
+
+    fn remove_file(&self, path: &Path) -> io::Result<()> {
+        self.files
+            .borrow_mut()
+            .remove(path)
+            .map(|_| ())
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, path.display().to_string()))
+    }
+
+    fn create_dir_all(&self, _path: &Path) -> io::Result<()> {
+        // Directories are implicit in `FakeFs` -- writing a file under a path is enough.
+        Ok(())
+    }
+
+    fn exists(&self, path: &Path) -> bool {
+        self.files.borrow().contains_key(path)
+    }
+}
+
+/// One filesystem mutation `DryRunFs` would have made, recorded instead of applied.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DryRunOp {
+    Write { path: PathBuf, contents: String },
+    Rename { from: PathBuf, to: PathBuf },
+    Remove { path: PathBuf },
+    CreateDirAll { path: PathBuf },
+}
+
+/// Wraps another `Fs` and records every mutating call instead of performing it, so a
+/// caller can print a diff preview of what writeback *would* do. Reads (`read_to_string`,
+/// `exists`) pass straight through to the wrapped filesystem, since previewing a change
+/// still needs to see the real file it would be changing.
+pub struct DryRunFs<'a> {
+    inner: &'a dyn Fs,
+    ops: RefCell<Vec<DryRunOp>>,
+}
+
+impl<'a> DryRunFs<'a> {
+    pub fn new(inner: &'a dyn Fs) -> Self {
+        DryRunFs {
+            inner,
+            ops: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// The operations that would have been performed, in the order they were recorded.
+    pub fn into_ops(self) -> Vec<DryRunOp> {
+        self.ops.into_inner()
+    }
+}
+
+impl Fs for DryRunFs<'_> {
+    fn read_to_string(&self, path: &Path) -> io::Result<String> {
+        self.inner.read_to_string(path)
+    }
+
+    fn write(&self, path: &Path, contents: &str) -> io::Result<()> {
+        self.ops.borrow_mut().push(DryRunOp::Write {
+            path: path.to_path_buf(),
+            contents: contents.to_string(),
+        });
+        Ok(())
+    }
+
+    fn rename(&self, from: &Path, to: &Path) -> io::Result<()> {
+        self.ops.borrow_mut().push(DryRunOp::Rename {
+            from: from.to_path_buf(),
+            to: to.to_path_buf(),
+        });
+        Ok(())
+    }
+
+    fn remove_file(&self, path: &Path) -> io::Result<()> {
+        self.ops.borrow_mut().push(DryRunOp::Remove {
+            path: path.to_path_buf(),
+        });
+        Ok(())
+    }
+
+    fn create_dir_all(&self, path: &Path) -> io::Result<()> {
+        self.ops.borrow_mut().push(DryRunOp::CreateDirAll {
+            path: path.to_path_buf(),
+        });
+        Ok(())
+    }
+
+    fn exists(&self, path: &Path) -> bool {
+        self.inner.exists(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fake_fs_round_trips_writes() {
+        let fs = FakeFs::new().with_file("a.yml", "one: 1");
+        assert_eq!(fs.read_to_string(Path::new("a.yml")).unwrap(), "one: 1");
+
+        fs.write(Path::new("a.yml"), "one: 2").unwrap();
+        assert_eq!(fs.read(Path::new("a.yml")).as_deref(), Some("one: 2"));
+
+        fs.rename(Path::new("a.yml"), Path::new("b.yml")).unwrap();
+        assert!(!fs.exists(Path::new("a.yml")));
+        assert_eq!(fs.read(Path::new("b.yml")).as_deref(), Some("one: 2"));
+
+        fs.remove_file(Path::new("b.yml")).unwrap();
+        assert!(!fs.exists(Path::new("b.yml")));
+    }
+
+    #[test]
+    fn real_fs_write_is_atomic_and_leaves_no_temp_file_on_success() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("models.yml");
+        std::fs::write(&path, "one: 1").unwrap();
+
+        RealFs.write(&path, "one: 2").unwrap();
+
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "one: 2");
+        let temp_path = sibling_temp_path(&path);
+        assert!(
+            !temp_path.exists(),
+            "temp file should be renamed away, not left behind"
+        );
+    }
+
+    #[test]
+    fn dry_run_fs_records_without_mutating_inner() {
+        let inner = FakeFs::new().with_file("a.yml", "one: 1");
+        let dry_run = DryRunFs::new(&inner);
+
+        dry_run.write(Path::new("a.yml"), "one: 2").unwrap();
+        dry_run
+            .rename(Path::new("a.yml"), Path::new("b.yml"))
+            .unwrap();
+
+        assert_eq!(inner.read(Path::new("a.yml")).as_deref(), Some("one: 1"));
+        assert_eq!(
+            dry_run.into_ops(),
+            vec![
+                DryRunOp::Write {
+                    path: PathBuf::from("a.yml"),
+                    contents: "one: 2".to_string(),
+                },
+                DryRunOp::Rename {
+                    from: PathBuf::from("a.yml"),
+                    to: PathBuf::from("b.yml"),
+                },
+            ]
+        );
+    }
+}
@@ -1,11 +1,21 @@
-use crate::check::ModelChanges;
-use std::{collections::BTreeMap, path::Path};
+use crate::check::{DocChange, DocResult, ModelChanges};
+use crate::graph::DbtGraph;
+use dbt_schemas::schemas::manifest::DbtManifestV12;
+use fs::Fs;
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
 use thiserror::Error;
 
 pub mod changes;
+pub mod doc;
+pub mod fs;
+pub mod plan;
 pub mod properties;
 pub mod python;
 pub mod rust;
+pub mod splice;
 
 #[derive(Debug, Error)]
 pub enum WriteBackError {
@@ -30,18 +40,258 @@ pub enum WriteBackError {
     },
     #[error("model `{model_id}` not found in docs")]
     ModelMissing { model_id: String },
+    #[error(
+        "refusing to move `{model_id}` over existing destination for model `{model_name}` \
+         (pass an overwrite option to allow this)"
+    )]
+    DestinationConflict { model_id: String, model_name: String },
+    #[error("python worker response out of order: expected request {expected}, got {actual}")]
+    PythonWorkerDesync { expected: u64, actual: u64 },
     #[error("yaml error: {0}")]
     Yaml(#[from] dbt_serde_yaml::Error),
+    #[error("circular lineage between {}", cycle.join(" → "))]
+    CircularLineage { cycle: Vec<String> },
 }
 
-/// Dispatch based on configured writeback method.
+/// Bails with [`WriteBackError::CircularLineage`] if any model in `changes` sits on a
+/// lineage cycle, rather than letting parents-first ordering proceed on a graph where
+/// "parents-first" isn't well-defined. Checked once up front so every writeback method
+/// (and their dry-run counterparts) fail the same way instead of silently falling back
+/// to an arbitrary order.
+fn reject_cyclic_lineage(
+    graph: &DbtGraph,
+    changes: &BTreeMap<String, ModelChanges>,
+) -> Result<(), WriteBackError> {
+    for cycle in graph.find_cycles() {
+        if cycle.iter().any(|id| changes.contains_key(id)) {
+            return Err(WriteBackError::CircularLineage { cycle });
+        }
+    }
+    Ok(())
+}
+
+/// Dispatch based on configured writeback method. `fs` is consulted by the `Rust` and
+/// `Diff` methods -- `Python` shells out to a helper script that does its own file I/O -- but it
+/// takes the same `&dyn Fs` either way so a caller previewing changes (e.g. via
+/// `fs::DryRunFs`) doesn't need to know which method is configured.
+///
+/// `graph` is used to process models parents-first, so that when transitive description
+/// inheritance is enabled, a column merge sees its parent's freshly written description
+/// rather than depending on write order being incidental. If any model in `changes` sits
+/// on a lineage cycle, this returns [`WriteBackError::CircularLineage`] instead of
+/// writing anything, since "parents-first" has no answer on a cycle.
 pub fn apply_model_changes(
+    fs: &dyn Fs,
     project_root: &Path,
     changes: &BTreeMap<String, ModelChanges>,
     config: &crate::config::Config,
+    graph: &DbtGraph,
 ) -> Result<Vec<(String, Vec<String>)>, WriteBackError> {
+    reject_cyclic_lineage(graph, changes)?;
+
     match config.writeback {
         crate::config::WritebackMethod::Python => python::apply_with_python(project_root, changes),
-        crate::config::WritebackMethod::Rust => rust::apply_with_rust(project_root, changes),
+        crate::config::WritebackMethod::Rust => rust::apply_with_rust(
+            fs,
+            project_root,
+            changes,
+            rust::MoveOptions {
+                overwrite: config.overwrite_on_move,
+            },
+            &config.properties_format,
+            graph,
+        ),
+        crate::config::WritebackMethod::Diff => {
+            print_diff(fs, project_root, changes, config, graph)
+        }
+    }
+}
+
+/// `WritebackMethod::Diff`: stages the exact same changes `Rust` would write (via
+/// `plan::plan_model_changes`), prints each touched file's unified diff instead of
+/// writing it, and returns the same per-model/column results `Rust` would -- so a caller
+/// can't tell the two methods apart except that the project is left untouched.
+fn print_diff(
+    fs: &dyn Fs,
+    project_root: &Path,
+    changes: &BTreeMap<String, ModelChanges>,
+    config: &crate::config::Config,
+    graph: &DbtGraph,
+) -> Result<Vec<(String, Vec<String>)>, WriteBackError> {
+    let plan = plan::plan_model_changes(fs, project_root, changes, config, graph)?;
+
+    let mut results = Vec::new();
+    for file in &plan.files {
+        if !file.diff.is_empty() {
+            print!("{}", file.diff);
+        }
+        results.extend(file.models.iter().cloned());
+    }
+    Ok(results)
+}
+
+/// Applies every `DocChange::ConsolidateDocsBlock` in `docs` by editing the file(s) that
+/// define the removed `{% docs %}` blocks (resolved via `manifest.docs`) through
+/// `doc::apply_consolidate_docs_block`. Returns the name of each docs block kept.
+///
+/// Scoped deliberately to just those defining files: a `{{ doc(...) }}` reference can
+/// live in any `.sql`/`.yml` in the project, and there's no existing mechanism anywhere
+/// in this crate for rewriting every file that might reference a changed node (unlike a
+/// model's patch path, which is always singular and known from the manifest) -- so a
+/// reference outside the defining file or files is left alone.
+pub fn apply_doc_changes(
+    fs: &dyn Fs,
+    project_root: &Path,
+    manifest: &DbtManifestV12,
+    docs: &BTreeMap<String, DocResult>,
+) -> Result<Vec<String>, WriteBackError> {
+    let mut updated = Vec::new();
+    for doc_result in docs.values() {
+        for change in doc_result.changes() {
+            let DocChange::ConsolidateDocsBlock { keep, remove } = change;
+            let Some(keep_doc) = manifest.docs.get(keep) else {
+                continue;
+            };
+            let keep_name = keep_doc.name.clone();
+
+            let mut remove_names_by_file: BTreeMap<PathBuf, Vec<String>> = BTreeMap::new();
+            for remove_id in remove {
+                let Some(doc) = manifest.docs.get(remove_id) else {
+                    continue;
+                };
+                remove_names_by_file
+                    .entry(doc.original_file_path.clone())
+                    .or_default()
+                    .push(doc.name.clone());
+            }
+
+            for (relative_path, remove_names) in remove_names_by_file {
+                let file_path = project_root.join(&relative_path);
+                let contents = fs.read_to_string(&file_path)?;
+                let updated_contents =
+                    doc::apply_consolidate_docs_block(&contents, &keep_name, &remove_names);
+                fs.write(&file_path, &updated_contents)?;
+            }
+
+            updated.push(keep_name);
+        }
+    }
+    Ok(updated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::writeback::fs::FakeFs;
+    use std::collections::HashMap;
+
+    fn graph_from_edges(edges: &[(&str, &str)]) -> DbtGraph {
+        let mut graph = petgraph::graph::Graph::<String, ()>::new();
+        let mut index: HashMap<String, petgraph::graph::NodeIndex> = HashMap::new();
+
+        for (parent, child) in edges {
+            let p = *index
+                .entry(parent.to_string())
+                .or_insert_with(|| graph.add_node(parent.to_string()));
+            let c = *index
+                .entry(child.to_string())
+                .or_insert_with(|| graph.add_node(child.to_string()));
+            graph.add_edge(p, c, ());
+        }
+
+        DbtGraph { graph, index }
+    }
+
+    #[test]
+    fn apply_model_changes_refuses_to_write_when_a_touched_model_is_on_a_cycle() {
+        let graph = graph_from_edges(&[
+            ("model.jaffle_shop.a", "model.jaffle_shop.b"),
+            ("model.jaffle_shop.b", "model.jaffle_shop.a"),
+        ]);
+
+        let mut changes = BTreeMap::new();
+        changes.insert(
+            "model.jaffle_shop.a".to_string(),
+            ModelChanges {
+                model_id: "model.jaffle_shop.a".to_string(),
+                patch_path: Some(Path::new("models.yml").to_path_buf()),
+                ..Default::default()
+            },
+        );
+
+        let fs = FakeFs::new();
+        let err = apply_model_changes(&fs, Path::new("/project"), &changes, &Config::default(), &graph)
+            .unwrap_err();
+
+        assert!(matches!(err, WriteBackError::CircularLineage { .. }));
+    }
+
+    #[test]
+    fn apply_model_changes_ignores_a_cycle_that_touches_none_of_the_changes() {
+        let graph = graph_from_edges(&[
+            ("model.jaffle_shop.a", "model.jaffle_shop.b"),
+            ("model.jaffle_shop.b", "model.jaffle_shop.a"),
+        ]);
+
+        let changes = BTreeMap::new();
+        let fs = FakeFs::new();
+        let result =
+            apply_model_changes(&fs, Path::new("/project"), &changes, &Config::default(), &graph);
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn diff_writeback_method_leaves_the_project_untouched() {
+        use crate::change_descriptors::ModelChange;
+        use crate::config::WritebackMethod;
+        use crate::writeback::properties::{ColumnProperty, ModelProperty};
+
+        let project_root = Path::new("/project");
+        let yaml = "models:\n  - name: stg_orders\n    columns:\n      - name: id\n        \
+                    description: old\n";
+        let fs = FakeFs::new().with_file(project_root.join("models.yml"), yaml);
+
+        let mut mc = ModelChanges {
+            model_id: "model.jaffle_shop.stg_orders".to_string(),
+            patch_path: Some(Path::new("models.yml").to_path_buf()),
+            ..Default::default()
+        };
+        mc.changes.push(ModelChange::ChangePropertiesFile {
+            model_id: mc.model_id.clone(),
+            model_name: "stg_orders".to_string(),
+            patch_path: mc.patch_path.clone(),
+            property: Some(ModelProperty {
+                name: Some("stg_orders".to_string()),
+                description: None,
+                columns: vec![ColumnProperty {
+                    name: "id".to_string(),
+                    description: Some("new".to_string()),
+                    ..Default::default()
+                }],
+                extras: BTreeMap::new(),
+            }),
+        });
+        let mut changes = BTreeMap::new();
+        changes.insert(mc.model_id.clone(), mc);
+
+        let graph = DbtGraph {
+            graph: petgraph::graph::Graph::new(),
+            index: std::collections::HashMap::new(),
+        };
+        let config = Config {
+            writeback: WritebackMethod::Diff,
+            ..Default::default()
+        };
+
+        let results = apply_model_changes(&fs, project_root, &changes, &config, &graph)
+            .expect("diff should succeed");
+
+        assert!(!results.is_empty(), "diff mode should still report what it would change");
+        assert!(
+            fs.read(&project_root.join("models.yml")).unwrap().contains("old"),
+            "diff mode must not write the staged content to disk"
+        );
     }
 }
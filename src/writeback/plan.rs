@@ -0,0 +1,367 @@
+//! Dry-run planning: computes what `apply_model_changes` would do, without writing
+//! anything to disk, so a user can review a diff-able changeset before committing to it.
+
+use super::WriteBackError;
+use super::fs::{DryRunFs, DryRunOp, Fs};
+use super::rust::{self, MoveOptions};
+use crate::check::ModelChanges;
+use crate::config::{Config, WritebackMethod};
+use crate::graph::DbtGraph;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// One file writeback would touch: the model/column operations that landed in it, plus
+/// a unified diff of the before/after YAML.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlannedFile {
+    pub path: PathBuf,
+    pub models: Vec<(String, Vec<String>)>,
+    pub diff: String,
+}
+
+/// The outcome of [`plan_model_changes`]: every file writeback would touch, in the same
+/// order `apply_model_changes` would process them in.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct WritebackPlan {
+    pub files: Vec<PlannedFile>,
+}
+
+/// Computes what `apply_model_changes` would do without writing anything to disk --
+/// the engine behind the preview shown when `--fix` isn't passed and behind the `Diff`
+/// writeback method. For [`WritebackMethod::Rust`], this runs the exact same staging
+/// pass (`rust::plan_rust_writeback_grouped` against a [`DryRunFs`]) that
+/// `apply_with_rust` commits from, so the preview is guaranteed to match what a real run
+/// would write. The python backend computes its target documents inside the helper
+/// process rather than in Rust, so there's no before/after YAML to diff on this side;
+/// its plan is instead the JSON request payload `python::plan_with_python` would have
+/// sent, which is the most faithful preview available without actually invoking it.
+pub fn plan_model_changes(
+    fs: &dyn Fs,
+    project_root: &Path,
+    changes: &BTreeMap<String, ModelChanges>,
+    config: &Config,
+    graph: &DbtGraph,
+) -> Result<WritebackPlan, WriteBackError> {
+    super::reject_cyclic_lineage(graph, changes)?;
+
+    match config.writeback {
+        // `Diff` stages changes the exact same way `Rust` does -- only
+        // `writeback::print_diff` (rather than `apply_with_rust`) acts on the result
+        // differently, by printing the diffs instead of committing them.
+        WritebackMethod::Rust | WritebackMethod::Diff => plan_with_rust(
+            fs,
+            project_root,
+            changes,
+            MoveOptions {
+                overwrite: config.overwrite_on_move,
+            },
+            &config.properties_format,
+            graph,
+        ),
+        WritebackMethod::Python => {
+            let payload = super::python::plan_with_python(project_root, changes)?;
+            Ok(WritebackPlan {
+                files: vec![PlannedFile {
+                    path: project_root.to_path_buf(),
+                    models: Vec::new(),
+                    diff: payload,
+                }],
+            })
+        }
+    }
+}
+
+fn plan_with_rust(
+    fs: &dyn Fs,
+    project_root: &Path,
+    changes: &BTreeMap<String, ModelChanges>,
+    options: MoveOptions,
+    style: &crate::config::PropertiesFormat,
+    graph: &DbtGraph,
+) -> Result<WritebackPlan, WriteBackError> {
+    let staging = DryRunFs::new(fs);
+    let groups =
+        rust::plan_rust_writeback_grouped(&staging, project_root, changes, options, style, graph)?;
+    let ops = staging.into_ops();
+
+    let files = groups
+        .into_iter()
+        .map(|group| {
+            let diff = render_diff_for_path(fs, &group.path, &ops);
+            PlannedFile {
+                path: group.path,
+                models: group.results,
+                diff,
+            }
+        })
+        .collect();
+
+    Ok(WritebackPlan { files })
+}
+
+/// Renders the unified diff for whichever recorded op touched `path`: a `Write` diffs the
+/// file's prior contents (read straight from `fs`, the real filesystem -- `DryRunFs`
+/// never mutates it) against the staged new contents; a `Rename`/`Remove` has no content
+/// diff, just a note of what would happen. A file nothing happened to (e.g. a
+/// `NormalizePropertiesLayout` that turned out to be a no-op) has no entry at all.
+fn render_diff_for_path(fs: &dyn Fs, path: &Path, ops: &[DryRunOp]) -> String {
+    let mut rendered = String::new();
+    for op in ops {
+        match op {
+            DryRunOp::Write { path: op_path, contents } if op_path == path => {
+                let old = fs.read_to_string(path).unwrap_or_default();
+                rendered.push_str(&unified_diff(path, &old, contents));
+            }
+            DryRunOp::Remove { path: op_path } if op_path == path => {
+                rendered.push_str(&format!("(file removed: {})\n", path.display()));
+            }
+            DryRunOp::Rename { from, to } if to == path => {
+                rendered.push_str(&format!(
+                    "(moved from {} to {})\n",
+                    from.display(),
+                    to.display()
+                ));
+            }
+            _ => {}
+        }
+    }
+    rendered
+}
+
+/// Lines of context kept on each side of a changed block, same as `diff -u`'s default.
+const DIFF_CONTEXT: usize = 3;
+
+enum DiffLine<'a> {
+    Equal(&'a str),
+    Delete(&'a str),
+    Insert(&'a str),
+}
+
+/// Backtraces a classic O(n*m) LCS table into a flat edit script. No diff crate is
+/// vendored in this workspace, and property-file YAML is small, so this is plenty.
+fn diff_lines<'a>(old_lines: &[&'a str], new_lines: &[&'a str]) -> Vec<DiffLine<'a>> {
+    let (n, m) = (old_lines.len(), new_lines.len());
+    let mut lcs = vec![vec![0usize; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lcs[i][j] = if old_lines[i] == new_lines[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut ops = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < n && j < m {
+        if old_lines[i] == new_lines[j] {
+            ops.push(DiffLine::Equal(old_lines[i]));
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            ops.push(DiffLine::Delete(old_lines[i]));
+            i += 1;
+        } else {
+            ops.push(DiffLine::Insert(new_lines[j]));
+            j += 1;
+        }
+    }
+    while i < n {
+        ops.push(DiffLine::Delete(old_lines[i]));
+        i += 1;
+    }
+    while j < m {
+        ops.push(DiffLine::Insert(new_lines[j]));
+        j += 1;
+    }
+    ops
+}
+
+/// A real unified diff of `old` vs `new` against `path` -- `git apply`-compatible `---`/
+/// `+++` file headers plus `@@ -l,s +l,s @@` hunk headers around up to `DIFF_CONTEXT`
+/// lines of surrounding context, the same grouping `diff -u` uses. Returns an empty
+/// string when the two are identical (no hunks to show).
+fn unified_diff(path: &Path, old: &str, new: &str) -> String {
+    let old_lines: Vec<&str> = old.lines().collect();
+    let new_lines: Vec<&str> = new.lines().collect();
+    let ops = diff_lines(&old_lines, &new_lines);
+
+    if ops.iter().all(|op| matches!(op, DiffLine::Equal(_))) {
+        return String::new();
+    }
+
+    let display = path.display();
+    let mut out = format!("--- a/{display}\n+++ b/{display}\n");
+    // 1-indexed old/new line numbers the next op in `ops` lands on.
+    let (mut old_line, mut new_line) = (1usize, 1usize);
+
+    let mut start = 0;
+    while start < ops.len() {
+        // Find the next changed op (a hunk always has one, since the all-Equal case
+        // already returned above).
+        let Some(change_at) = ops[start..].iter().position(|op| !matches!(op, DiffLine::Equal(_)))
+        else {
+            break;
+        };
+        let change_at = start + change_at;
+
+        let hunk_start = change_at.saturating_sub(DIFF_CONTEXT).max(start);
+        // Extend the hunk through any further changes within `2 * DIFF_CONTEXT` lines of
+        // each other, so two nearby edits share one hunk instead of printing twice.
+        let mut hunk_end = change_at;
+        loop {
+            let next_change = ops[hunk_end..]
+                .iter()
+                .position(|op| !matches!(op, DiffLine::Equal(_)))
+                .map(|offset| hunk_end + offset);
+            let Some(next_change) = next_change else {
+                break;
+            };
+            let run_end = ops[next_change..]
+                .iter()
+                .position(|op| matches!(op, DiffLine::Equal(_)))
+                .map(|offset| next_change + offset)
+                .unwrap_or(ops.len());
+            if next_change > hunk_end && next_change - hunk_end > 2 * DIFF_CONTEXT {
+                break;
+            }
+            hunk_end = run_end;
+        }
+        let hunk_end = (hunk_end + DIFF_CONTEXT).min(ops.len());
+
+        // Advance the running line counters over the context before this hunk so the
+        // hunk header reports the right starting line numbers.
+        for op in &ops[start..hunk_start] {
+            match op {
+                DiffLine::Equal(_) => {
+                    old_line += 1;
+                    new_line += 1;
+                }
+                DiffLine::Delete(_) => old_line += 1,
+                DiffLine::Insert(_) => new_line += 1,
+            }
+        }
+
+        let (old_start, new_start) = (old_line, new_line);
+        let mut old_count = 0;
+        let mut new_count = 0;
+        let mut body = String::new();
+        for op in &ops[hunk_start..hunk_end] {
+            match op {
+                DiffLine::Equal(line) => {
+                    body.push_str(&format!(" {line}\n"));
+                    old_count += 1;
+                    new_count += 1;
+                    old_line += 1;
+                    new_line += 1;
+                }
+                DiffLine::Delete(line) => {
+                    body.push_str(&format!("-{line}\n"));
+                    old_count += 1;
+                    old_line += 1;
+                }
+                DiffLine::Insert(line) => {
+                    body.push_str(&format!("+{line}\n"));
+                    new_count += 1;
+                    new_line += 1;
+                }
+            }
+        }
+
+        out.push_str(&format!(
+            "@@ -{old_start},{old_count} +{new_start},{new_count} @@\n"
+        ));
+        out.push_str(&body);
+
+        start = hunk_end;
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::change_descriptors::ModelChange;
+    use crate::writeback::fs::FakeFs;
+    use crate::writeback::properties::{ColumnProperty, ModelProperty};
+
+    fn sample_yaml() -> &'static str {
+        r#"
+models:
+  - name: stg_order_items
+    description: Individual food and drink items that make up our orders, one row per item.
+    columns:
+      - name: order_item_id
+        description: The unique key for each order item.
+"#
+    }
+
+    #[test]
+    fn rust_plan_diffs_without_mutating_the_file() {
+        let project_root = Path::new("/project");
+        let fs = FakeFs::new().with_file(project_root.join("models.yml"), sample_yaml());
+
+        let mut changes = BTreeMap::new();
+        let mut mc = ModelChanges {
+            model_id: "model.jaffle_shop.stg_order_items".to_string(),
+            patch_path: Some(Path::new("models.yml").to_path_buf()),
+            ..Default::default()
+        };
+        mc.changes.push(ModelChange::ChangePropertiesFile {
+            model_id: mc.model_id.clone(),
+            model_name: "stg_order_items".to_string(),
+            patch_path: mc.patch_path.clone(),
+            property: Some(ModelProperty {
+                name: Some("stg_order_items".to_string()),
+                description: None,
+                columns: vec![ColumnProperty {
+                    name: "order_item_id".to_string(),
+                    description: Some("New desc".to_string()),
+                    ..Default::default()
+                }],
+                extras: BTreeMap::new(),
+            }),
+        });
+        changes.insert(mc.model_id.clone(), mc);
+
+        let config = Config::default();
+        let graph = DbtGraph {
+            graph: petgraph::graph::Graph::new(),
+            index: std::collections::HashMap::new(),
+        };
+        let plan = plan_model_changes(&fs, project_root, &changes, &config, &graph).unwrap();
+
+        assert_eq!(plan.files.len(), 1);
+        let file = &plan.files[0];
+        assert_eq!(file.path, project_root.join("models.yml"));
+        assert!(file.diff.contains(&format!("--- a/{}", file.path.display())));
+        assert!(file.diff.contains("@@ -"));
+        assert!(file.diff.contains("- "));
+        assert!(file.diff.contains("New desc"));
+        assert!(
+            fs.read(&project_root.join("models.yml"))
+                .unwrap()
+                .contains("The unique key for each order item."),
+            "dry run must not mutate the underlying file"
+        );
+    }
+
+    #[test]
+    fn unified_diff_emits_git_apply_compatible_headers_and_hunks() {
+        let old = "a\nb\nc\nd\ne\n";
+        let new = "a\nb\nX\nd\ne\n";
+        let diff = unified_diff(Path::new("models.yml"), old, new);
+
+        assert!(diff.starts_with("--- a/models.yml\n+++ b/models.yml\n"));
+        assert!(diff.contains("@@ -1,5 +1,5 @@\n"));
+        assert!(diff.contains("-c\n"));
+        assert!(diff.contains("+X\n"));
+    }
+
+    #[test]
+    fn unified_diff_of_identical_content_is_empty() {
+        assert_eq!(unified_diff(Path::new("models.yml"), "a\nb\n", "a\nb\n"), "");
+    }
+}
@@ -4,27 +4,205 @@
 
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 
 use dbt_schemas::schemas::{dbt_column::DbtColumnRef, manifest::ManifestModel};
+
+/// How `merge` resolves a conflicting value between `self` and `other`, threaded
+/// through `ColumnProperty`/`ModelProperty`/`SourceProperty::merge` so all three agree
+/// on one rule instead of each picking its own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MergeStrategy {
+    /// `self`'s value wins unless it's absent, in which case `other`'s fills it in.
+    /// Matches the old model/source merge behavior.
+    FillIfEmpty,
+    /// `other`'s value wins whenever it's present. Matches the old column merge
+    /// behavior.
+    Overwrite,
+}
+
+impl MergeStrategy {
+    /// Applies this strategy to one `description`-shaped field.
+    fn resolve(self, self_value: &mut Option<String>, other_value: &Option<String>) {
+        match self {
+            MergeStrategy::FillIfEmpty => {
+                if self_value.is_none() {
+                    *self_value = other_value.clone();
+                }
+            }
+            MergeStrategy::Overwrite => {
+                if other_value.is_some() {
+                    *self_value = other_value.clone();
+                }
+            }
+        }
+    }
+}
+
+/// Merges `other_extras` into `self_extras` key by key, applying `strategy` uniformly with
+/// the other merge rules instead of the old unconditional "fill missing keys only" behavior.
+fn merge_extras_with_strategy(
+    self_extras: &mut BTreeMap<String, dbt_serde_yaml::Value>,
+    other_extras: &BTreeMap<String, dbt_serde_yaml::Value>,
+    strategy: MergeStrategy,
+) {
+    for (key, other_value) in other_extras {
+        match self_extras.get(key) {
+            None => {
+                self_extras.insert(key.clone(), other_value.clone());
+            }
+            Some(self_value) if self_value == other_value => {}
+            Some(_) => match strategy {
+                MergeStrategy::FillIfEmpty => {}
+                MergeStrategy::Overwrite => {
+                    self_extras.insert(key.clone(), other_value.clone());
+                }
+            },
+        }
+    }
+}
+
+/// A single field-level disagreement surfaced by `Merge::merge`, e.g.
+/// `source_x.t1.col_z.description`, for callers to report as a lint diagnostic instead
+/// of letting one side win invisibly.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MergeConflict {
+    pub path: String,
+    pub self_value: String,
+    pub other_value: String,
+}
+
+impl MergeConflict {
+    fn new(
+        path: impl Into<String>,
+        self_value: impl Into<String>,
+        other_value: impl Into<String>,
+    ) -> Self {
+        MergeConflict {
+            path: path.into(),
+            self_value: self_value.into(),
+            other_value: other_value.into(),
+        }
+    }
+
+    /// Prepends `prefix` to `path`, turning a column's own `description` conflict into
+    /// `col_z.description` once aggregated by its owning `ModelProperty`.
+    fn prefixed(self, prefix: &str) -> Self {
+        MergeConflict {
+            path: format!("{prefix}.{}", self.path),
+            ..self
+        }
+    }
+}
+
+/// Merges `other` into `self`, reporting every field where both sides had a populated
+/// but differing value instead of letting one win invisibly -- the actual conflict
+/// resolution still favors `other` (same rule as `MergeStrategy::Overwrite`), this just
+/// makes the collision visible to the caller.
+pub trait Merge {
+    fn merge(&mut self, other: &Self) -> Vec<MergeConflict>;
+}
+
+impl Merge for BTreeMap<String, dbt_serde_yaml::Value> {
+    fn merge(&mut self, other: &Self) -> Vec<MergeConflict> {
+        let mut conflicts = Vec::new();
+        for (k, v) in other {
+            match self.get(k) {
+                Some(existing) if existing != v => {
+                    conflicts.push(MergeConflict::new(
+                        k.clone(),
+                        format!("{existing:?}"),
+                        format!("{v:?}"),
+                    ));
+                    self.insert(k.clone(), v.clone());
+                }
+                Some(_) => {}
+                None => {
+                    self.insert(k.clone(), v.clone());
+                }
+            }
+        }
+        conflicts
+    }
+}
+
 #[skip_serializing_none]
-#[derive(Debug, Deserialize, Serialize, Clone)]
+#[derive(Debug, Default, Deserialize, Serialize, Clone)]
 pub struct ColumnProperty {
     pub name: String,
     pub description: Option<String>,
+    pub data_type: Option<String>,
+    pub data_tests: Option<Vec<String>>,
+    pub tags: Option<Vec<String>>,
+    pub meta: Option<BTreeMap<String, dbt_serde_yaml::Value>>,
     #[serde(flatten)]
     pub extras: BTreeMap<String, dbt_serde_yaml::Value>,
 }
 
 impl ColumnProperty {
-    fn merge(&mut self, other: &ColumnProperty) {
-        if other.description.is_some() {
-            self.description = other.description.clone();
+    fn merge_with_strategy(&mut self, other: &ColumnProperty, strategy: MergeStrategy) {
+        strategy.resolve(&mut self.description, &other.description);
+        strategy.resolve(&mut self.data_type, &other.data_type);
+        if let Some(other_tests) = &other.data_tests {
+            let tests = self.data_tests.get_or_insert_with(Vec::new);
+            for test_name in other_tests {
+                if !tests.contains(test_name) {
+                    tests.push(test_name.clone());
+                }
+            }
+        }
+        if let Some(other_tags) = &other.tags {
+            let tags = self.tags.get_or_insert_with(Vec::new);
+            for tag in other_tags {
+                if !tags.contains(tag) {
+                    tags.push(tag.clone());
+                }
+            }
+        }
+        if let Some(other_meta) = &other.meta {
+            merge_extras_with_strategy(
+                self.meta.get_or_insert_with(BTreeMap::new),
+                other_meta,
+                strategy,
+            );
         }
-        // probably won't have extras here
-        for (k, v) in &other.extras {
-            self.extras.entry(k.clone()).or_insert_with(|| v.clone());
+        merge_extras_with_strategy(&mut self.extras, &other.extras, strategy);
+    }
+}
+
+impl Merge for ColumnProperty {
+    fn merge(&mut self, other: &ColumnProperty) -> Vec<MergeConflict> {
+        let mut conflicts = Vec::new();
+        if let (Some(self_desc), Some(other_desc)) = (&self.description, &other.description)
+            && self_desc != other_desc
+        {
+            conflicts.push(MergeConflict::new(
+                "description",
+                self_desc.clone(),
+                other_desc.clone(),
+            ));
+        }
+        if let (Some(self_type), Some(other_type)) = (&self.data_type, &other.data_type)
+            && self_type != other_type
+        {
+            conflicts.push(MergeConflict::new(
+                "data_type",
+                self_type.clone(),
+                other_type.clone(),
+            ));
         }
+        if let (Some(self_meta), Some(other_meta)) = (&self.meta, &other.meta) {
+            conflicts.extend(
+                self_meta
+                    .clone()
+                    .merge(other_meta)
+                    .into_iter()
+                    .map(|c| c.prefixed("meta")),
+            );
+        }
+        conflicts.extend(self.extras.clone().merge(&other.extras));
+        self.merge_with_strategy(other, MergeStrategy::Overwrite);
+        conflicts
     }
 }
 
@@ -39,17 +217,16 @@ pub struct ModelProperty {
 }
 
 impl ModelProperty {
-    pub fn merge(&mut self, other: &ModelProperty) {
-        if self.description.is_none() {
-            self.description = other.description.clone();
-        }
+    pub fn merge_with_strategy(&mut self, other: &ModelProperty, strategy: MergeStrategy) {
+        strategy.resolve(&mut self.description, &other.description);
+
         let mut other_columns_map: BTreeMap<String, &ColumnProperty> = BTreeMap::new();
         for col in &other.columns {
             other_columns_map.insert(col.name.clone(), col);
         }
         for col in &mut self.columns {
             if let Some(other_col) = other_columns_map.get(&col.name) {
-                col.merge(other_col);
+                col.merge_with_strategy(other_col, strategy);
                 // pop from other_columns_map to track which have been merged
                 other_columns_map.remove(&col.name);
             }
@@ -59,17 +236,69 @@ impl ModelProperty {
             self.columns.push(col.clone());
         }
 
-        // probably won't have extras here
-        for (k, v) in &other.extras {
-            self.extras.entry(k.clone()).or_insert_with(|| v.clone());
+        merge_extras_with_strategy(&mut self.extras, &other.extras, strategy);
+    }
+}
+
+impl Merge for ModelProperty {
+    fn merge(&mut self, other: &ModelProperty) -> Vec<MergeConflict> {
+        let mut conflicts = Vec::new();
+        if let (Some(self_desc), Some(other_desc)) = (&self.description, &other.description)
+            && self_desc != other_desc
+        {
+            conflicts.push(MergeConflict::new(
+                "description",
+                self_desc.clone(),
+                other_desc.clone(),
+            ));
         }
+
+        let mut other_columns_map: BTreeMap<String, &ColumnProperty> = BTreeMap::new();
+        for col in &other.columns {
+            other_columns_map.insert(col.name.clone(), col);
+        }
+        for col in &mut self.columns {
+            if let Some(other_col) = other_columns_map.get(&col.name) {
+                let name = col.name.clone();
+                conflicts.extend(col.merge(other_col).into_iter().map(|c| c.prefixed(&name)));
+            }
+        }
+
+        conflicts.extend(self.extras.clone().merge(&other.extras));
+        self.merge_with_strategy(other, MergeStrategy::Overwrite);
+        conflicts
     }
 }
 
+/// A single column-level change between two versions of a model, modeled as a
+/// New/Modify/Delete operation triad (mirrors how dependency resolvers diff a lockfile).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ColumnDiff {
+    Added(ColumnProperty),
+    Modified {
+        name: String,
+        description: Option<String>,
+        // other tracked attributes (e.g. data_tests, data_type, tags, meta -- all of
+        // which `ColumnProperty` now has a field for on the writeback side) would gain a
+        // field here once `DbtColumnRef` exposes them for comparison against the
+        // properties file.
+    },
+    Removed(String),
+}
+
+/// Diffs `original` against `updated` and returns the `ModelProperty` patch to write
+/// back (model description plus any added/modified column descriptions) alongside the
+/// full column changeset, including columns present in `original` but dropped from
+/// `updated`. Returns `None` if nothing changed.
+///
+/// `ModelProperty`/`ColumnProperty` have no way to express "delete this column" yet --
+/// `ColumnDiff::Removed` is surfaced for callers to act on, but `apply_with_rust`
+/// doesn't currently consume it, so a dropped column's properties-file entry is left
+/// in place rather than pruned.
 pub fn model_property_from_manifest_differences(
     original: &ManifestModel,
     updated: &ManifestModel,
-) -> Option<ModelProperty> {
+) -> Option<(ModelProperty, Vec<ColumnDiff>)> {
     let mut model_prop = ModelProperty {
         name: Some(original.__common_attr__.name.clone()), // TODO: name shouldn't be option
         description: None,
@@ -82,27 +311,127 @@ pub fn model_property_from_manifest_differences(
         model_prop.description = updated.__common_attr__.description.clone();
     }
 
-    let mut original_columns_map: BTreeMap<String, &DbtColumnRef> = BTreeMap::new();
+    let mut original_columns: BTreeMap<String, &DbtColumnRef> = BTreeMap::new();
     for col in &original.__base_attr__.columns {
-        original_columns_map.insert(col.name.clone(), col);
+        original_columns.insert(col.name.clone(), col);
     }
+    let mut updated_columns: BTreeMap<String, &DbtColumnRef> = BTreeMap::new();
+    for col in &updated.__base_attr__.columns {
+        updated_columns.insert(col.name.clone(), col);
+    }
+
+    let all_names: BTreeSet<String> = original_columns
+        .keys()
+        .chain(updated_columns.keys())
+        .cloned()
+        .collect();
 
-    for updated_col in &updated.__base_attr__.columns {
-        if let Some(orig_col) = original_columns_map.get(&updated_col.name) {
-            if orig_col.description != updated_col.description {
-                model_prop.columns.push(ColumnProperty {
-                    name: updated_col.name.clone(),
+    let mut column_diffs = Vec::new();
+    for name in all_names {
+        match (original_columns.get(&name), updated_columns.get(&name)) {
+            (None, Some(updated_col)) => {
+                has_change = true;
+                let prop = ColumnProperty {
+                    name: name.clone(),
                     description: updated_col.description.clone(),
-                    extras: BTreeMap::new(),
-                });
+                    ..Default::default()
+                };
+                column_diffs.push(ColumnDiff::Added(prop.clone()));
+                model_prop.columns.push(prop);
             }
+            (Some(_), None) => {
+                has_change = true;
+                column_diffs.push(ColumnDiff::Removed(name));
+            }
+            (Some(orig_col), Some(updated_col)) => {
+                if orig_col.description != updated_col.description {
+                    has_change = true;
+                    column_diffs.push(ColumnDiff::Modified {
+                        name: name.clone(),
+                        description: updated_col.description.clone(),
+                    });
+                    model_prop.columns.push(ColumnProperty {
+                        name,
+                        description: updated_col.description.clone(),
+                        ..Default::default()
+                    });
+                }
+            }
+            (None, None) => unreachable!("name drawn from the union of both key sets"),
         }
     }
-    // I don't think this catches everything yet, but it's a start
+
     if !has_change {
         return None;
     }
-    Some(model_prop)
+    Some((model_prop, column_diffs))
+}
+
+/// Diffs `original` against `updated` and returns the `SourceProperty` patch to write
+/// back. Unlike `model_property_from_manifest_differences`, there's no column changeset
+/// to return separately -- `check::sources` doesn't offer a per-column fix the way
+/// `check_model` does, it only ever flips the table's own description or a column's, so
+/// a single `SourceProperty` (one source block holding the one changed table) is enough.
+/// Returns `None` if nothing changed.
+///
+/// A `ManifestSource` node is already scoped to a single table (the manifest flattens
+/// `sources[].tables[]` into one node per table, unlike a model), so the returned
+/// `SourceProperty.tables` always holds exactly one entry.
+pub fn source_property_from_manifest_differences(
+    original: &dbt_schemas::schemas::manifest::ManifestSource,
+    updated: &dbt_schemas::schemas::manifest::ManifestSource,
+) -> Option<SourceProperty> {
+    let mut table_prop = ModelProperty {
+        name: Some(original.__common_attr__.name.clone()),
+        description: None,
+        columns: Vec::new(),
+        extras: BTreeMap::new(),
+    };
+    let mut has_change = false;
+    if original.__common_attr__.description != updated.__common_attr__.description {
+        has_change = true;
+        table_prop.description = updated.__common_attr__.description.clone();
+    }
+
+    let mut original_columns: BTreeMap<String, &DbtColumnRef> = BTreeMap::new();
+    for col in &original.columns {
+        original_columns.insert(col.name.clone(), col);
+    }
+    let mut updated_columns: BTreeMap<String, &DbtColumnRef> = BTreeMap::new();
+    for col in &updated.columns {
+        updated_columns.insert(col.name.clone(), col);
+    }
+
+    let all_names: BTreeSet<String> = original_columns
+        .keys()
+        .chain(updated_columns.keys())
+        .cloned()
+        .collect();
+
+    for name in all_names {
+        if let (Some(orig_col), Some(updated_col)) =
+            (original_columns.get(&name), updated_columns.get(&name))
+            && orig_col.description != updated_col.description
+        {
+            has_change = true;
+            table_prop.columns.push(ColumnProperty {
+                name,
+                description: updated_col.description.clone(),
+                ..Default::default()
+            });
+        }
+    }
+
+    if !has_change {
+        return None;
+    }
+
+    Some(SourceProperty {
+        name: original.source_name.clone(),
+        description: None,
+        tables: vec![table_prop],
+        extras: BTreeMap::new(),
+    })
 }
 
 #[skip_serializing_none]
@@ -116,10 +445,39 @@ pub struct SourceProperty {
 }
 
 impl SourceProperty {
-    pub fn merge(&mut self, other: &SourceProperty) {
-        if self.description.is_none() {
-            self.description = other.description.clone();
+    pub fn merge_with_strategy(&mut self, other: &SourceProperty, strategy: MergeStrategy) {
+        strategy.resolve(&mut self.description, &other.description);
+
+        let mut other_tables_map: BTreeMap<String, &ModelProperty> = BTreeMap::new();
+        for table in &other.tables {
+            if let Some(name) = &table.name {
+                other_tables_map.insert(name.clone(), table);
+            }
         }
+        for table in &mut self.tables {
+            if let Some(name) = &table.name
+                && let Some(other_table) = other_tables_map.get(name)
+            {
+                table.merge_with_strategy(other_table, strategy);
+            }
+        }
+        merge_extras_with_strategy(&mut self.extras, &other.extras, strategy);
+    }
+}
+
+impl Merge for SourceProperty {
+    fn merge(&mut self, other: &SourceProperty) -> Vec<MergeConflict> {
+        let mut conflicts = Vec::new();
+        if let (Some(self_desc), Some(other_desc)) = (&self.description, &other.description)
+            && self_desc != other_desc
+        {
+            conflicts.push(MergeConflict::new(
+                "description",
+                self_desc.clone(),
+                other_desc.clone(),
+            ));
+        }
+
         let mut other_tables_map: BTreeMap<String, &ModelProperty> = BTreeMap::new();
         for table in &other.tables {
             if let Some(name) = &table.name {
@@ -130,19 +488,66 @@ impl SourceProperty {
             if let Some(name) = &table.name
                 && let Some(other_table) = other_tables_map.get(name)
             {
-                table.merge(other_table);
+                conflicts.extend(table.merge(other_table).into_iter().map(|c| c.prefixed(name)));
             }
         }
-        // probably won't have extras here
-        for (k, v) in &other.extras {
-            self.extras.entry(k.clone()).or_insert_with(|| v.clone());
+
+        conflicts.extend(self.extras.clone().merge(&other.extras));
+        self.merge_with_strategy(other, MergeStrategy::Overwrite);
+        conflicts
+    }
+}
+
+/// The schema version this crate currently reads and writes. Bump this alongside a new
+/// entry in `MIGRATIONS` whenever the emitted/expected shape changes.
+pub const CURRENT_VERSION: u32 = 2;
+
+/// Older property files (and any hand-built `PropertyFile` in tests) have no `version`
+/// key at all -- that's the oldest shape this crate understands, not an error.
+fn default_version() -> u32 {
+    1
+}
+
+/// A migration from the version at its index + 1 up to the next version, e.g.
+/// `MIGRATIONS[0]` takes a version-1 file to version 2.
+type MigrationFn = fn(&mut PropertyFile);
+
+/// Ordered migration chain, run in order starting from a file's declared `version`.
+const MIGRATIONS: &[MigrationFn] = &[migrate_v1_to_v2];
+
+/// dbt renamed the `tests:` key to `data_tests:` (keeping `tests` as a deprecated
+/// alias). `ColumnProperty` only binds `data_tests`, so a version-1 file's `tests:`
+/// was falling unmigrated into `extras`. Pull it into the named field so every
+/// column's tests -- old alias or new -- live in one place for linting and merging.
+fn migrate_v1_to_v2(file: &mut PropertyFile) {
+    for model in file.models.iter_mut().flatten() {
+        for col in &mut model.columns {
+            migrate_column_tests_alias(col);
         }
     }
+    for source in file.sources.iter_mut().flatten() {
+        for table in &mut source.tables {
+            for col in &mut table.columns {
+                migrate_column_tests_alias(col);
+            }
+        }
+    }
+}
+
+fn migrate_column_tests_alias(col: &mut ColumnProperty) {
+    if col.data_tests.is_none()
+        && let Some(old_tests) = col.extras.remove("tests")
+        && let Ok(tests) = dbt_serde_yaml::from_value::<Vec<String>>(old_tests)
+    {
+        col.data_tests = Some(tests);
+    }
 }
 
 #[skip_serializing_none]
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct PropertyFile {
+    #[serde(default = "default_version")]
+    pub version: u32,
     pub models: Option<Vec<ModelProperty>>,
     pub sources: Option<Vec<SourceProperty>>,
     #[serde(flatten)]
@@ -150,6 +555,19 @@ pub struct PropertyFile {
 }
 
 impl PropertyFile {
+    /// Runs every migration between the file's declared `version` and
+    /// `CURRENT_VERSION` in order, then stamps `version` as current. `find_model_mut`
+    /// and the `Merge` paths assume this has already run; `rust::read_property_file`
+    /// calls it on every file it loads, so a hand-built `PropertyFile` (as in tests)
+    /// only needs to call it explicitly if it cares about pre-v2 shapes.
+    pub fn normalize(&mut self) {
+        let already_applied = self.version.saturating_sub(1) as usize;
+        for migration in MIGRATIONS.iter().skip(already_applied) {
+            migration(self);
+        }
+        self.version = CURRENT_VERSION;
+    }
+
     pub fn find_model_mut(&mut self, model_name: &str) -> Option<&mut ModelProperty> {
         self.models.as_mut().and_then(|models| {
             models
@@ -159,25 +577,505 @@ impl PropertyFile {
     }
 }
 
+/// Which line terminator a property file used on disk, so writeback can re-apply it
+/// instead of always emitting `dbt_serde_yaml`'s LF-normalized output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineEnding {
+    Lf,
+    Crlf,
+}
+
+impl LineEnding {
+    /// Sniffs the terminator of the first line break found, same idea as Zed's
+    /// `LineEnding::detect`. Files with no line breaks at all are treated as `Lf`.
+    fn detect(contents: &str) -> Self {
+        match contents.find('\n') {
+            Some(idx) if idx > 0 && contents.as_bytes()[idx - 1] == b'\r' => LineEnding::Crlf,
+            _ => LineEnding::Lf,
+        }
+    }
+}
+
+/// Everything about a property file's on-disk formatting that `dbt_serde_yaml` doesn't
+/// preserve across a parse/serialize round trip: its line ending, whether it ended in a
+/// trailing newline, and any leading comment block (e.g. a license header). Captured by
+/// `rust::read_property_file` and re-applied by `render_property_file` so editing one
+/// column doesn't rewrite the whole file's formatting out from under the user.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PropertyFileFormatting {
+    line_ending: LineEnding,
+    trailing_newline: bool,
+    leading_comment: String,
+}
+
+impl Default for PropertyFileFormatting {
+    /// Matches what `dbt_serde_yaml::to_string` already produces on its own, so a
+    /// brand-new property file is unaffected.
+    fn default() -> Self {
+        PropertyFileFormatting {
+            line_ending: LineEnding::Lf,
+            trailing_newline: true,
+            leading_comment: String::new(),
+        }
+    }
+}
+
+impl PropertyFileFormatting {
+    /// Splits `contents` into its formatting and the remaining text to hand to the YAML
+    /// parser, so leading comments never get silently dropped by the `models`/`sources`/
+    /// `extras` round trip.
+    pub fn detect(contents: &str) -> (Self, &str) {
+        let (leading_comment, remainder) = split_leading_comments(contents);
+        let formatting = PropertyFileFormatting {
+            line_ending: LineEnding::detect(contents),
+            trailing_newline: contents.ends_with('\n'),
+            leading_comment: leading_comment.to_string(),
+        };
+        (formatting, remainder)
+    }
+}
+
+/// Splits off a leading run of blank and `#`-comment lines verbatim, so they can be
+/// re-prepended after serialization instead of being fed to (and dropped by) the YAML
+/// parser.
+fn split_leading_comments(contents: &str) -> (&str, &str) {
+    let mut split_idx = 0;
+    for line in contents.split_inclusive('\n') {
+        let trimmed = line.trim_start();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            split_idx += line.len();
+        } else {
+            break;
+        }
+    }
+    contents.split_at(split_idx)
+}
+
+/// Serializes `doc` and re-applies `formatting`'s leading comment, line ending, and
+/// trailing newline, so a one-column edit produces a minimal diff instead of rewriting
+/// the whole file. Equivalent to [`render_property_file_styled`] with the default
+/// [`crate::config::PropertiesFormat`], i.e. whatever `dbt_serde_yaml` already produces.
+pub fn render_property_file(
+    doc: &PropertyFile,
+    formatting: &PropertyFileFormatting,
+) -> Result<String, dbt_serde_yaml::Error> {
+    render_property_file_styled(doc, formatting, &crate::config::PropertiesFormat::default())
+}
+
+/// Serializes `doc` in the YAML style described by `style`, then re-applies `formatting`'s
+/// leading comment, line ending, and trailing newline, same as [`render_property_file`].
+///
+/// `dbt_serde_yaml` has no configurable serializer (no indent/quote-style builder, confirmed
+/// by grepping every call site of it in this crate), so `style` is applied as text
+/// post-processing on top of its default two-space-indented output rather than through the
+/// serializer itself:
+/// - `indent_width`: every line's leading-space run is a multiple of two in the default
+///   output (both map nesting and `- ` sequence markers use a two-space unit), so each run
+///   is rescaled from that unit to `indent_width`.
+/// - `wrap_long_descriptions`: a `description` value longer than `description_wrap_threshold`
+///   is rewritten from a single quoted line into a folded block scalar (`>-`) wrapped across
+///   multiple physical lines. Folded style re-joins wrapped lines back into the same single
+///   logical string on parse, so this only changes how the file looks, not the value.
+/// - `quote_policy`: `AlwaysDouble` rewrites an unquoted plain scalar value into a
+///   double-quoted one; this is best-effort text matching, not a YAML-aware rewrite, so it
+///   skips anything that isn't unambiguously a bare string (numbers, booleans, null,
+///   existing quotes, flow collections, anchors/aliases, block scalars).
+/// - `key_order`: `CanonicalDbtOrder` is a no-op, matching what `ModelProperty`/
+///   `ColumnProperty`'s derived `Serialize` impl already emits. `PreserveOriginal` behaves
+///   identically for now -- see the doc comment on `crate::config::KeyOrder::PreserveOriginal`.
+pub fn render_property_file_styled(
+    doc: &PropertyFile,
+    formatting: &PropertyFileFormatting,
+    style: &crate::config::PropertiesFormat,
+) -> Result<String, dbt_serde_yaml::Error> {
+    let yaml = dbt_serde_yaml::to_string(doc)?;
+    let yaml = if style.wrap_long_descriptions {
+        wrap_long_description_lines(&yaml, style.description_wrap_threshold)
+    } else {
+        yaml
+    };
+    let yaml = if style.quote_policy == crate::config::QuotePolicy::AlwaysDouble {
+        apply_always_double_quotes(&yaml)
+    } else {
+        yaml
+    };
+    let yaml = reindent(&yaml, style.indent_width);
+
+    let yaml = match formatting.line_ending {
+        LineEnding::Lf => yaml,
+        LineEnding::Crlf => yaml.replace('\n', "\r\n"),
+    };
+    let mut out = format!("{}{yaml}", formatting.leading_comment);
+    if !formatting.trailing_newline {
+        while out.ends_with('\n') || out.ends_with('\r') {
+            out.pop();
+        }
+    }
+    Ok(out)
+}
+
+/// Rescales every line's leading-space run from `dbt_serde_yaml`'s native two-space unit to
+/// `indent_width` spaces per level. A no-op when `indent_width` is already 2.
+fn reindent(yaml: &str, indent_width: usize) -> String {
+    if indent_width == 2 {
+        return yaml.to_string();
+    }
+
+    let mut out = String::with_capacity(yaml.len());
+    for line in yaml.split_inclusive('\n') {
+        let (content, newline) = match line.strip_suffix('\n') {
+            Some(c) => (c, "\n"),
+            None => (line, ""),
+        };
+        let leading = content.chars().take_while(|c| *c == ' ').count();
+        let rest = &content[leading..];
+        out.push_str(&" ".repeat((leading / 2) * indent_width));
+        out.push_str(rest);
+        out.push_str(newline);
+    }
+    out
+}
+
+/// Rewrites a `description: "..."` line longer than `threshold` characters into a folded
+/// block scalar (`description: >-`) wrapped across multiple physical lines, one indent level
+/// deeper than the key.
+fn wrap_long_description_lines(yaml: &str, threshold: usize) -> String {
+    let mut out = String::new();
+    for line in yaml.split_inclusive('\n') {
+        let (content, newline) = match line.strip_suffix('\n') {
+            Some(c) => (c, "\n"),
+            None => (line, ""),
+        };
+        let leading = content.chars().take_while(|c| *c == ' ').count();
+        let stripped = &content[leading..];
+
+        let Some(value) = stripped.strip_prefix("description: ") else {
+            out.push_str(content);
+            out.push_str(newline);
+            continue;
+        };
+        let Some(text) = unquote_plain_scalar(value) else {
+            out.push_str(content);
+            out.push_str(newline);
+            continue;
+        };
+        if text.chars().count() <= threshold {
+            out.push_str(content);
+            out.push_str(newline);
+            continue;
+        }
+
+        let continuation_indent = " ".repeat(leading + 2);
+        out.push_str(&" ".repeat(leading));
+        out.push_str("description: >-\n");
+        for wrapped in wrap_at_width(&text, threshold) {
+            out.push_str(&continuation_indent);
+            out.push_str(&wrapped);
+            out.push('\n');
+        }
+    }
+    out
+}
+
+/// Strips a double- or single-quoted YAML plain scalar down to its logical value. Returns
+/// `None` for anything else (already a block scalar, flow collection, anchor, unquoted bare
+/// word), since those aren't this function's concern.
+pub(super) fn unquote_plain_scalar(value: &str) -> Option<String> {
+    if let Some(inner) = value.strip_prefix('"').and_then(|v| v.strip_suffix('"')) {
+        Some(inner.replace("\\\"", "\""))
+    } else {
+        value
+            .strip_prefix('\'')
+            .and_then(|v| v.strip_suffix('\''))
+            .map(|inner| inner.replace("''", "'"))
+    }
+}
+
+/// Greedy word wrap: packs whitespace-separated words onto lines no longer than `width`
+/// where possible (a single word longer than `width` still gets its own line whole).
+pub(super) fn wrap_at_width(text: &str, width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut current = String::new();
+    for word in text.split_whitespace() {
+        if !current.is_empty() && current.len() + 1 + word.len() > width {
+            lines.push(std::mem::take(&mut current));
+        }
+        if !current.is_empty() {
+            current.push(' ');
+        }
+        current.push_str(word);
+    }
+    if !current.is_empty() {
+        lines.push(current);
+    }
+    lines
+}
+
+/// Rewrites an unquoted plain scalar value into a double-quoted one, line by line. Tracks
+/// whether we're inside a block scalar's continuation lines (by the indent of the key line
+/// that opened it) so block-scalar body text is never mistaken for a `key: value` pair.
+fn apply_always_double_quotes(yaml: &str) -> String {
+    let mut out = String::new();
+    let mut block_scalar_key_indent: Option<usize> = None;
+
+    for line in yaml.split_inclusive('\n') {
+        let (content, newline) = match line.strip_suffix('\n') {
+            Some(c) => (c, "\n"),
+            None => (line, ""),
+        };
+        let leading = content.chars().take_while(|c| *c == ' ').count();
+
+        if let Some(key_indent) = block_scalar_key_indent {
+            if content.trim().is_empty() || leading > key_indent {
+                out.push_str(content);
+                out.push_str(newline);
+                continue;
+            }
+            block_scalar_key_indent = None;
+        }
+
+        match quote_scalar_value(content) {
+            Some(quoted) => out.push_str(&quoted),
+            None => out.push_str(content),
+        }
+        out.push_str(newline);
+
+        if scalar_value(content).is_some_and(is_block_scalar_indicator) {
+            block_scalar_key_indent = Some(leading);
+        }
+    }
+    out
+}
+
+/// Splits a `key: value` (or `- key: value`) line into its value part, if it has one.
+pub(super) fn scalar_value(line: &str) -> Option<&str> {
+    let stripped = line.trim_start();
+    let rest = stripped.strip_prefix("- ").unwrap_or(stripped);
+    let colon = rest.find(": ")?;
+    Some(&rest[colon + 2..])
+}
+
+pub(super) fn is_block_scalar_indicator(value: &str) -> bool {
+    matches!(value, "|" | "|-" | "|+" | ">" | ">-" | ">+")
+}
+
+/// Rewrites `key: value`/`- key: value` into `key: "value"` when `value` is an unquoted
+/// plain scalar that's unambiguously a bare string -- i.e. not already quoted, not a number/
+/// bool/null, not a flow collection, anchor, alias, or block scalar indicator. Returns `None`
+/// (leave the line alone) for anything else, including lines with no `key: value` shape at all.
+fn quote_scalar_value(line: &str) -> Option<String> {
+    let stripped = line.trim_start();
+    let leading_ws = &line[..line.len() - stripped.len()];
+
+    let (prefix, rest) = match stripped.strip_prefix("- ") {
+        Some(after_dash) => (format!("{leading_ws}- "), after_dash),
+        None => (leading_ws.to_string(), stripped),
+    };
+
+    let colon = rest.find(": ")?;
+    let (key, value) = rest.split_at(colon);
+    let value = &value[2..];
+
+    let is_plain_string = !value.is_empty()
+        && !value.starts_with('"')
+        && !value.starts_with('\'')
+        && !value.starts_with('[')
+        && !value.starts_with('{')
+        && !value.starts_with('&')
+        && !value.starts_with('*')
+        && !is_block_scalar_indicator(value)
+        && !matches!(value, "null" | "~" | "true" | "false")
+        && value.parse::<f64>().is_err();
+
+    if !is_plain_string {
+        return None;
+    }
+
+    Some(format!("{prefix}{key}: \"{value}\""))
+}
+
+/// The nesting level an `extras` bucket was found at, each with its own set of keys
+/// dbt actually recognizes there (fields already bound to a named struct field, like
+/// `description`, never show up in `extras` and so aren't listed here).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PropertyLevel {
+    Model,
+    Source,
+    SourceTable,
+    Column,
+}
+
+impl PropertyLevel {
+    /// The common dbt-recognized keys at this level beyond the ones already bound to a
+    /// named field on the corresponding struct. Not exhaustive of every dbt version's
+    /// schema, but enough to catch the typo/misplacement class of mistake this check
+    /// targets.
+    fn known_keys(self) -> &'static [&'static str] {
+        match self {
+            PropertyLevel::Model => &[
+                "config",
+                "meta",
+                "tags",
+                "docs",
+                "constraints",
+                "latest_version",
+                "deprecation_date",
+                "access",
+                "versions",
+                "tests",
+            ],
+            PropertyLevel::Source => &[
+                "database",
+                "schema",
+                "loader",
+                "meta",
+                "tags",
+                "config",
+                "overrides",
+                "freshness",
+                "quoting",
+            ],
+            PropertyLevel::SourceTable => &[
+                "meta",
+                "tags",
+                "tests",
+                "loaded_at_field",
+                "freshness",
+                "quoting",
+                "external",
+                "identifier",
+            ],
+            PropertyLevel::Column => &["tests", "meta", "tags", "quote", "constraints"],
+        }
+    }
+}
+
+/// An `extras` key that isn't part of the known schema for its level, e.g. `descrption`
+/// under a model when `description` was meant.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExtrasWarning {
+    /// Dotted location of the offending key, e.g. `source_x.t1.col_z`.
+    pub path: String,
+    pub key: String,
+    /// The closest known key within a small edit distance, if any.
+    pub suggestion: Option<String>,
+}
+
+/// Walks a parsed `PropertyFile` and flags every `extras` key that isn't part of the
+/// known schema for its level, with a nearest-key suggestion where one is close enough
+/// to plausibly be a typo. Keys that round-trip silently today (via `#[serde(flatten)]`)
+/// surface here instead.
+pub fn validate_extras(file: &PropertyFile) -> Vec<ExtrasWarning> {
+    let mut warnings = Vec::new();
+
+    for model in file.models.iter().flatten() {
+        let model_path = model.name.clone().unwrap_or_default();
+        warnings.extend(extras_warnings(&model.extras, PropertyLevel::Model, &model_path));
+        for col in &model.columns {
+            let col_path = format!("{model_path}.{}", col.name);
+            warnings.extend(extras_warnings(&col.extras, PropertyLevel::Column, &col_path));
+        }
+    }
+
+    for source in file.sources.iter().flatten() {
+        warnings.extend(extras_warnings(&source.extras, PropertyLevel::Source, &source.name));
+        for table in &source.tables {
+            let table_name = table.name.clone().unwrap_or_default();
+            let table_path = format!("{}.{table_name}", source.name);
+            warnings.extend(extras_warnings(
+                &table.extras,
+                PropertyLevel::SourceTable,
+                &table_path,
+            ));
+            for col in &table.columns {
+                let col_path = format!("{table_path}.{}", col.name);
+                warnings.extend(extras_warnings(&col.extras, PropertyLevel::Column, &col_path));
+            }
+        }
+    }
+
+    warnings
+}
+
+fn extras_warnings(
+    extras: &BTreeMap<String, dbt_serde_yaml::Value>,
+    level: PropertyLevel,
+    path: &str,
+) -> Vec<ExtrasWarning> {
+    let known = level.known_keys();
+    // Named struct fields never land in `extras`, but a typo of one does, so they're
+    // offered as suggestions alongside `known`.
+    let named: &[&str] = match level {
+        PropertyLevel::Model | PropertyLevel::SourceTable => &["name", "description", "columns"],
+        PropertyLevel::Source => &["name", "description", "tables"],
+        PropertyLevel::Column => &["name", "description", "data_type", "data_tests"],
+    };
+    let candidates: Vec<&str> = known.iter().chain(named).copied().collect();
+    extras
+        .keys()
+        .filter(|key| !known.contains(&key.as_str()))
+        .map(|key| ExtrasWarning {
+            path: path.to_string(),
+            key: key.clone(),
+            suggestion: nearest_key(key, &candidates),
+        })
+        .collect()
+}
+
+/// The closest known key within a small edit distance (2), or `None` if nothing is
+/// close enough to plausibly be a typo of `key`.
+fn nearest_key(key: &str, known: &[&str]) -> Option<String> {
+    const MAX_SUGGESTION_DISTANCE: usize = 2;
+    known
+        .iter()
+        .map(|candidate| (*candidate, levenshtein_distance(key, candidate)))
+        .filter(|(_, distance)| *distance <= MAX_SUGGESTION_DISTANCE)
+        .min_by_key(|(_, distance)| *distance)
+        .map(|(candidate, _)| candidate.to_string())
+}
+
+fn levenshtein_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for i in 1..=a.len() {
+        let mut prev_diag = row[0];
+        row[0] = i;
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let deletion = row[j] + 1;
+            let insertion = row[j - 1] + 1;
+            let substitution = prev_diag + cost;
+            prev_diag = row[j];
+            row[j] = deletion.min(insertion).min(substitution);
+        }
+    }
+
+    row[b.len()]
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use dbt_schemas::schemas::dbt_column::DbtColumn;
     use std::collections::BTreeMap;
+    use std::sync::Arc;
 
     #[test]
     fn test_column_merge_fills_description() {
         let mut a = ColumnProperty {
             name: "col_a".to_string(),
             description: None,
-            extras: BTreeMap::new(),
+            ..Default::default()
         };
         let mut b = ColumnProperty {
             name: "col_a".to_string(),
             description: Some("desc from b".to_string()),
-            extras: BTreeMap::new(),
+            ..Default::default()
         };
 
-        a.merge(&b);
+        a.merge_with_strategy(&b, MergeStrategy::Overwrite);
         assert_eq!(
             a.description.as_deref(),
             Some("desc from b"),
@@ -185,7 +1083,7 @@ mod tests {
         );
 
         b.description = Some("new desc from b".to_string());
-        a.merge(&b);
+        a.merge_with_strategy(&b, MergeStrategy::Overwrite);
         assert_eq!(
             a.description.as_deref(),
             Some("new desc from b"),
@@ -193,6 +1091,27 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_column_merge_appends_data_tests_without_duplicates() {
+        let mut a = ColumnProperty {
+            name: "col_a".to_string(),
+            data_tests: Some(vec!["unique".to_string()]),
+            ..Default::default()
+        };
+        let b = ColumnProperty {
+            name: "col_a".to_string(),
+            data_tests: Some(vec!["unique".to_string(), "not_null".to_string()]),
+            ..Default::default()
+        };
+
+        a.merge_with_strategy(&b, MergeStrategy::Overwrite);
+        assert_eq!(
+            a.data_tests,
+            Some(vec!["unique".to_string(), "not_null".to_string()]),
+            "merges in new tests without duplicating existing ones"
+        );
+    }
+
     #[test]
     fn test_model_merge() {
         let mut self_model = ModelProperty {
@@ -202,12 +1121,12 @@ mod tests {
                 ColumnProperty {
                     name: "c1".to_string(),
                     description: None,
-                    extras: BTreeMap::new(),
+                    ..Default::default()
                 },
                 ColumnProperty {
                     name: "c3".to_string(),
                     description: Some("c3 desc".to_string()),
-                    extras: BTreeMap::new(),
+                    ..Default::default()
                 },
             ],
             extras: BTreeMap::new(),
@@ -220,18 +1139,18 @@ mod tests {
                 ColumnProperty {
                     name: "c1".to_string(),
                     description: Some("c1 desc".to_string()),
-                    extras: BTreeMap::new(),
+                    ..Default::default()
                 },
                 ColumnProperty {
                     name: "c2".to_string(),
                     description: Some("c2 desc".to_string()),
-                    extras: BTreeMap::new(),
+                    ..Default::default()
                 },
             ],
             extras: BTreeMap::new(),
         };
 
-        self_model.merge(&other_model);
+        self_model.merge_with_strategy(&other_model, MergeStrategy::FillIfEmpty);
         // description should be filled
         assert_eq!(
             self_model.description.as_deref(),
@@ -284,14 +1203,14 @@ mod tests {
                 columns: vec![ColumnProperty {
                     name: "col_z".to_string(),
                     description: Some("z desc".to_string()),
-                    extras: BTreeMap::new(),
+                    ..Default::default()
                 }],
                 extras: BTreeMap::new(),
             }],
             extras: BTreeMap::new(),
         };
 
-        src_a.merge(&src_b);
+        src_a.merge_with_strategy(&src_b, MergeStrategy::FillIfEmpty);
         assert_eq!(src_a.description.as_deref(), Some("source desc"));
         let table = src_a
             .tables
@@ -303,9 +1222,260 @@ mod tests {
         assert_eq!(col.description.as_deref(), Some("z desc"));
     }
 
+    #[test]
+    fn extras_merge_reports_collision_and_fills_missing_key() {
+        let mut a: BTreeMap<String, dbt_serde_yaml::Value> =
+            dbt_serde_yaml::from_str("owner: alice").unwrap();
+        let b: BTreeMap<String, dbt_serde_yaml::Value> =
+            dbt_serde_yaml::from_str("owner: bob\nmeta: extra").unwrap();
+
+        let conflicts = a.merge(&b);
+        assert_eq!(conflicts.len(), 1, "only the differing key is reported");
+        assert_eq!(conflicts[0].path, "owner");
+        assert!(a.contains_key("meta"), "missing key is still filled in");
+    }
+
+    #[test]
+    fn merge_extras_with_strategy_overwrite_replaces_differing_key() {
+        let mut a: BTreeMap<String, dbt_serde_yaml::Value> =
+            dbt_serde_yaml::from_str("owner: alice").unwrap();
+        let b: BTreeMap<String, dbt_serde_yaml::Value> =
+            dbt_serde_yaml::from_str("owner: bob").unwrap();
+
+        merge_extras_with_strategy(&mut a, &b, MergeStrategy::Overwrite);
+        assert_eq!(
+            a.get("owner").and_then(|v| v.as_str()),
+            Some("bob"),
+            "Overwrite should let the incoming value win on a differing key, unlike the old unconditional fill-missing-only extras merge"
+        );
+    }
+
+    #[test]
+    fn column_merge_reports_description_conflict() {
+        let mut a = ColumnProperty {
+            name: "col_a".to_string(),
+            description: Some("self desc".to_string()),
+            ..Default::default()
+        };
+        let b = ColumnProperty {
+            name: "col_a".to_string(),
+            description: Some("other desc".to_string()),
+            ..Default::default()
+        };
+
+        let conflicts = a.merge(&b);
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].path, "description");
+        assert_eq!(conflicts[0].self_value, "self desc");
+        assert_eq!(conflicts[0].other_value, "other desc");
+        // the merge still goes through, `other` wins
+        assert_eq!(a.description.as_deref(), Some("other desc"));
+    }
+
+    #[test]
+    fn model_merge_prefixes_column_conflicts_with_column_name() {
+        let mut self_model = ModelProperty {
+            name: Some("model_1".to_string()),
+            description: None,
+            columns: vec![ColumnProperty {
+                name: "col_a".to_string(),
+                description: Some("self desc".to_string()),
+                ..Default::default()
+            }],
+            extras: BTreeMap::new(),
+        };
+        let other_model = ModelProperty {
+            name: Some("model_1".to_string()),
+            description: None,
+            columns: vec![ColumnProperty {
+                name: "col_a".to_string(),
+                description: Some("other desc".to_string()),
+                ..Default::default()
+            }],
+            extras: BTreeMap::new(),
+        };
+
+        let conflicts = self_model.merge(&other_model);
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].path, "col_a.description");
+    }
+
+    #[test]
+    fn source_merge_prefixes_conflicts_with_table_then_column_name() {
+        let mut src_a = SourceProperty {
+            name: "source_x".to_string(),
+            description: None,
+            tables: vec![ModelProperty {
+                name: Some("t1".to_string()),
+                description: None,
+                columns: vec![ColumnProperty {
+                    name: "col_z".to_string(),
+                    description: Some("self desc".to_string()),
+                    ..Default::default()
+                }],
+                extras: BTreeMap::new(),
+            }],
+            extras: BTreeMap::new(),
+        };
+        let src_b = SourceProperty {
+            name: "source_x".to_string(),
+            description: None,
+            tables: vec![ModelProperty {
+                name: Some("t1".to_string()),
+                description: None,
+                columns: vec![ColumnProperty {
+                    name: "col_z".to_string(),
+                    description: Some("other desc".to_string()),
+                    ..Default::default()
+                }],
+                extras: BTreeMap::new(),
+            }],
+            extras: BTreeMap::new(),
+        };
+
+        let conflicts = src_a.merge(&src_b);
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].path, "t1.col_z.description");
+    }
+
+    #[test]
+    fn model_property_from_manifest_differences_reports_full_column_changeset() {
+        let mut original = ManifestModel::default();
+        original.__common_attr__.name = "model_1".to_string();
+        original.__base_attr__.columns = vec![
+            Arc::new(DbtColumn {
+                name: "kept".to_string(),
+                description: Some("old desc".to_string()),
+                ..Default::default()
+            }),
+            Arc::new(DbtColumn {
+                name: "dropped".to_string(),
+                description: Some("going away".to_string()),
+                ..Default::default()
+            }),
+        ];
+
+        let mut updated = original.clone();
+        updated.__base_attr__.columns = vec![
+            Arc::new(DbtColumn {
+                name: "kept".to_string(),
+                description: Some("new desc".to_string()),
+                ..Default::default()
+            }),
+            Arc::new(DbtColumn {
+                name: "added".to_string(),
+                description: Some("brand new".to_string()),
+                ..Default::default()
+            }),
+        ];
+
+        let (property, diffs) = model_property_from_manifest_differences(&original, &updated)
+            .expect("column changes should be detected");
+
+        assert!(
+            property.columns.iter().any(|c| c.name == "kept"
+                && c.description.as_deref() == Some("new desc")),
+            "modified column patch carries the new description"
+        );
+        assert!(
+            property
+                .columns
+                .iter()
+                .any(|c| c.name == "added" && c.description.as_deref() == Some("brand new")),
+            "added column is included in the property patch"
+        );
+
+        assert!(diffs.iter().any(|d| matches!(
+            d,
+            ColumnDiff::Modified { name, description }
+                if name == "kept" && description.as_deref() == Some("new desc")
+        )));
+        assert!(
+            diffs
+                .iter()
+                .any(|d| matches!(d, ColumnDiff::Added(col) if col.name == "added"))
+        );
+        assert!(
+            diffs
+                .iter()
+                .any(|d| matches!(d, ColumnDiff::Removed(name) if name == "dropped"))
+        );
+    }
+
+    #[test]
+    fn model_property_from_manifest_differences_none_when_unchanged() {
+        let mut model = ManifestModel::default();
+        model.__common_attr__.name = "model_1".to_string();
+        model.__base_attr__.columns = vec![Arc::new(DbtColumn {
+            name: "col_a".to_string(),
+            description: Some("desc".to_string()),
+            ..Default::default()
+        })];
+
+        assert!(model_property_from_manifest_differences(&model, &model.clone()).is_none());
+    }
+
+    #[test]
+    fn normalize_migrates_legacy_tests_alias_and_stamps_current_version() {
+        let mut file: PropertyFile = dbt_serde_yaml::from_str(
+            r#"
+models:
+  - name: model_1
+    columns:
+      - name: col_a
+        tests:
+          - unique
+          - not_null
+"#,
+        )
+        .unwrap();
+        assert_eq!(file.version, 1, "no version key parses as the oldest version");
+
+        file.normalize();
+        assert_eq!(file.version, CURRENT_VERSION);
+
+        let col = &file.models.as_ref().unwrap()[0].columns[0];
+        assert_eq!(
+            col.data_tests,
+            Some(vec!["unique".to_string(), "not_null".to_string()]),
+            "legacy `tests:` alias is migrated into `data_tests`"
+        );
+        assert!(
+            !col.extras.contains_key("tests"),
+            "migrated key is removed from extras, not left duplicated"
+        );
+    }
+
+    #[test]
+    fn normalize_is_a_no_op_for_already_current_files() {
+        let mut file = PropertyFile {
+            version: CURRENT_VERSION,
+            models: Some(vec![ModelProperty {
+                name: Some("model_1".to_string()),
+                description: None,
+                columns: vec![ColumnProperty {
+                    name: "col_a".to_string(),
+                    data_tests: Some(vec!["unique".to_string()]),
+                    ..Default::default()
+                }],
+                extras: BTreeMap::new(),
+            }]),
+            sources: None,
+            extras: BTreeMap::new(),
+        };
+
+        file.normalize();
+        assert_eq!(file.version, CURRENT_VERSION);
+        assert_eq!(
+            file.models.unwrap()[0].columns[0].data_tests,
+            Some(vec!["unique".to_string()])
+        );
+    }
+
     #[test]
     fn find_model_mut_returns_mutable_reference() {
         let mut root = PropertyFile {
+            version: CURRENT_VERSION,
             models: Some(vec![ModelProperty {
                 name: Some("m_x".to_string()),
                 description: None,
@@ -321,4 +1491,222 @@ mod tests {
         let m2 = root.find_model_mut("m_x").unwrap();
         assert_eq!(m2.description.as_deref(), Some("new desc"));
     }
+
+    #[test]
+    fn formatting_detect_sniffs_crlf_and_missing_trailing_newline() {
+        let contents = "models:\r\n  - name: m1\r\n";
+        let (formatting, remainder) = PropertyFileFormatting::detect(contents);
+        assert_eq!(formatting.line_ending, LineEnding::Crlf);
+        assert!(formatting.trailing_newline);
+        assert_eq!(formatting.leading_comment, "");
+        assert_eq!(remainder, contents);
+
+        let no_trailing_newline = "models:\n  - name: m1";
+        let (formatting, _) = PropertyFileFormatting::detect(no_trailing_newline);
+        assert_eq!(formatting.line_ending, LineEnding::Lf);
+        assert!(!formatting.trailing_newline);
+    }
+
+    #[test]
+    fn formatting_detect_splits_off_leading_comment_block() {
+        let contents = "# Copyright Example Corp\n# SPDX-License-Identifier: MIT\n\nmodels:\n  - name: m1\n";
+        let (formatting, remainder) = PropertyFileFormatting::detect(contents);
+        assert_eq!(
+            formatting.leading_comment,
+            "# Copyright Example Corp\n# SPDX-License-Identifier: MIT\n\n"
+        );
+        assert_eq!(remainder, "models:\n  - name: m1\n");
+    }
+
+    #[test]
+    fn render_property_file_round_trips_formatting() {
+        let contents = "# header comment\r\nmodels:\r\n  - name: m1\r\n    columns: []\r\n";
+        let (formatting, remainder) = PropertyFileFormatting::detect(contents);
+        let mut doc: PropertyFile = dbt_serde_yaml::from_str(remainder).unwrap();
+        doc.normalize();
+
+        let rendered = render_property_file(&doc, &formatting).unwrap();
+        assert!(
+            rendered.starts_with("# header comment\r\n"),
+            "leading comment should be preserved verbatim: {rendered:?}"
+        );
+        assert!(
+            !rendered.contains('\n') || rendered.matches("\r\n").count() == rendered.matches('\n').count(),
+            "every newline should be paired with a preceding \\r: {rendered:?}"
+        );
+    }
+
+    #[test]
+    fn render_property_file_omits_trailing_newline_when_original_had_none() {
+        let contents = "models:\n  - name: m1\n    columns: []";
+        let (formatting, remainder) = PropertyFileFormatting::detect(contents);
+        let mut doc: PropertyFile = dbt_serde_yaml::from_str(remainder).unwrap();
+        doc.normalize();
+
+        let rendered = render_property_file(&doc, &formatting).unwrap();
+        assert!(
+            !rendered.ends_with('\n'),
+            "original file had no trailing newline: {rendered:?}"
+        );
+    }
+
+    #[test]
+    fn render_property_file_styled_reindents_to_configured_width() {
+        let contents = "models:\n  - name: m1\n    columns:\n      - name: c1\n";
+        let (formatting, remainder) = PropertyFileFormatting::detect(contents);
+        let mut doc: PropertyFile = dbt_serde_yaml::from_str(remainder).unwrap();
+        doc.normalize();
+
+        let style = crate::config::PropertiesFormat {
+            indent_width: 4,
+            ..crate::config::PropertiesFormat::default()
+        };
+        let rendered = render_property_file_styled(&doc, &formatting, &style).unwrap();
+
+        assert!(
+            rendered.contains("\n    - name: m1\n"),
+            "two-space levels should scale to four: {rendered:?}"
+        );
+        assert!(
+            rendered.contains("\n        name: c1\n") || rendered.ends_with("        name: c1"),
+            "nested column should scale to four levels of four spaces: {rendered:?}"
+        );
+    }
+
+    #[test]
+    fn render_property_file_styled_wraps_long_descriptions_as_folded_scalar() {
+        let long_desc = "word ".repeat(30);
+        let contents = format!("models:\n  - name: m1\n    description: \"{}\"\n    columns: []\n", long_desc.trim());
+        let (formatting, remainder) = PropertyFileFormatting::detect(&contents);
+        let mut doc: PropertyFile = dbt_serde_yaml::from_str(remainder).unwrap();
+        doc.normalize();
+
+        let style = crate::config::PropertiesFormat {
+            wrap_long_descriptions: true,
+            description_wrap_threshold: 20,
+            ..crate::config::PropertiesFormat::default()
+        };
+        let rendered = render_property_file_styled(&doc, &formatting, &style).unwrap();
+
+        assert!(
+            rendered.contains("description: >-\n"),
+            "long description should become a folded block scalar: {rendered:?}"
+        );
+        let reparsed: PropertyFile = dbt_serde_yaml::from_str(&rendered).unwrap();
+        assert_eq!(
+            reparsed.models.as_ref().unwrap()[0].description.as_deref(),
+            Some(long_desc.trim()),
+            "folding must not change the logical description value"
+        );
+    }
+
+    #[test]
+    fn render_property_file_styled_quotes_plain_scalars_when_always_double() {
+        let contents = "models:\n  - name: m1\n    description: unquoted text\n    columns: []\n";
+        let (formatting, remainder) = PropertyFileFormatting::detect(contents);
+        let mut doc: PropertyFile = dbt_serde_yaml::from_str(remainder).unwrap();
+        doc.normalize();
+
+        let style = crate::config::PropertiesFormat {
+            quote_policy: crate::config::QuotePolicy::AlwaysDouble,
+            ..crate::config::PropertiesFormat::default()
+        };
+        let rendered = render_property_file_styled(&doc, &formatting, &style).unwrap();
+
+        assert!(
+            rendered.contains("description: \"unquoted text\""),
+            "a bare string value should get double-quoted: {rendered:?}"
+        );
+        assert!(
+            rendered.contains("name: \"m1\""),
+            "every bare string value should get double-quoted, including `name`: {rendered:?}"
+        );
+    }
+
+    #[test]
+    fn render_property_file_defaults_to_unstyled_output() {
+        let contents = "models:\n  - name: m1\n    columns: []\n";
+        let (formatting, remainder) = PropertyFileFormatting::detect(contents);
+        let mut doc: PropertyFile = dbt_serde_yaml::from_str(remainder).unwrap();
+        doc.normalize();
+
+        assert_eq!(
+            render_property_file(&doc, &formatting).unwrap(),
+            render_property_file_styled(&doc, &formatting, &crate::config::PropertiesFormat::default())
+                .unwrap(),
+        );
+    }
+
+    #[test]
+    fn validate_extras_flags_typo_with_suggestion() {
+        let extras: BTreeMap<String, dbt_serde_yaml::Value> =
+            dbt_serde_yaml::from_str("descrption: oops").unwrap();
+        let file = PropertyFile {
+            version: CURRENT_VERSION,
+            models: Some(vec![ModelProperty {
+                name: Some("model_1".to_string()),
+                description: None,
+                columns: vec![],
+                extras,
+            }]),
+            sources: None,
+            extras: BTreeMap::new(),
+        };
+
+        let warnings = validate_extras(&file);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].path, "model_1");
+        assert_eq!(warnings[0].key, "descrption");
+        assert_eq!(warnings[0].suggestion.as_deref(), Some("description"));
+    }
+
+    #[test]
+    fn validate_extras_no_suggestion_when_nothing_close() {
+        let extras: BTreeMap<String, dbt_serde_yaml::Value> =
+            dbt_serde_yaml::from_str("totally_unrelated: 1").unwrap();
+        let file = PropertyFile {
+            version: CURRENT_VERSION,
+            models: Some(vec![ModelProperty {
+                name: Some("model_1".to_string()),
+                description: None,
+                columns: vec![],
+                extras,
+            }]),
+            sources: None,
+            extras: BTreeMap::new(),
+        };
+
+        let warnings = validate_extras(&file);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].suggestion, None);
+    }
+
+    #[test]
+    fn validate_extras_ignores_known_keys_and_checks_nested_levels() {
+        let model_extras: BTreeMap<String, dbt_serde_yaml::Value> =
+            dbt_serde_yaml::from_str("meta: {}").unwrap();
+        let col_extras: BTreeMap<String, dbt_serde_yaml::Value> =
+            dbt_serde_yaml::from_str("mispelled_tests: []").unwrap();
+
+        let file = PropertyFile {
+            version: CURRENT_VERSION,
+            models: Some(vec![ModelProperty {
+                name: Some("model_1".to_string()),
+                description: None,
+                columns: vec![ColumnProperty {
+                    name: "col_a".to_string(),
+                    extras: col_extras,
+                    ..Default::default()
+                }],
+                extras: model_extras,
+            }]),
+            sources: None,
+            extras: BTreeMap::new(),
+        };
+
+        let warnings = validate_extras(&file);
+        assert_eq!(warnings.len(), 1, "only the column-level key is unknown");
+        assert_eq!(warnings[0].path, "model_1.col_a");
+        assert_eq!(warnings[0].key, "mispelled_tests");
+    }
 }
@@ -1,18 +1,36 @@
 use super::WriteBackError;
 use crate::change_descriptors::{ModelChange, ModelChanges};
+use dbt_serde_yaml;
+use rayon::prelude::*;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use std::{
+    cell::RefCell,
     collections::BTreeMap,
-    io::Write,
+    io::{BufRead, BufReader, Write},
     path::Path,
-    process::{Command, Stdio},
+    process::{Child, ChildStdin, ChildStdout, Command, Stdio},
 };
 
+/// A single column's worth of metadata to sync into a properties file. Every field beyond
+/// `column_name` is the target value the manifest (or a freshly computed fix) wants that
+/// attribute to hold -- the helper is expected to reconcile it against whatever's already
+/// on disk additively (e.g. adding a missing `not_null` test, filling in `data_type`,
+/// merging new `meta` keys) rather than clobbering user-authored entries it doesn't
+/// recognize, same contract `ColumnProperty::merge_with_strategy` applies on the Rust side.
 #[derive(Debug, Clone, Serialize)]
 struct PythonColumnChange {
     column_name: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     new_description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    new_data_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    data_tests: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tags: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    meta: Option<BTreeMap<String, dbt_serde_yaml::Value>>,
 }
 
 /// Single model update within a batch request
@@ -25,7 +43,7 @@ struct ModelUpdate {
 }
 
 /// Batch request: single file, multiple models
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Serialize)]
 struct PythonBatchRequest {
     patch_path: std::path::PathBuf,
     models: Vec<ModelUpdate>,
@@ -36,7 +54,7 @@ struct PythonBatchResponse {
     results: BTreeMap<String, Vec<String>>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Serialize)]
 struct LayoutRequest {
     current_patch: String,
     expected_patch: String,
@@ -58,6 +76,156 @@ struct LayoutResponse {
     mutated: bool,
 }
 
+/// Envelope wrapping a request line with a monotonically increasing `request_id`, so the
+/// matching response line can be checked for alignment. `#[serde(flatten)]` keeps the
+/// wire format identical to the un-enveloped request plus one extra field.
+#[derive(Debug, Serialize)]
+struct RequestEnvelope<T> {
+    request_id: u64,
+    #[serde(flatten)]
+    body: T,
+}
+
+#[derive(Debug, Deserialize)]
+struct ResponseEnvelope<T> {
+    request_id: u64,
+    #[serde(flatten)]
+    body: T,
+}
+
+/// A long-lived `python3` process speaking newline-delimited JSON, one request per line,
+/// kept alive across every file in a batch instead of being spawned per call. Spawning a
+/// fresh interpreter (plus importing `ruamel`) costs 200-400ms, which dominates runtime on
+/// projects with hundreds of patch files; this amortizes that cost across the whole run.
+struct PythonWorker {
+    helper_path: std::path::PathBuf,
+    child: Child,
+    stdin: ChildStdin,
+    stdout: BufReader<ChildStdout>,
+    next_request_id: u64,
+}
+
+impl PythonWorker {
+    fn spawn(helper_path: &Path) -> Result<Self, WriteBackError> {
+        let (child, stdin, stdout) = Self::spawn_child(helper_path)?;
+        Ok(Self {
+            helper_path: helper_path.to_path_buf(),
+            child,
+            stdin,
+            stdout,
+            next_request_id: 0,
+        })
+    }
+
+    fn spawn_child(helper_path: &Path) -> Result<(Child, ChildStdin, BufReader<ChildStdout>), WriteBackError> {
+        let mut command = Command::new("python3");
+        command.arg(helper_path);
+        command.stdin(Stdio::piped());
+        command.stdout(Stdio::piped());
+        command.stderr(Stdio::piped());
+
+        let mut child = command.spawn()?;
+        let stdin = child.stdin.take().expect("stdin was piped");
+        let stdout = BufReader::new(child.stdout.take().expect("stdout was piped"));
+        Ok((child, stdin, stdout))
+    }
+
+    /// Kills the current process (if it's even still alive) and replaces it with a fresh
+    /// one, resetting the request counter -- used to recover once after the worker crashes
+    /// or its output otherwise desyncs.
+    fn respawn(&mut self) -> Result<(), WriteBackError> {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+        let (child, stdin, stdout) = Self::spawn_child(&self.helper_path)?;
+        self.child = child;
+        self.stdin = stdin;
+        self.stdout = stdout;
+        self.next_request_id = 0;
+        Ok(())
+    }
+
+    /// Sends `body` as one NDJSON request line and reads back exactly one response line.
+    /// If the worker has died or its response comes back out of order, it's respawned once
+    /// and the request retried before the failure is surfaced to the caller.
+    fn call<Req, Resp>(&mut self, body: Req) -> Result<Resp, WriteBackError>
+    where
+        Req: Serialize + Clone,
+        Resp: DeserializeOwned,
+    {
+        match self.try_call(body.clone()) {
+            Ok(response) => Ok(response),
+            Err(_) => {
+                self.respawn()?;
+                self.try_call(body)
+            }
+        }
+    }
+
+    fn try_call<Req, Resp>(&mut self, body: Req) -> Result<Resp, WriteBackError>
+    where
+        Req: Serialize,
+        Resp: DeserializeOwned,
+    {
+        let request_id = self.next_request_id;
+        self.next_request_id += 1;
+
+        let mut line = serde_json::to_string(&RequestEnvelope { request_id, body })?;
+        line.push('\n');
+        self.stdin.write_all(line.as_bytes())?;
+        self.stdin.flush()?;
+
+        let mut response_line = String::new();
+        let bytes_read = self.stdout.read_line(&mut response_line)?;
+        if bytes_read == 0 {
+            return Err(WriteBackError::Io(std::io::Error::new(
+                std::io::ErrorKind::UnexpectedEof,
+                format!("python helper at {} closed its stdout", self.helper_path.display()),
+            )));
+        }
+
+        let response: ResponseEnvelope<Resp> = serde_json::from_str(&response_line)
+            .map_err(WriteBackError::ResponseParseFailure)?;
+        if response.request_id != request_id {
+            return Err(WriteBackError::PythonWorkerDesync {
+                expected: request_id,
+                actual: response.request_id,
+            });
+        }
+
+        Ok(response.body)
+    }
+
+    /// Closes stdin (so the helper's read loop sees EOF and exits cleanly) and waits for it.
+    fn shutdown(self) {
+        let PythonWorker {
+            mut child, stdin, ..
+        } = self;
+        drop(stdin);
+        let _ = child.wait();
+    }
+}
+
+thread_local! {
+    /// One batch-helper process per rayon worker thread, reused across every file
+    /// dispatched to that thread -- the NDJSON protocol is strictly sequential, so a
+    /// single `PythonWorker` can't be shared across threads the way a single-threaded
+    /// run reuses it across files.
+    static BATCH_WORKER: RefCell<Option<PythonWorker>> = const { RefCell::new(None) };
+    /// Same idea as `BATCH_WORKER`, for the layout-move helper.
+    static LAYOUT_WORKER: RefCell<Option<PythonWorker>> = const { RefCell::new(None) };
+}
+
+/// Shuts down whichever worker(s) this thread spawned, if any. Called once per pool
+/// thread via `ThreadPool::broadcast` after every file has been processed.
+fn shutdown_thread_local_workers() {
+    if let Some(worker) = BATCH_WORKER.with(|cell| cell.borrow_mut().take()) {
+        worker.shutdown();
+    }
+    if let Some(worker) = LAYOUT_WORKER.with(|cell| cell.borrow_mut().take()) {
+        worker.shutdown();
+    }
+}
+
 pub fn apply_with_python(
     project_root: &Path,
     changes: &BTreeMap<String, ModelChanges>,
@@ -69,16 +237,283 @@ pub fn apply_with_python(
     use crate::writeback::changes::group_changes_by_file;
 
     let helper_path = resolve_helper_path()?;
-    let mut layout_helper_path: Option<std::path::PathBuf> = None;
 
+    // Group changes by file for batching: one Python process call per file. Each
+    // file's worth of work (building batch_updates, any layout move, the batch helper
+    // call) is independent of every other file, so it's dispatched across a dedicated
+    // rayon pool sized by the number of available cores instead of run sequentially --
+    // the helper process round-trip is the bottleneck, so wall-clock scales down with
+    // the number of worker threads (and therefore helper processes) available.
+    let grouped_changes: Vec<(std::path::PathBuf, Vec<&ModelChanges>)> =
+        group_changes_by_file(changes).into_iter().collect();
+
+    let worker_count = std::thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1)
+        .min(grouped_changes.len().max(1));
+
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(worker_count)
+        .build()
+        .expect("failed to build apply_with_python's rayon thread pool");
+
+    let per_file_results: Vec<Vec<(String, Vec<String>)>> = pool.install(|| {
+        grouped_changes
+            .par_iter()
+            .map(|(_patch_path, models_for_file)| {
+                apply_file_group(project_root, &helper_path, models_for_file)
+            })
+            .collect::<Result<Vec<_>, WriteBackError>>()
+    })?;
+    pool.broadcast(|_| shutdown_thread_local_workers());
+
+    // Merge deterministically: the per-file dispatch above no longer runs in
+    // `grouped_changes`'s original order, so sort on `model_id` to keep output stable
+    // for tests and diffs.
+    let mut results: Vec<(String, Vec<String>)> = per_file_results.into_iter().flatten().collect();
+    results.sort_by(|a, b| a.0.cmp(&b.0));
+    Ok(results)
+}
+
+/// Applies every model update destined for a single patch file: layout moves first
+/// (via the thread-local layout worker), then one batched description/column-update
+/// call (via the thread-local batch worker). Runs on a rayon pool worker thread, so
+/// both workers are looked up (and lazily spawned) from this thread's `thread_local!`.
+fn apply_file_group(
+    project_root: &Path,
+    helper_path: &Path,
+    models_for_file: &[&ModelChanges],
+) -> Result<Vec<(String, Vec<String>)>, WriteBackError> {
     let mut results = Vec::new();
 
-    // Group changes by file for batching: one Python process call per file
+    // First pass: handle file moves and layout changes, collect model updates
+    let mut batch_updates: Vec<(String, ModelUpdate)> = Vec::new();
+    let mut resolved_path: Option<std::path::PathBuf> = None;
+
+    for model_changes in models_for_file {
+        let patch_path = model_changes.patch_path.as_ref().ok_or_else(|| {
+            WriteBackError::PatchPathMissing {
+                model_id: model_changes.model_id.clone(),
+            }
+        })?;
+
+        let mut current_path = if patch_path.is_absolute() {
+            patch_path.clone()
+        } else {
+            project_root.join(patch_path)
+        };
+
+        // Set resolved_path on first iteration
+        if resolved_path.is_none() {
+            resolved_path = Some(current_path.clone());
+        }
+
+        let model_name = extract_model_name(&model_changes.model_id);
+        let mut model_description_change: Option<String> = None;
+        let mut property_payload: Option<&crate::writeback::properties::ModelProperty> = None;
+
+        // Process changes for this model
+        for change in &model_changes.changes {
+            match change {
+                ModelChange::MovePropertiesFile {
+                    patch_path,
+                    new_path,
+                    ..
+                } => {
+                    let current_patch = patch_path.as_ref().ok_or_else(|| {
+                        WriteBackError::PatchPathMissing {
+                            model_id: model_changes.model_id.clone(),
+                        }
+                    })?;
+
+                    let resolved_current = if current_patch.is_absolute() {
+                        current_patch.clone()
+                    } else {
+                        project_root.join(current_patch)
+                    };
+
+                    let resolved_expected = if new_path.is_absolute() {
+                        new_path.clone()
+                    } else {
+                        project_root.join(new_path)
+                    };
+
+                    if resolved_current != resolved_expected {
+                        let response: LayoutResponse = LAYOUT_WORKER.with(|cell| {
+                            let mut worker = cell.borrow_mut();
+                            if worker.is_none() {
+                                *worker = Some(PythonWorker::spawn(&resolve_layout_helper_path()?)?);
+                            }
+                            worker.as_mut().expect("layout worker set").call(LayoutRequest::new(
+                                &resolved_current,
+                                &resolved_expected,
+                                model_name,
+                            ))
+                        })?;
+                        let _mutated = response.mutated;
+                    }
+
+                    current_path = resolved_expected;
+                    resolved_path = Some(current_path.clone());
+                }
+                ModelChange::ChangePropertiesFile {
+                    patch_path,
+                    property,
+                    ..
+                } => {
+                    if patch_path.is_none() {
+                        eprintln!(
+                            "Skipping unsupported model-level change for `{}` in python writeback",
+                            model_changes.model_id
+                        );
+                        continue;
+                    }
+                    if let Some(prop) = property {
+                        if let Some(desc) = prop.description.as_ref() {
+                            model_description_change = Some(desc.clone());
+                        }
+                        property_payload = Some(prop);
+                    }
+                }
+                other => {
+                    return Err(WriteBackError::UnsupportedModelChange {
+                        model_id: model_changes.model_id.clone(),
+                        change: format!("{other:?}"),
+                    });
+                }
+            }
+        }
+
+        // Collect column changes for this model
+        let mut column_changes: Vec<PythonColumnChange> = Vec::new();
+        if let Some(prop) = property_payload {
+            for column in &prop.columns {
+                column_changes.push(PythonColumnChange {
+                    column_name: column.name.clone(),
+                    new_description: column.description.clone(),
+                    new_data_type: column.data_type.clone(),
+                    data_tests: column.data_tests.clone(),
+                    tags: column.tags.clone(),
+                    meta: column.meta.clone(),
+                });
+            }
+        } else if !model_changes.column_changes.is_empty() {
+            for column_name in model_changes.column_changes.keys() {
+                column_changes.push(PythonColumnChange {
+                    column_name: column_name.clone(),
+                    new_description: None,
+                    new_data_type: None,
+                    data_tests: None,
+                    tags: None,
+                    meta: None,
+                });
+            }
+        }
+
+        // Add to batch if there are changes to apply
+        if !column_changes.is_empty() || model_description_change.is_some() {
+            batch_updates.push((
+                model_changes.model_id.clone(),
+                ModelUpdate {
+                    model_name: model_name.to_string(),
+                    column_changes,
+                    model_description: model_description_change,
+                },
+            ));
+        } else if !model_changes.changes.is_empty() {
+            // Some changes were processed (e.g., moves, layout) but nothing to send to Python
+            results.push((model_changes.model_id.clone(), Vec::new()));
+        }
+    }
+
+    // Single batch call to Python for all models in this file
+    if let Some(patch_path) = resolved_path
+        && !batch_updates.is_empty()
+    {
+        let model_updates: Vec<ModelUpdate> = batch_updates
+            .iter()
+            .map(|(_, update)| update.clone())
+            .collect();
+
+        let request = PythonBatchRequest {
+            patch_path,
+            models: model_updates,
+        };
+
+        let response: PythonBatchResponse = BATCH_WORKER.with(|cell| {
+            let mut worker = cell.borrow_mut();
+            if worker.is_none() {
+                *worker = Some(PythonWorker::spawn(helper_path)?);
+            }
+            worker.as_mut().expect("batch worker set").call(request)
+        })?;
+
+        // Map responses back to model IDs
+        for (model_id, _) in batch_updates {
+            let model_name = extract_model_name(&model_id).to_string();
+            let updated_cols = response
+                .results
+                .get(&model_name)
+                .cloned()
+                .unwrap_or_default();
+            results.push((model_id, updated_cols));
+        }
+    }
+
+    Ok(results)
+}
+
+/// A single model's planned edits within [`plan_with_python`]'s output: the same
+/// information that would go into a `PythonBatchRequest`/`LayoutRequest`, but collected
+/// for review instead of sent to a helper process.
+#[derive(Debug, Serialize)]
+struct ModelPlan {
+    model_id: String,
+    model_name: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    column_changes: Vec<PythonColumnChange>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    model_description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    move_properties_file: Option<PlannedMove>,
+}
+
+#[derive(Debug, Serialize)]
+struct PlannedMove {
+    current_patch: std::path::PathBuf,
+    expected_patch: std::path::PathBuf,
+    would_mutate: bool,
+}
+
+#[derive(Debug, Serialize)]
+struct FilePlan {
+    patch_path: std::path::PathBuf,
+    models: Vec<ModelPlan>,
+}
+
+#[derive(Debug, Serialize)]
+struct WritebackPlan {
+    files: Vec<FilePlan>,
+}
+
+/// Walks the same `group_changes_by_file` logic as `apply_with_python`, but never invokes
+/// a python helper or touches disk: it builds every batch/layout request writeback would
+/// have sent and serializes them into one JSON document, so a user (or CI) gets a
+/// reviewable, diff-able preview of exactly what `--fix` would rewrite. The `would_mutate`
+/// flag mirrors `LayoutResponse::mutated`, but is predicted on the Rust side (a move is
+/// only planned, and therefore always "would mutate", when `resolved_current` differs
+/// from `resolved_expected`) since there's no helper round-trip to ask.
+pub fn plan_with_python(
+    project_root: &Path,
+    changes: &BTreeMap<String, ModelChanges>,
+) -> Result<String, WriteBackError> {
+    use crate::writeback::changes::group_changes_by_file;
+
+    let mut files = Vec::new();
     let grouped_changes = group_changes_by_file(changes);
 
     for (_patch_path, models_for_file) in grouped_changes {
-        // First pass: handle file moves and layout changes, collect model updates
-        let mut batch_updates: Vec<(String, ModelUpdate)> = Vec::new();
+        let mut models = Vec::new();
         let mut resolved_path: Option<std::path::PathBuf> = None;
 
         for model_changes in &models_for_file {
@@ -94,7 +529,6 @@ pub fn apply_with_python(
                 project_root.join(patch_path)
             };
 
-            // Set resolved_path on first iteration
             if resolved_path.is_none() {
                 resolved_path = Some(current_path.clone());
             }
@@ -102,8 +536,8 @@ pub fn apply_with_python(
             let model_name = extract_model_name(&model_changes.model_id);
             let mut model_description_change: Option<String> = None;
             let mut property_payload: Option<&crate::writeback::properties::ModelProperty> = None;
+            let mut move_plan: Option<PlannedMove> = None;
 
-            // Process changes for this model
             for change in &model_changes.changes {
                 match change {
                     ModelChange::MovePropertiesFile {
@@ -122,26 +556,19 @@ pub fn apply_with_python(
                         } else {
                             project_root.join(current_patch)
                         };
-
                         let resolved_expected = if new_path.is_absolute() {
                             new_path.clone()
                         } else {
                             project_root.join(new_path)
                         };
 
-                        if resolved_current != resolved_expected {
-                            if layout_helper_path.is_none() {
-                                layout_helper_path = Some(resolve_layout_helper_path()?);
-                            }
-                            let helper = layout_helper_path.as_ref().expect("layout helper set");
-                            let _mutated = invoke_layout_helper(
-                                helper,
-                                LayoutRequest::new(
-                                    &resolved_current,
-                                    &resolved_expected,
-                                    model_name,
-                                ),
-                            )?;
+                        let would_mutate = resolved_current != resolved_expected;
+                        if would_mutate {
+                            move_plan = Some(PlannedMove {
+                                current_patch: resolved_current,
+                                expected_patch: resolved_expected.clone(),
+                                would_mutate,
+                            });
                         }
 
                         current_path = resolved_expected;
@@ -153,10 +580,6 @@ pub fn apply_with_python(
                         ..
                     } => {
                         if patch_path.is_none() {
-                            eprintln!(
-                                "Skipping unsupported model-level change for `{}` in python writeback",
-                                model_changes.model_id
-                            );
                             continue;
                         }
                         if let Some(prop) = property {
@@ -175,13 +598,16 @@ pub fn apply_with_python(
                 }
             }
 
-            // Collect column changes for this model
             let mut column_changes: Vec<PythonColumnChange> = Vec::new();
             if let Some(prop) = property_payload {
                 for column in &prop.columns {
                     column_changes.push(PythonColumnChange {
                         column_name: column.name.clone(),
                         new_description: column.description.clone(),
+                        new_data_type: column.data_type.clone(),
+                        data_tests: column.data_tests.clone(),
+                        tags: column.tags.clone(),
+                        meta: column.meta.clone(),
                     });
                 }
             } else if !model_changes.column_changes.is_empty() {
@@ -189,56 +615,36 @@ pub fn apply_with_python(
                     column_changes.push(PythonColumnChange {
                         column_name: column_name.clone(),
                         new_description: None,
+                        new_data_type: None,
+                        data_tests: None,
+                        tags: None,
+                        meta: None,
                     });
                 }
             }
 
-            // Add to batch if there are changes to apply
-            if !column_changes.is_empty() || model_description_change.is_some() {
-                batch_updates.push((
-                    model_changes.model_id.clone(),
-                    ModelUpdate {
-                        model_name: model_name.to_string(),
-                        column_changes,
-                        model_description: model_description_change,
-                    },
-                ));
-            } else if !model_changes.changes.is_empty() {
-                // Some changes were processed (e.g., moves, layout) but nothing to send to Python
-                results.push((model_changes.model_id.clone(), Vec::new()));
+            if !column_changes.is_empty()
+                || model_description_change.is_some()
+                || move_plan.is_some()
+            {
+                models.push(ModelPlan {
+                    model_id: model_changes.model_id.clone(),
+                    model_name: model_name.to_string(),
+                    column_changes,
+                    model_description: model_description_change,
+                    move_properties_file: move_plan,
+                });
             }
         }
 
-        // Single batch call to Python for all models in this file
         if let Some(patch_path) = resolved_path
-            && !batch_updates.is_empty()
+            && !models.is_empty()
         {
-            let model_updates: Vec<ModelUpdate> = batch_updates
-                .iter()
-                .map(|(_, update)| update.clone())
-                .collect();
-
-            let request = PythonBatchRequest {
-                patch_path,
-                models: model_updates,
-            };
-
-            let response = invoke_python_batch_helper(&helper_path, &request)?;
-
-            // Map responses back to model IDs
-            for (model_id, _) in batch_updates {
-                let model_name = extract_model_name(&model_id).to_string();
-                let updated_cols = response
-                    .results
-                    .get(&model_name)
-                    .cloned()
-                    .unwrap_or_default();
-                results.push((model_id, updated_cols));
-            }
+            files.push(FilePlan { patch_path, models });
         }
     }
 
-    Ok(results)
+    serde_json::to_string_pretty(&WritebackPlan { files }).map_err(WriteBackError::SerializeFailure)
 }
 
 fn resolve_helper_path() -> Result<std::path::PathBuf, WriteBackError> {
@@ -303,68 +709,6 @@ fn resolve_layout_helper_path() -> Result<std::path::PathBuf, WriteBackError> {
     Err(WriteBackError::HelperMissing(fallback))
 }
 
-fn invoke_layout_helper(
-    helper_path: &Path,
-    request: LayoutRequest,
-) -> Result<bool, WriteBackError> {
-    let mut command = Command::new("python3");
-    command.arg(helper_path);
-    command.stdin(Stdio::piped());
-    command.stdout(Stdio::piped());
-    command.stderr(Stdio::piped());
-
-    let mut child = command.spawn()?;
-
-    if let Some(mut stdin) = child.stdin.take() {
-        let json = serde_json::to_vec(&request)?;
-        stdin.write_all(&json)?;
-    }
-
-    let output = child.wait_with_output()?;
-
-    if !output.status.success() {
-        let status = output.status.code().unwrap_or(-1);
-        let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
-        return Err(WriteBackError::PythonFailure { status, stderr });
-    }
-
-    let response: LayoutResponse =
-        serde_json::from_slice(&output.stdout).map_err(WriteBackError::ResponseParseFailure)?;
-
-    Ok(response.mutated)
-}
-
-fn invoke_python_batch_helper(
-    helper_path: &Path,
-    request: &PythonBatchRequest,
-) -> Result<PythonBatchResponse, WriteBackError> {
-    let mut command = Command::new("python3");
-    command.arg(helper_path);
-    command.stdin(Stdio::piped());
-    command.stdout(Stdio::piped());
-    command.stderr(Stdio::piped());
-
-    let mut child = command.spawn()?;
-
-    if let Some(mut stdin) = child.stdin.take() {
-        let json = serde_json::to_vec(request)?;
-        stdin.write_all(&json)?;
-    }
-
-    let output = child.wait_with_output()?;
-
-    if !output.status.success() {
-        let status = output.status.code().unwrap_or(-1);
-        let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
-        return Err(WriteBackError::PythonFailure { status, stderr });
-    }
-
-    let response: PythonBatchResponse =
-        serde_json::from_slice(&output.stdout).map_err(WriteBackError::ResponseParseFailure)?;
-
-    Ok(response)
-}
-
 fn extract_model_name(unique_id: &str) -> &str {
     unique_id.rsplit('.').next().unwrap_or(unique_id)
 }
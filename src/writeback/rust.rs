@@ -1,25 +1,121 @@
 use super::WriteBackError;
 use crate::change_descriptors::ModelChange;
 use crate::check::ModelChanges;
+use crate::graph::DbtGraph;
 use crate::writeback::changes::group_changes_by_file;
-use crate::writeback::properties::{ModelProperty, PropertyFile};
+use crate::writeback::fs::{DryRunFs, DryRunOp, Fs};
+use crate::writeback::properties;
+use crate::writeback::properties::{Merge, ModelProperty, PropertiesFormat, PropertyFile};
+use crate::writeback::splice;
 use dbt_serde_yaml;
 use std::collections::BTreeMap;
-use std::fs;
 use std::path::{Path, PathBuf};
 
+/// Collision policy for the move operations below, mirroring Zed's `RenameOptions`/
+/// `CreateOptions { overwrite, ignore_if_exists }`. The default (`overwrite: false`)
+/// fails a move closed with `WriteBackError::DestinationConflict` rather than silently
+/// clobbering a destination `.sql`/`.yml` file or merging into a property file that
+/// already defines the model being moved.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MoveOptions {
+    pub overwrite: bool,
+}
+
+/// Applies `changes` to the project's `.sql`/`.yml` files with an all-or-nothing
+/// guarantee: the batch is first planned against `fs` through a [`DryRunFs`] (so nothing
+/// real is touched yet), then the planned operations are committed one at a time, each
+/// recorded in an undo log as it succeeds. If any operation in the commit phase fails
+/// (permission denied, disk full, ...), every already-applied operation is rolled back
+/// before the error is returned, so a mid-batch failure never leaves the project
+/// half-edited.
 pub fn apply_with_rust(
+    fs: &dyn Fs,
     project_root: &Path,
     changes: &BTreeMap<String, ModelChanges>,
+    options: MoveOptions,
+    style: &properties::PropertiesFormat,
+    graph: &DbtGraph,
 ) -> Result<Vec<(String, Vec<String>)>, WriteBackError> {
     if changes.is_empty() {
         return Ok(Vec::new());
     }
 
-    let mut results = Vec::new();
+    let staging = DryRunFs::new(fs);
+    let results = plan_rust_writeback(&staging, project_root, changes, options, style, graph)?;
+    commit_ops(fs, staging.into_ops())?;
+    Ok(results)
+}
+
+/// Computes what `apply_with_rust` would do without touching `fs` for real -- `fs` here is
+/// typically a [`DryRunFs`] wrapping the real filesystem, so reads see the true
+/// pre-transaction state while writes/renames/removals are only recorded.
+fn plan_rust_writeback(
+    fs: &dyn Fs,
+    project_root: &Path,
+    changes: &BTreeMap<String, ModelChanges>,
+    options: MoveOptions,
+    style: &properties::PropertiesFormat,
+    graph: &DbtGraph,
+) -> Result<Vec<(String, Vec<String>)>, WriteBackError> {
+    Ok(
+        plan_rust_writeback_grouped(fs, project_root, changes, options, style, graph)?
+            .into_iter()
+            .flat_map(|group| group.results)
+            .collect(),
+    )
+}
+
+/// Orders `grouped_changes` (and, within each group, its models) parents-first per
+/// `graph`'s [`DbtGraph::topo_order`], so that a column merge relying on transitive
+/// description inheritance sees its parent's freshly written description within this
+/// same writeback run rather than depending on incidental `BTreeMap` ordering. A model
+/// missing from `graph` (or a graph with a cycle, which callers should have already
+/// rejected via `reject_cyclic_lineage`) sorts last, keeping its original relative order.
+fn sort_changes_topologically<'a>(
+    grouped_changes: BTreeMap<PathBuf, Vec<&'a ModelChanges>>,
+    graph: &DbtGraph,
+) -> Vec<(PathBuf, Vec<&'a ModelChanges>)> {
+    let rank: BTreeMap<String, usize> = graph
+        .topo_order()
+        .map(|order| order.into_iter().enumerate().map(|(i, id)| (id, i)).collect())
+        .unwrap_or_default();
+    let rank_of = |model_id: &str| rank.get(model_id).copied().unwrap_or(usize::MAX);
+
+    let mut grouped_changes: Vec<(PathBuf, Vec<&ModelChanges>)> = grouped_changes.into_iter().collect();
+    for (_, models) in &mut grouped_changes {
+        models.sort_by_key(|mc| rank_of(&mc.model_id));
+    }
+    grouped_changes.sort_by_key(|(_, models)| {
+        models.iter().map(|mc| rank_of(&mc.model_id)).min().unwrap_or(usize::MAX)
+    });
+
+    grouped_changes
+}
+
+/// One file-group's outcome from [`plan_rust_writeback_grouped`]: its final resolved path
+/// (after any moves/normalizes) and the per-model column results for the models that
+/// belong to it, in the same shape `apply_with_rust`'s flattened result `Vec` returns.
+pub(super) struct GroupedFilePlan {
+    pub path: PathBuf,
+    pub results: Vec<(String, Vec<String>)>,
+}
+
+/// Same planning pass as `plan_rust_writeback`, but keeps each file group's results (and
+/// final resolved path) separate instead of flattening them, so a caller building a
+/// diff-able preview (see `writeback::plan`) can tell which models/columns a given file's
+/// diff belongs to.
+pub(super) fn plan_rust_writeback_grouped(
+    fs: &dyn Fs,
+    project_root: &Path,
+    changes: &BTreeMap<String, ModelChanges>,
+    options: MoveOptions,
+    style: &properties::PropertiesFormat,
+    graph: &DbtGraph,
+) -> Result<Vec<GroupedFilePlan>, WriteBackError> {
+    let mut groups = Vec::new();
 
     // Group changes by file for efficient batching: one read/write per file instead of per model
-    let grouped_changes = group_changes_by_file(changes);
+    let grouped_changes = sort_changes_topologically(group_changes_by_file(changes), graph);
 
     for (patch_path, models_for_file) in grouped_changes {
         let mut resolved_path = if patch_path.is_absolute() {
@@ -29,8 +125,26 @@ pub fn apply_with_rust(
         };
 
         // Single read per file
-        let mut docs = read_property_file(&resolved_path)?;
+        let (mut docs, formatting) = read_property_file(fs, &resolved_path)?;
+
+        // Before falling back to the full deserialize/merge/render round trip (which is
+        // always correct but normalizes quoting, can reorder keys, and drops comments),
+        // try a format-preserving in-place splice -- see `try_splice_file` for exactly
+        // what it does and does not cover.
+        if *style == PropertiesFormat::default() && fs.exists(&resolved_path) {
+            let raw_source = fs.read_to_string(&resolved_path)?;
+            if let Some((spliced, splice_results)) = try_splice_file(&raw_source, &docs, &models_for_file) {
+                fs.write(&resolved_path, &spliced)?;
+                groups.push(GroupedFilePlan {
+                    path: resolved_path,
+                    results: splice_results,
+                });
+                continue;
+            }
+        }
+
         let mut file_mutated = false;
+        let mut results = Vec::new();
 
         // Apply all changes for this file
         for model_changes in models_for_file {
@@ -63,12 +177,15 @@ pub fn apply_with_rust(
                         };
 
                         let mutated = move_model_property(
+                            fs,
                             &mut docs,
                             project_root,
                             model_id,
                             model_name,
                             patch_path,
                             new_path,
+                            options,
+                            style,
                         )?;
 
                         resolved_path = expected_path;
@@ -87,7 +204,14 @@ pub fn apply_with_rust(
                         };
 
                         if let Some(existing) = docs.find_model_mut(model_name) {
-                            existing.merge(prop);
+                            // The freshly computed fix wins, but a populated-but-differing
+                            // field on disk is worth flagging rather than overwriting silently.
+                            for conflict in existing.merge(prop) {
+                                eprintln!(
+                                    "warning: {model_name}.{} conflict during writeback: {:?} vs {:?}",
+                                    conflict.path, conflict.self_value, conflict.other_value
+                                );
+                            }
                         } else {
                             let mut new_prop = prop.clone();
                             if new_prop.name.is_none() {
@@ -103,6 +227,7 @@ pub fn apply_with_rust(
                         // The check phase already wrote the properties file; nothing to do.
                     }
                     ModelChange::MoveModelFile {
+                        model_name,
                         patch_path,
                         new_path,
                         ..
@@ -123,10 +248,47 @@ pub fn apply_with_rust(
                         } else {
                             project_root.join(new_path)
                         };
+                        if fs.exists(&dst) && !options.overwrite {
+                            return Err(WriteBackError::DestinationConflict {
+                                model_id: model_changes.model_id.clone(),
+                                model_name: model_name.clone(),
+                            });
+                        }
                         if let Some(parent) = dst.parent() {
-                            std::fs::create_dir_all(parent)?;
+                            fs.create_dir_all(parent)?;
+                        }
+                        fs.rename(&src, &dst)?;
+                    }
+                    ModelChange::NormalizePropertiesLayout {
+                        model_id,
+                        model_name,
+                        current_patch,
+                        expected_patch,
+                        ..
+                    } => {
+                        let expected_path = if expected_patch.is_absolute() {
+                            expected_patch.clone()
+                        } else {
+                            project_root.join(expected_patch)
+                        };
+
+                        let mutated = normalize_properties_layout(
+                            fs,
+                            &mut docs,
+                            project_root,
+                            model_id,
+                            model_name,
+                            current_patch,
+                            expected_patch,
+                            options,
+                            style,
+                        )?;
+
+                        resolved_path = expected_path;
+                        if mutated {
+                            file_mutated = true;
+                            updated_columns.push(format!("@model:{}", model_name));
                         }
-                        std::fs::rename(&src, &dst)?;
                     }
                 }
             }
@@ -149,29 +311,211 @@ pub fn apply_with_rust(
         // Single write per file after all changes for this file are applied
         if file_mutated {
             if property_file_is_empty(&docs) {
-                if resolved_path.exists() {
-                    std::fs::remove_file(&resolved_path)?;
+                if fs.exists(&resolved_path) {
+                    fs.remove_file(&resolved_path)?;
                 }
             } else {
-                let out_str = dbt_serde_yaml::to_string(&docs)?;
+                let out_str = properties::render_property_file_styled(&docs, &formatting, style)?;
                 if let Some(parent) = resolved_path.parent() {
-                    std::fs::create_dir_all(parent)?;
+                    fs.create_dir_all(parent)?;
                 }
-                std::fs::write(&resolved_path, out_str)?;
+                fs.write(&resolved_path, &out_str)?;
             }
         }
+
+        groups.push(GroupedFilePlan {
+            path: resolved_path,
+            results,
+        });
     }
 
-    Ok(results)
+    Ok(groups)
+}
+
+/// A single description edit that's narrow enough for [`try_splice_file`] to splice in
+/// place rather than falling back to a full file rewrite.
+enum DescriptionEdit {
+    Model(String),
+    Column { column: String, value: String },
+}
+
+/// Classifies the gap between `existing` (what's currently on disk) and `incoming` (the
+/// freshly computed fix) as a single description edit, or `None` if it's anything wider --
+/// a new column, a populated `data_tests`/extras field, or more than one description
+/// changing at once. A column present in `incoming` whose description already matches
+/// `existing` is treated as a no-op, not a disqualifying change, since `ModelProperty::merge`
+/// would do nothing there either.
+fn description_only_edit(existing: &ModelProperty, incoming: &ModelProperty) -> Option<DescriptionEdit> {
+    if !incoming.extras.is_empty() {
+        return None;
+    }
+
+    let model_description_changed =
+        incoming.description.is_some() && incoming.description != existing.description;
+
+    let mut changed_column = None;
+    for col in &incoming.columns {
+        let existing_col = existing.columns.iter().find(|c| c.name == col.name)?;
+        if col.data_tests.is_some()
+            || col.data_type.is_some()
+            || col.tags.is_some()
+            || col.meta.is_some()
+            || !col.extras.is_empty()
+        {
+            return None;
+        }
+        if col.description.is_some() && col.description != existing_col.description {
+            if changed_column.is_some() {
+                return None;
+            }
+            changed_column = Some((col.name.clone(), col.description.clone().unwrap()));
+        }
+    }
+
+    match (model_description_changed, changed_column) {
+        (true, None) => Some(DescriptionEdit::Model(incoming.description.clone().unwrap())),
+        (false, Some((column, value))) => Some(DescriptionEdit::Column { column, value }),
+        _ => None,
+    }
+}
+
+/// Attempts a format-preserving splice of `source` for this file group, succeeding only
+/// when every model in it reduces to a single description edit on an already-existing
+/// model/column: exactly one [`ModelChange::ChangePropertiesFile`] per model, touching no
+/// new columns, `data_tests`, or extras. Any model with a move, a layout normalize, more
+/// than one change, or a wider property edit falls the whole group back to `None`, so a
+/// caller never ends up splicing part of a file and fully rewriting the rest of it.
+fn try_splice_file(
+    source: &str,
+    docs: &PropertyFile,
+    models_for_file: &[&ModelChanges],
+) -> Option<(String, Vec<(String, Vec<String>)>)> {
+    let mut text = source.to_string();
+    let mut results = Vec::new();
+
+    for model_changes in models_for_file {
+        let [ModelChange::ChangePropertiesFile {
+            model_name,
+            property: Some(prop),
+            ..
+        }] = model_changes.changes.as_slice()
+        else {
+            return None;
+        };
+
+        let existing = docs
+            .models
+            .as_ref()?
+            .iter()
+            .find(|m| m.name.as_deref() == Some(model_name.as_str()))?;
+
+        let edit = description_only_edit(existing, prop)?;
+
+        let (column, value) = match &edit {
+            DescriptionEdit::Model(value) => (None, value.as_str()),
+            DescriptionEdit::Column { column, value } => (Some(column.as_str()), value.as_str()),
+        };
+        text = splice::splice_description(&text, model_name, column, value, None)?;
+
+        let reported_columns = match &edit {
+            DescriptionEdit::Model(_) => Vec::new(),
+            DescriptionEdit::Column { column, .. } => vec![column.clone()],
+        };
+        results.push((model_changes.model_id.clone(), reported_columns));
+    }
+
+    Some((text, results))
+}
+
+/// One step needed to undo an already-applied [`DryRunOp`], built up as `commit_ops`
+/// applies each planned operation in turn.
+enum UndoOp {
+    Restore { path: PathBuf, contents: String },
+    Remove { path: PathBuf },
+    Rename { from: PathBuf, to: PathBuf },
+}
+
+/// Applies `ops` (as planned against the real filesystem by `plan_rust_writeback`) one at a
+/// time, recording how to reverse each as it succeeds. If any operation fails, every
+/// already-applied operation is rolled back in reverse order before the original error is
+/// returned.
+fn commit_ops(fs: &dyn Fs, ops: Vec<DryRunOp>) -> Result<(), WriteBackError> {
+    let mut undo_log = Vec::new();
+
+    for op in ops {
+        if let Err(err) = apply_op(fs, &op, &mut undo_log) {
+            rollback(fs, undo_log);
+            return Err(err);
+        }
+    }
+
+    Ok(())
+}
+
+fn apply_op(fs: &dyn Fs, op: &DryRunOp, undo_log: &mut Vec<UndoOp>) -> Result<(), WriteBackError> {
+    match op {
+        DryRunOp::Write { path, contents } => {
+            let prior = if fs.exists(path) {
+                Some(fs.read_to_string(path)?)
+            } else {
+                None
+            };
+            fs.write(path, contents)?;
+            undo_log.push(match prior {
+                Some(contents) => UndoOp::Restore {
+                    path: path.clone(),
+                    contents,
+                },
+                None => UndoOp::Remove { path: path.clone() },
+            });
+        }
+        DryRunOp::Rename { from, to } => {
+            fs.rename(from, to)?;
+            undo_log.push(UndoOp::Rename {
+                from: to.clone(),
+                to: from.clone(),
+            });
+        }
+        DryRunOp::Remove { path } => {
+            let contents = fs.read_to_string(path)?;
+            fs.remove_file(path)?;
+            undo_log.push(UndoOp::Restore {
+                path: path.clone(),
+                contents,
+            });
+        }
+        DryRunOp::CreateDirAll { path } => {
+            fs.create_dir_all(path)?;
+        }
+    }
+    Ok(())
+}
+
+/// Best-effort: a failure here means we're already unwinding from a prior error, so there's
+/// nothing more useful to do than warn and keep undoing the rest of the log.
+fn rollback(fs: &dyn Fs, undo_log: Vec<UndoOp>) {
+    for undo in undo_log.into_iter().rev() {
+        let result = match undo {
+            UndoOp::Restore { path, contents } => fs.write(&path, &contents),
+            UndoOp::Remove { path } => fs.remove_file(&path),
+            UndoOp::Rename { from, to } => fs.rename(&from, &to),
+        };
+        if let Err(err) = result {
+            eprintln!("warning: failed to roll back writeback change: {err}");
+        }
+    }
 }
 
 fn move_model_property(
+    fs: &dyn Fs,
     target_root: &mut PropertyFile,
     project_root: &Path,
     model_id: &str,
     model_name: &str,
     current_patch: &Option<PathBuf>,
     expected_patch: &PathBuf,
+    options: MoveOptions,
+    style: &properties::PropertiesFormat,
 ) -> Result<bool, WriteBackError> {
     let current = current_patch
         .clone()
@@ -183,14 +527,57 @@ fn move_model_property(
         return Ok(false);
     }
 
+    if !options.overwrite {
+        check_no_destination_conflict(fs, project_root, model_id, model_name, expected_patch)?;
+    }
+
     let current_path = resolve_patch_path(project_root, &current);
 
-    let mut source_doc = read_property_file(&current_path)?;
+    let (mut source_doc, source_formatting) = read_property_file(fs, &current_path)?;
     let property = extract_model_property(model_id, model_name, &mut source_doc)?;
 
     upsert_model_property(target_root, property);
 
-    write_or_remove_property_file(&current_path, &source_doc)?;
+    write_or_remove_property_file(fs, &current_path, &source_doc, &source_formatting, style)?;
+
+    Ok(true)
+}
+
+/// Moves a model's property out of `current_patch` and into `target_root`, same as
+/// `move_model_property`, except a model with no properties file yet (`current_patch`
+/// is `None`) is a no-op here rather than an error -- there's nothing to reorganize
+/// until a `ChangePropertiesFile`/`GeneratePropertiesFile` change creates one.
+fn normalize_properties_layout(
+    fs: &dyn Fs,
+    target_root: &mut PropertyFile,
+    project_root: &Path,
+    model_id: &str,
+    model_name: &str,
+    current_patch: &Option<PathBuf>,
+    expected_patch: &Path,
+    options: MoveOptions,
+    style: &properties::PropertiesFormat,
+) -> Result<bool, WriteBackError> {
+    let Some(current) = current_patch.clone() else {
+        return Ok(false);
+    };
+
+    if current.as_path() == expected_patch {
+        return Ok(false);
+    }
+
+    if !options.overwrite {
+        check_no_destination_conflict(fs, project_root, model_id, model_name, expected_patch)?;
+    }
+
+    let current_path = resolve_patch_path(project_root, &current);
+
+    let (mut source_doc, source_formatting) = read_property_file(fs, &current_path)?;
+    let property = extract_model_property(model_id, model_name, &mut source_doc)?;
+
+    upsert_model_property(target_root, property);
+
+    write_or_remove_property_file(fs, &current_path, &source_doc, &source_formatting, style)?;
 
     Ok(true)
 }
@@ -203,34 +590,54 @@ fn resolve_patch_path(project_root: &Path, patch_path: &Path) -> PathBuf {
     }
 }
 
-fn read_property_file(path: &Path) -> Result<PropertyFile, WriteBackError> {
-    if !path.exists() {
-        return Ok(PropertyFile {
+fn read_property_file(
+    fs: &dyn Fs,
+    path: &Path,
+) -> Result<(PropertyFile, properties::PropertyFileFormatting), WriteBackError> {
+    if !fs.exists(path) {
+        let doc = PropertyFile {
+            version: properties::CURRENT_VERSION,
             models: None,
             sources: None,
             extras: Default::default(),
-        });
+        };
+        return Ok((doc, properties::PropertyFileFormatting::default()));
     }
 
-    let contents = fs::read_to_string(path)?;
-    let doc = dbt_serde_yaml::from_str(&contents)?;
-    Ok(doc)
+    let contents = fs.read_to_string(path)?;
+    let (formatting, remainder) = properties::PropertyFileFormatting::detect(&contents);
+    let mut doc: PropertyFile = dbt_serde_yaml::from_str(remainder)?;
+    doc.normalize();
+    Ok((doc, formatting))
+}
+
+/// Reads the properties file at `path` and flags any `extras` key that isn't part of
+/// the known dbt schema for its level (see `properties::validate_extras`).
+pub fn lint_property_file(fs: &dyn Fs, path: &Path) -> Result<Vec<properties::ExtrasWarning>, WriteBackError> {
+    let (doc, _formatting) = read_property_file(fs, path)?;
+    Ok(properties::validate_extras(&doc))
 }
 
-fn write_or_remove_property_file(path: &Path, doc: &PropertyFile) -> Result<(), WriteBackError> {
+fn write_or_remove_property_file(
+    fs: &dyn Fs,
+    path: &Path,
+    doc: &PropertyFile,
+    formatting: &properties::PropertyFileFormatting,
+    style: &properties::PropertiesFormat,
+) -> Result<(), WriteBackError> {
     if property_file_is_empty(doc) {
-        if path.exists() {
-            fs::remove_file(path)?;
+        if fs.exists(path) {
+            fs.remove_file(path)?;
         }
         return Ok(());
     }
 
     if let Some(parent) = path.parent() {
-        std::fs::create_dir_all(parent)?;
+        fs.create_dir_all(parent)?;
     }
 
-    let yaml = dbt_serde_yaml::to_string(doc)?;
-    fs::write(path, yaml)?;
+    let yaml = properties::render_property_file_styled(doc, formatting, style)?;
+    fs.write(path, &yaml)?;
     Ok(())
 }
 
@@ -270,12 +677,46 @@ fn upsert_model_property(doc: &mut PropertyFile, property: ModelProperty) {
         .iter_mut()
         .find(|model| model.name.as_deref() == property.name.as_deref())
     {
-        existing.merge(&property);
+        let model_name = existing.name.clone().unwrap_or_default();
+        for conflict in existing.merge(&property) {
+            eprintln!(
+                "warning: {model_name}.{} conflict during writeback: {:?} vs {:?}",
+                conflict.path, conflict.self_value, conflict.other_value
+            );
+        }
     } else {
         models.push(property);
     }
 }
 
+/// Checks whether `expected_patch`'s file already defines a model named `model_name`,
+/// straight from `fs` rather than `target_root` -- `target_root` is loaded from the
+/// model's *current* file, so it always already contains this model's own pre-move
+/// entry, which would make every move look like a false self-collision. Only a fresh
+/// read of the actual destination can tell that apart from two different models
+/// genuinely landing on the same name.
+fn check_no_destination_conflict(
+    fs: &dyn Fs,
+    project_root: &Path,
+    model_id: &str,
+    model_name: &str,
+    expected_patch: &Path,
+) -> Result<(), WriteBackError> {
+    let destination_path = resolve_patch_path(project_root, expected_patch);
+    let (destination_doc, _) = read_property_file(fs, &destination_path)?;
+    let already_defined = destination_doc
+        .models
+        .as_ref()
+        .is_some_and(|models| models.iter().any(|m| m.name.as_deref() == Some(model_name)));
+    if already_defined {
+        return Err(WriteBackError::DestinationConflict {
+            model_id: model_id.to_string(),
+            model_name: model_name.to_string(),
+        });
+    }
+    Ok(())
+}
+
 fn property_file_is_empty(doc: &PropertyFile) -> bool {
     doc.models.as_ref().is_none_or(|models| models.is_empty())
         && doc
@@ -289,9 +730,37 @@ fn property_file_is_empty(doc: &PropertyFile) -> bool {
 mod tests {
     use super::*;
     use crate::change_descriptors::{ColumnChange, ModelChange};
-    use std::fs;
+    use crate::graph::DbtGraph;
+    use crate::writeback::fs::FakeFs;
     use std::path::Path;
-    use tempfile::tempdir;
+
+    /// A graph with no edges: these tests don't exercise topological ordering, so every
+    /// model is simply "its own component" as far as `sort_changes_topologically` is
+    /// concerned.
+    fn empty_graph() -> DbtGraph {
+        DbtGraph {
+            graph: petgraph::graph::Graph::new(),
+            index: std::collections::HashMap::new(),
+        }
+    }
+
+    fn graph_from_edges(edges: &[(&str, &str)]) -> DbtGraph {
+        let mut graph = petgraph::graph::Graph::<String, ()>::new();
+        let mut index: std::collections::HashMap<String, petgraph::graph::NodeIndex> =
+            std::collections::HashMap::new();
+
+        for (parent, child) in edges {
+            let p = *index
+                .entry(parent.to_string())
+                .or_insert_with(|| graph.add_node(parent.to_string()));
+            let c = *index
+                .entry(child.to_string())
+                .or_insert_with(|| graph.add_node(child.to_string()));
+            graph.add_edge(p, c, ());
+        }
+
+        DbtGraph { graph, index }
+    }
 
     fn sample_yaml() -> &'static str {
         r#"
@@ -308,9 +777,8 @@ models:
 
     #[test]
     fn rust_writeback_updates_existing_column() {
-        let dir = tempdir().unwrap();
-        let file = dir.path().join("models.yml");
-        fs::write(&file, sample_yaml()).unwrap();
+        let project_root = Path::new("/project");
+        let fs = FakeFs::new().with_file(project_root.join("models.yml"), sample_yaml());
 
         let mut changes = std::collections::BTreeMap::new();
         let mut mc = ModelChanges {
@@ -333,24 +801,23 @@ models:
                 columns: vec![crate::writeback::properties::ColumnProperty {
                     name: "order_item_id".to_string(),
                     description: Some("New desc".to_string()),
-                    extras: std::collections::BTreeMap::new(),
+                    ..Default::default()
                 }],
                 extras: std::collections::BTreeMap::new(),
             }),
         });
         changes.insert(mc.model_id.clone(), mc);
 
-        let res = apply_with_rust(dir.path(), &changes).unwrap();
+        let res = apply_with_rust(&fs, project_root, &changes, MoveOptions::default(), &properties::PropertiesFormat::default(), &empty_graph()).unwrap();
         assert_eq!(res.len(), 1);
-        let written = fs::read_to_string(dir.path().join("models.yml")).unwrap();
+        let written = fs.read(&project_root.join("models.yml")).unwrap();
         assert!(written.contains("New desc"));
     }
 
     #[test]
     fn rust_writeback_appends_missing_column() {
-        let dir = tempdir().unwrap();
-        let file = dir.path().join("models.yml");
-        fs::write(&file, sample_yaml()).unwrap();
+        let project_root = Path::new("/project");
+        let fs = FakeFs::new().with_file(project_root.join("models.yml"), sample_yaml());
 
         let mut changes = std::collections::BTreeMap::new();
         let mut mc = ModelChanges {
@@ -373,24 +840,92 @@ models:
                 columns: vec![crate::writeback::properties::ColumnProperty {
                     name: "new_col".to_string(),
                     description: Some("Appended".to_string()),
-                    extras: std::collections::BTreeMap::new(),
+                    ..Default::default()
                 }],
                 extras: std::collections::BTreeMap::new(),
             }),
         });
         changes.insert(mc.model_id.clone(), mc);
 
-        let res = apply_with_rust(dir.path(), &changes).unwrap();
+        let res = apply_with_rust(&fs, project_root, &changes, MoveOptions::default(), &properties::PropertiesFormat::default(), &empty_graph()).unwrap();
         assert_eq!(res.len(), 1);
-        let written = fs::read_to_string(dir.path().join("models.yml")).unwrap();
+        let written = fs.read(&project_root.join("models.yml")).unwrap();
         assert!(written.contains("Appended"));
     }
 
     #[test]
     fn rust_writeback_moves_properties_file() {
-        let dir = tempdir().unwrap();
-        let file = dir.path().join("models.yml");
-        fs::write(&file, sample_yaml()).unwrap();
+        let project_root = Path::new("/project");
+        let fs = FakeFs::new().with_file(project_root.join("models.yml"), sample_yaml());
+
+        let mut changes = std::collections::BTreeMap::new();
+        let mut mc = ModelChanges {
+            model_id: "model.jaffle_shop.stg_order_items".to_string(),
+            patch_path: Some(Path::new("models.yml").to_path_buf()),
+            ..Default::default()
+        };
+        mc.changes.push(ModelChange::MovePropertiesFile {
+            model_id: mc.model_id.clone(),
+            model_name: "stg_order_items".to_string(),
+            patch_path: mc.patch_path.clone(),
+            new_path: Path::new("nested").join("models.yml"),
+        });
+        changes.insert(mc.model_id.clone(), mc);
+
+        let res = apply_with_rust(&fs, project_root, &changes, MoveOptions::default(), &properties::PropertiesFormat::default(), &empty_graph()).unwrap();
+        assert_eq!(res.len(), 1);
+
+        assert!(
+            !fs.exists(&project_root.join("models.yml")),
+            "original file should be moved"
+        );
+        assert!(
+            fs.exists(&project_root.join("nested/models.yml")),
+            "moved file should exist"
+        );
+    }
+
+    #[test]
+    fn rust_writeback_refuses_to_merge_into_an_existing_destination_model() {
+        let project_root = Path::new("/project");
+        let fs = FakeFs::new()
+            .with_file(project_root.join("models.yml"), sample_yaml())
+            .with_file(
+                project_root.join("nested/models.yml"),
+                "models:\n  - name: stg_order_items\n    description: already here\n",
+            );
+
+        let mut changes = std::collections::BTreeMap::new();
+        let mut mc = ModelChanges {
+            model_id: "model.jaffle_shop.stg_order_items".to_string(),
+            patch_path: Some(Path::new("models.yml").to_path_buf()),
+            ..Default::default()
+        };
+        mc.changes.push(ModelChange::MovePropertiesFile {
+            model_id: mc.model_id.clone(),
+            model_name: "stg_order_items".to_string(),
+            patch_path: mc.patch_path.clone(),
+            new_path: Path::new("nested").join("models.yml"),
+        });
+        changes.insert(mc.model_id.clone(), mc);
+
+        let err = apply_with_rust(&fs, project_root, &changes, MoveOptions::default(), &properties::PropertiesFormat::default(), &empty_graph()).unwrap_err();
+        assert!(matches!(err, WriteBackError::DestinationConflict { .. }));
+        assert!(
+            fs.exists(&project_root.join("models.yml")),
+            "a refused move must not touch the source file"
+        );
+    }
+
+    #[test]
+    fn rust_writeback_merges_into_existing_destination_model_when_overwrite_is_set() {
+        let project_root = Path::new("/project");
+        let fs = FakeFs::new()
+            .with_file(project_root.join("models.yml"), sample_yaml())
+            .with_file(
+                project_root.join("nested/models.yml"),
+                "models:\n  - name: stg_order_items\n    description: already here\n",
+            );
 
         let mut changes = std::collections::BTreeMap::new();
         let mut mc = ModelChanges {
@@ -406,11 +941,302 @@ models:
         });
         changes.insert(mc.model_id.clone(), mc);
 
-        let res = apply_with_rust(dir.path(), &changes).unwrap();
+        let res = apply_with_rust(
+            &fs,
+            project_root,
+            &changes,
+            MoveOptions { overwrite: true },
+            &properties::PropertiesFormat::default(),
+            &empty_graph(),
+        )
+        .unwrap();
         assert_eq!(res.len(), 1);
+        assert!(!fs.exists(&project_root.join("models.yml")));
+        let merged = fs.read(&project_root.join("nested/models.yml")).unwrap();
+        assert!(
+            merged.contains("Individual food and drink items"),
+            "with overwrite set, the moved-in model's fields win: {merged:?}"
+        );
+    }
+
+    #[test]
+    fn rust_writeback_move_model_file_refuses_to_overwrite_existing_destination() {
+        let project_root = Path::new("/project");
+        let fs = FakeFs::new()
+            .with_file(project_root.join("old.sql"), "select 1")
+            .with_file(project_root.join("new.sql"), "select 2");
+
+        let mut changes = std::collections::BTreeMap::new();
+        let mut mc = ModelChanges {
+            model_id: "model.jaffle_shop.stg_order_items".to_string(),
+            patch_path: Some(Path::new("models.yml").to_path_buf()),
+            ..Default::default()
+        };
+        mc.changes.push(ModelChange::MoveModelFile {
+            model_id: mc.model_id.clone(),
+            model_name: "stg_order_items".to_string(),
+            patch_path: Some(Path::new("old.sql").to_path_buf()),
+            new_path: Path::new("new.sql").to_path_buf(),
+        });
+        changes.insert(mc.model_id.clone(), mc);
+
+        let err = apply_with_rust(&fs, project_root, &changes, MoveOptions::default(), &properties::PropertiesFormat::default(), &empty_graph()).unwrap_err();
+        assert!(matches!(err, WriteBackError::DestinationConflict { .. }));
+        assert_eq!(fs.read(&project_root.join("old.sql")).as_deref(), Some("select 1"));
+        assert_eq!(fs.read(&project_root.join("new.sql")).as_deref(), Some("select 2"));
+    }
+
+    #[test]
+    fn rust_writeback_normalizes_properties_layout() {
+        let project_root = Path::new("/project");
+        let fs = FakeFs::new().with_file(project_root.join("models.yml"), sample_yaml());
+
+        let mut changes = std::collections::BTreeMap::new();
+        let mut mc = ModelChanges {
+            model_id: "model.jaffle_shop.stg_order_items".to_string(),
+            patch_path: Some(Path::new("models.yml").to_path_buf()),
+            ..Default::default()
+        };
+        mc.changes.push(ModelChange::NormalizePropertiesLayout {
+            model_id: mc.model_id.clone(),
+            model_name: "stg_order_items".to_string(),
+            current_patch: mc.patch_path.clone(),
+            expected_patch: Path::new("stg_order_items.yml").to_path_buf(),
+            layout: crate::config::ModelPropertiesLayout::PerModel,
+        });
+        changes.insert(mc.model_id.clone(), mc);
+
+        let res = apply_with_rust(&fs, project_root, &changes, MoveOptions::default(), &properties::PropertiesFormat::default(), &empty_graph()).unwrap();
+        assert_eq!(res.len(), 1);
+
+        assert!(
+            !fs.exists(&project_root.join("models.yml")),
+            "the now-empty shared file should be removed"
+        );
+        let moved = fs
+            .read(&project_root.join("stg_order_items.yml"))
+            .expect("per-model file should have been written");
+        assert!(moved.contains("stg_order_items"));
+    }
+
+    #[test]
+    fn normalize_properties_layout_is_a_no_op_without_a_current_patch() {
+        let project_root = Path::new("/project");
+        let fs = FakeFs::new().with_file(project_root.join("models.yml"), sample_yaml());
+
+        let mut changes = std::collections::BTreeMap::new();
+        let mut mc = ModelChanges {
+            model_id: "model.jaffle_shop.new_model".to_string(),
+            patch_path: Some(Path::new("models.yml").to_path_buf()),
+            ..Default::default()
+        };
+        mc.changes.push(ModelChange::NormalizePropertiesLayout {
+            model_id: mc.model_id.clone(),
+            model_name: "new_model".to_string(),
+            current_patch: None,
+            expected_patch: Path::new("new_model.yml").to_path_buf(),
+            layout: crate::config::ModelPropertiesLayout::PerModel,
+        });
+        let model_id = mc.model_id.clone();
+        changes.insert(model_id.clone(), mc);
+
+        let res = apply_with_rust(&fs, project_root, &changes, MoveOptions::default(), &properties::PropertiesFormat::default(), &empty_graph()).unwrap();
+        assert_eq!(res, vec![(model_id, Vec::new())]);
+
+        assert!(
+            fs.exists(&project_root.join("models.yml")),
+            "nothing to normalize yet, so the original file is untouched"
+        );
+        assert!(!fs.exists(&project_root.join("new_model.yml")));
+    }
+
+    #[test]
+    fn dry_run_fs_previews_without_writing() {
+        let project_root = Path::new("/project");
+        let real_fs = FakeFs::new().with_file(project_root.join("models.yml"), sample_yaml());
+        let dry_run = crate::writeback::fs::DryRunFs::new(&real_fs);
+
+        let mut changes = std::collections::BTreeMap::new();
+        let mut mc = ModelChanges {
+            model_id: "model.jaffle_shop.stg_order_items".to_string(),
+            patch_path: Some(Path::new("models.yml").to_path_buf()),
+            ..Default::default()
+        };
+        mc.changes.push(ModelChange::ChangePropertiesFile {
+            model_id: mc.model_id.clone(),
+            model_name: "stg_order_items".to_string(),
+            patch_path: mc.patch_path.clone(),
+            property: Some(crate::writeback::properties::ModelProperty {
+                name: Some("stg_order_items".to_string()),
+                description: None,
+                columns: vec![crate::writeback::properties::ColumnProperty {
+                    name: "order_item_id".to_string(),
+                    description: Some("New desc".to_string()),
+                    ..Default::default()
+                }],
+                extras: std::collections::BTreeMap::new(),
+            }),
+        });
+        changes.insert(mc.model_id.clone(), mc);
+
+        let res = apply_with_rust(&dry_run, project_root, &changes, MoveOptions::default(), &properties::PropertiesFormat::default(), &empty_graph()).unwrap();
+        assert_eq!(res.len(), 1);
+        assert!(
+            real_fs
+                .read(&project_root.join("models.yml"))
+                .unwrap()
+                .contains("order_item_id"),
+            "dry run must not mutate the underlying file"
+        );
+        assert!(
+            !real_fs
+                .read(&project_root.join("models.yml"))
+                .unwrap()
+                .contains("New desc"),
+            "dry run must not write the new description to disk"
+        );
+        assert_eq!(dry_run.into_ops().len(), 1, "the write should be recorded instead");
+    }
+
+    #[test]
+    fn rust_writeback_rolls_back_already_applied_writes_when_a_later_operation_fails() {
+        let project_root = Path::new("/project");
+        // "zzz_missing.sql" is never seeded, so renaming it fails once the commit phase
+        // actually touches the real filesystem -- the plan phase can't catch this, since
+        // `DryRunFs::rename` records the move without checking the source exists.
+        let fs = FakeFs::new().with_file(project_root.join("models.yml"), sample_yaml());
+
+        let mut changes = std::collections::BTreeMap::new();
+
+        let mut property_change = ModelChanges {
+            model_id: "model.jaffle_shop.stg_order_items".to_string(),
+            patch_path: Some(Path::new("models.yml").to_path_buf()),
+            ..Default::default()
+        };
+        property_change
+            .changes
+            .push(ModelChange::ChangePropertiesFile {
+                model_id: property_change.model_id.clone(),
+                model_name: "stg_order_items".to_string(),
+                patch_path: property_change.patch_path.clone(),
+                property: Some(crate::writeback::properties::ModelProperty {
+                    name: Some("stg_order_items".to_string()),
+                    description: None,
+                    columns: vec![crate::writeback::properties::ColumnProperty {
+                        name: "order_item_id".to_string(),
+                        description: Some("New desc".to_string()),
+                        ..Default::default()
+                    }],
+                    extras: std::collections::BTreeMap::new(),
+                }),
+            });
+        changes.insert(property_change.model_id.clone(), property_change);
+
+        let mut move_change = ModelChanges {
+            model_id: "model.jaffle_shop.missing_model".to_string(),
+            patch_path: Some(Path::new("zzz_missing.sql").to_path_buf()),
+            ..Default::default()
+        };
+        move_change.changes.push(ModelChange::MoveModelFile {
+            model_id: move_change.model_id.clone(),
+            model_name: "missing_model".to_string(),
+            patch_path: move_change.patch_path.clone(),
+            new_path: Path::new("new.sql").to_path_buf(),
+        });
+        changes.insert(move_change.model_id.clone(), move_change);
+
+        let err = apply_with_rust(&fs, project_root, &changes, MoveOptions::default(), &properties::PropertiesFormat::default(), &empty_graph()).unwrap_err();
+        assert!(matches!(err, WriteBackError::Io(_)));
+
+        let models_yml = fs.read(&project_root.join("models.yml")).unwrap();
+        assert!(
+            !models_yml.contains("New desc"),
+            "the successfully-applied write must be rolled back once the later rename fails: {models_yml:?}"
+        );
+    }
+
+    #[test]
+    fn sort_changes_topologically_orders_models_parents_first_within_a_file() {
+        let graph = graph_from_edges(&[("model.jaffle_shop.parent", "model.jaffle_shop.child")]);
+
+        let mut changes = BTreeMap::new();
+        for model_id in ["model.jaffle_shop.child", "model.jaffle_shop.parent"] {
+            changes.insert(
+                model_id.to_string(),
+                ModelChanges {
+                    model_id: model_id.to_string(),
+                    patch_path: Some(Path::new("models.yml").to_path_buf()),
+                    ..Default::default()
+                },
+            );
+        }
+
+        let grouped = sort_changes_topologically(group_changes_by_file(&changes), &graph);
+
+        assert_eq!(grouped.len(), 1);
+        let ordered_ids: Vec<&str> = grouped[0].1.iter().map(|mc| mc.model_id.as_str()).collect();
+        assert_eq!(
+            ordered_ids,
+            vec!["model.jaffle_shop.parent", "model.jaffle_shop.child"]
+        );
+    }
+
+    #[test]
+    fn sort_changes_topologically_orders_file_groups_by_their_earliest_model() {
+        let graph = graph_from_edges(&[("model.jaffle_shop.parent", "model.jaffle_shop.child")]);
+
+        let mut changes = BTreeMap::new();
+        changes.insert(
+            "model.jaffle_shop.child".to_string(),
+            ModelChanges {
+                model_id: "model.jaffle_shop.child".to_string(),
+                patch_path: Some(Path::new("aaa_child.yml").to_path_buf()),
+                ..Default::default()
+            },
+        );
+        changes.insert(
+            "model.jaffle_shop.parent".to_string(),
+            ModelChanges {
+                model_id: "model.jaffle_shop.parent".to_string(),
+                patch_path: Some(Path::new("zzz_parent.yml").to_path_buf()),
+                ..Default::default()
+            },
+        );
+
+        let grouped = sort_changes_topologically(group_changes_by_file(&changes), &graph);
+
+        let paths: Vec<&Path> = grouped.iter().map(|(path, _)| path.as_path()).collect();
+        assert_eq!(
+            paths,
+            vec![Path::new("zzz_parent.yml"), Path::new("aaa_child.yml")],
+            "parent's file must be processed first even though it sorts after alphabetically"
+        );
+    }
+
+    #[test]
+    fn sort_changes_topologically_leaves_models_unknown_to_the_graph_in_their_original_order() {
+        let graph = graph_from_edges(&[("model.jaffle_shop.known", "model.jaffle_shop.also_known")]);
+
+        let mut changes = BTreeMap::new();
+        for model_id in ["model.jaffle_shop.unknown", "model.jaffle_shop.known"] {
+            changes.insert(
+                model_id.to_string(),
+                ModelChanges {
+                    model_id: model_id.to_string(),
+                    patch_path: Some(Path::new("models.yml").to_path_buf()),
+                    ..Default::default()
+                },
+            );
+        }
+
+        let grouped = sort_changes_topologically(group_changes_by_file(&changes), &graph);
 
-        assert!(!file.exists(), "original file should be moved");
-        let new_file = dir.path().join("nested/models.yml");
-        assert!(new_file.exists(), "moved file should exist");
+        assert_eq!(grouped.len(), 1);
+        let ordered_ids: Vec<&str> = grouped[0].1.iter().map(|mc| mc.model_id.as_str()).collect();
+        assert_eq!(
+            ordered_ids,
+            vec!["model.jaffle_shop.known", "model.jaffle_shop.unknown"],
+            "a model absent from the graph should sort last, not panic or reorder known models"
+        );
     }
 }
@@ -0,0 +1,451 @@
+//! A best-effort, format-preserving in-place edit for the single most common writeback
+//! case: updating a column's (or model's) already-present `description:` scalar.
+//!
+//! [`properties::render_property_file_styled`] round-trips a property file through
+//! `dbt_serde_yaml::from_str`/`to_string`, which is simple and always correct but
+//! normalizes quoting, can reorder keys, and drops comments -- acceptable for the many
+//! shapes of change it has to handle (new models, new columns, moved files, ...), but
+//! overkill and lossy for the everyday case of "this one description changed". This
+//! module instead finds the exact line to rewrite via plain block-indentation scanning
+//! -- no YAML parser, no `dbt_serde_yaml` round-trip -- and replaces only that line's
+//! value, so every other byte (comments, blank lines, unrelated quoting, Jinja
+//! `{{ doc(...) }}`/`{{ ref(...) }}` text) survives untouched.
+//!
+//! Deliberately narrow: [`splice_description`] only understands a `description:` that
+//! already exists as a single-line scalar directly under an existing `models:` sequence
+//! entry (optionally nested one level further under that entry's `columns:` sequence).
+//! Anything wider -- a missing key, a multi-line block scalar, a model/column that
+//! doesn't exist yet -- returns `None` so the caller falls back to the full round-trip
+//! writer, which is always correct even when it isn't format-preserving.
+//!
+//! [`splice_source_description`] is the same idea one level deeper, for a `sources:`
+//! entry's `tables:` sequence (and optionally that table's `columns:` sequence), since
+//! `check::sources`'s fixes land on a source table or one of its columns the same way
+//! `check::models`'s land on a model or one of its columns.
+
+use crate::writeback::properties::{is_block_scalar_indicator, scalar_value, unquote_plain_scalar, wrap_at_width};
+
+/// Splices `model_name`'s description (or, if `column_name` is given, that column's
+/// description) to `new_value`, preserving the original line's indentation and quote
+/// style. If `wrap_width` is `Some` and `new_value` would exceed it as a single line, the
+/// replacement is written as a folded block scalar (`description: >-`) wrapped at that
+/// width instead, matching `render_property_file_styled`'s own `wrap_long_descriptions`
+/// presentation. Returns `None` -- leaving `source` untouched -- if the model, column, or
+/// an existing single-line `description:` isn't found in the shape this function
+/// understands.
+pub fn splice_description(
+    source: &str,
+    model_name: &str,
+    column_name: Option<&str>,
+    new_value: &str,
+    wrap_width: Option<usize>,
+) -> Option<String> {
+    let lines: Vec<&str> = source.split_inclusive('\n').map(strip_newline).collect();
+
+    let model_line = find_entry(&lines, 0, lines.len(), model_name)?;
+    let model_end = block_end(&lines, model_line + 1, indent_of(lines[model_line]));
+
+    let target_line = find_description_in_entry(&lines, model_line, model_end, column_name)?;
+    Some(replace_description_line(source, &lines, target_line, new_value, wrap_width))
+}
+
+/// Splices `table_name`'s description (or, if `column_name` is given, that column's
+/// description) within `source_name`'s block, the same way [`splice_description`] does
+/// for a model/column -- one level deeper, since a source's tables live under a nested
+/// `tables:` sequence rather than directly off the document root. Returns `None` --
+/// leaving `source` untouched -- if the source, table, column, or an existing
+/// single-line `description:` isn't found in the shape this function understands.
+pub fn splice_source_description(
+    source: &str,
+    source_name: &str,
+    table_name: &str,
+    column_name: Option<&str>,
+    new_value: &str,
+    wrap_width: Option<usize>,
+) -> Option<String> {
+    let lines: Vec<&str> = source.split_inclusive('\n').map(strip_newline).collect();
+
+    let source_line = find_entry(&lines, 0, lines.len(), source_name)?;
+    let source_indent = indent_of(lines[source_line]);
+    let source_end = block_end(&lines, source_line + 1, source_indent);
+
+    let tables_line = find_key_at(&lines, source_line + 1, source_end, "tables:", source_indent + 2)?;
+    let tables_indent = indent_of(lines[tables_line]);
+    let tables_end = block_end(&lines, tables_line + 1, tables_indent);
+
+    let table_line = find_entry(&lines, tables_line + 1, tables_end, table_name)?;
+    let table_end = block_end(&lines, table_line + 1, indent_of(lines[table_line]));
+
+    let target_line = find_description_in_entry(&lines, table_line, table_end, column_name)?;
+    Some(replace_description_line(source, &lines, target_line, new_value, wrap_width))
+}
+
+/// The 1-indexed line number of `model_name`'s entry (or, if `column_name` is given,
+/// that column's entry) in `source` -- for attributing a CI annotation to a location
+/// without editing anything. Unlike [`splice_description`], this doesn't require a
+/// `description:` key to already be present, since a model/column missing one entirely
+/// is exactly the kind of failure a caller wants to point at; it returns the `- name:`
+/// line of the entry itself. Returns `None` if the model or column isn't found.
+pub(crate) fn locate_model_entry(source: &str, model_name: &str, column_name: Option<&str>) -> Option<usize> {
+    let lines: Vec<&str> = source.split_inclusive('\n').map(strip_newline).collect();
+    let model_line = find_entry(&lines, 0, lines.len(), model_name)?;
+    match column_name {
+        None => Some(model_line + 1),
+        Some(column_name) => {
+            let model_end = block_end(&lines, model_line + 1, indent_of(lines[model_line]));
+            let column_line = find_column_entry(&lines, model_line, model_end, column_name)?;
+            Some(column_line + 1)
+        }
+    }
+}
+
+/// The same idea as [`locate_model_entry`], one level deeper for a source table (or one
+/// of its columns), mirroring how [`splice_source_description`] nests under
+/// [`splice_description`].
+pub(crate) fn locate_source_entry(
+    source: &str,
+    source_name: &str,
+    table_name: &str,
+    column_name: Option<&str>,
+) -> Option<usize> {
+    let lines: Vec<&str> = source.split_inclusive('\n').map(strip_newline).collect();
+
+    let source_line = find_entry(&lines, 0, lines.len(), source_name)?;
+    let source_indent = indent_of(lines[source_line]);
+    let source_end = block_end(&lines, source_line + 1, source_indent);
+
+    let tables_line = find_key_at(&lines, source_line + 1, source_end, "tables:", source_indent + 2)?;
+    let tables_indent = indent_of(lines[tables_line]);
+    let tables_end = block_end(&lines, tables_line + 1, tables_indent);
+
+    let table_line = find_entry(&lines, tables_line + 1, tables_end, table_name)?;
+    match column_name {
+        None => Some(table_line + 1),
+        Some(column_name) => {
+            let table_end = block_end(&lines, table_line + 1, indent_of(lines[table_line]));
+            let column_line = find_column_entry(&lines, table_line, table_end, column_name)?;
+            Some(column_line + 1)
+        }
+    }
+}
+
+/// `column_name`'s `- name:` line within the entry at `entry_line`'s (within
+/// `[entry_line, end)`) nested `columns:` sequence. Shared by [`locate_model_entry`] and
+/// [`locate_source_entry`], the same way [`find_description_in_entry`] is shared by the
+/// splice functions.
+fn find_column_entry(lines: &[&str], entry_line: usize, end: usize, column_name: &str) -> Option<usize> {
+    let entry_indent = indent_of(lines[entry_line]);
+    let columns_line = find_key_at(lines, entry_line + 1, end, "columns:", entry_indent + 2)?;
+    let columns_indent = indent_of(lines[columns_line]);
+    let columns_end = block_end(lines, columns_line + 1, columns_indent);
+    find_entry(lines, columns_line + 1, columns_end, column_name)
+}
+
+/// The `description:` line belonging directly to the sequence entry at `entry_line`
+/// (within `[entry_line, end)`), or -- if `column_name` is given -- that name's
+/// description within the entry's nested `columns:` sequence. Shared by
+/// [`splice_description`] and [`splice_source_description`], which only differ in how
+/// they locate `entry_line`/`end` in the first place.
+fn find_description_in_entry(
+    lines: &[&str],
+    entry_line: usize,
+    end: usize,
+    column_name: Option<&str>,
+) -> Option<usize> {
+    let entry_indent = indent_of(lines[entry_line]);
+    match column_name {
+        None => find_description_line(lines, entry_line + 1, end, entry_indent + 2),
+        Some(column_name) => {
+            let columns_line = find_key_at(lines, entry_line + 1, end, "columns:", entry_indent + 2)?;
+            let columns_indent = indent_of(lines[columns_line]);
+            let columns_end = block_end(lines, columns_line + 1, columns_indent);
+
+            let column_line = find_entry(lines, columns_line + 1, columns_end, column_name)?;
+            let column_indent = indent_of(lines[column_line]);
+            let column_end = block_end(lines, column_line + 1, column_indent);
+
+            find_description_line(lines, column_line + 1, column_end, column_indent + 2)
+        }
+    }
+}
+
+fn strip_newline(line: &str) -> &str {
+    line.strip_suffix('\n').unwrap_or(line)
+}
+
+fn indent_of(line: &str) -> usize {
+    line.chars().take_while(|c| *c == ' ').count()
+}
+
+/// The name after a sequence entry's leading `- name:` key, if `line` has that shape.
+fn entry_name(line: &str) -> Option<&str> {
+    let trimmed = line.trim_start();
+    Some(trimmed.strip_prefix("- name:")?.trim())
+}
+
+fn name_matches(raw_value: &str, target: &str) -> bool {
+    raw_value == target || unquote_plain_scalar(raw_value).as_deref() == Some(target)
+}
+
+/// First `- name: <name>` line in `[start, end)`, wherever its indentation happens to be.
+fn find_entry(lines: &[&str], start: usize, end: usize, name: &str) -> Option<usize> {
+    (start..end).find(|&i| entry_name(lines[i]).is_some_and(|v| name_matches(v, name)))
+}
+
+/// First line in `[start, end)` at exactly `indent` whose key is `key` (e.g. `"columns:"`).
+fn find_key_at(lines: &[&str], start: usize, end: usize, key: &str, indent: usize) -> Option<usize> {
+    (start..end).find(|&i| indent_of(lines[i]) == indent && lines[i].trim_start().starts_with(key))
+}
+
+/// First `description:` line in `[start, end)` at exactly `indent` whose value is a
+/// single-line scalar (not a block scalar that continues across further lines).
+fn find_description_line(lines: &[&str], start: usize, end: usize, indent: usize) -> Option<usize> {
+    let idx = find_key_at(lines, start, end, "description:", indent)?;
+    let value = scalar_value(lines[idx])?;
+    if is_block_scalar_indicator(value.trim()) {
+        return None;
+    }
+    Some(idx)
+}
+
+/// The line range `[start, end)` a block begun at `start` with entries/keys at `entry_indent`
+/// spans: every following line that's blank or indented deeper than `entry_indent` belongs
+/// to it; the first line at or below `entry_indent` (a sibling sequence entry, or a dedent
+/// out of the block entirely) ends it.
+fn block_end(lines: &[&str], start: usize, entry_indent: usize) -> usize {
+    let mut i = start;
+    while i < lines.len() && (lines[i].trim().is_empty() || indent_of(lines[i]) > entry_indent) {
+        i += 1;
+    }
+    i
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum QuoteStyle {
+    Double,
+    Single,
+    Plain,
+}
+
+fn quote_style_of(value: &str) -> QuoteStyle {
+    if value.len() >= 2 && value.starts_with('"') && value.ends_with('"') {
+        QuoteStyle::Double
+    } else if value.len() >= 2 && value.starts_with('\'') && value.ends_with('\'') {
+        QuoteStyle::Single
+    } else {
+        QuoteStyle::Plain
+    }
+}
+
+/// Renders `value` the same way `quote_style` would have rendered it on disk. A `Plain`
+/// original only stays unquoted if `value` is still unambiguously a bare string; anything
+/// that would change its parsed type or shape falls back to double-quoting, same
+/// threshold `properties::quote_scalar_value` uses for the reverse direction.
+fn render_value(value: &str, quote_style: QuoteStyle) -> String {
+    match quote_style {
+        QuoteStyle::Double => format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\"")),
+        QuoteStyle::Single => format!("'{}'", value.replace('\'', "''")),
+        QuoteStyle::Plain if is_safe_unquoted(value) => value.to_string(),
+        QuoteStyle::Plain => format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\"")),
+    }
+}
+
+fn is_safe_unquoted(value: &str) -> bool {
+    !value.is_empty()
+        && !value.contains(": ")
+        && !value.contains('\n')
+        && !value.starts_with('"')
+        && !value.starts_with('\'')
+        && !value.starts_with('[')
+        && !value.starts_with('{')
+        && !value.starts_with('&')
+        && !value.starts_with('*')
+        && !value.starts_with('#')
+        && !is_block_scalar_indicator(value)
+        && !matches!(value, "null" | "~" | "true" | "false")
+        && value.parse::<f64>().is_err()
+}
+
+/// Rewrites just `lines[target]` (a `description:` line) to hold `new_value`, preserving
+/// every other line of `source` byte-for-byte (including its original line endings,
+/// which `lines` -- split on `\n` alone -- otherwise discards).
+fn replace_description_line(
+    source: &str,
+    lines: &[&str],
+    target: usize,
+    new_value: &str,
+    wrap_width: Option<usize>,
+) -> String {
+    // `lines` was split on `\n` alone, so each line of a CRLF file still ends in `\r`.
+    let raw_line = lines[target];
+    let line = raw_line.strip_suffix('\r').unwrap_or(raw_line);
+    let indent = indent_of(line);
+    let original_value = scalar_value(line).unwrap_or_default();
+
+    let mut replacement = String::new();
+    match wrap_width {
+        Some(width) if new_value.chars().count() > width => {
+            replacement.push_str(&" ".repeat(indent));
+            replacement.push_str("description: >-");
+            let continuation_indent = " ".repeat(indent + 2);
+            for wrapped in wrap_at_width(new_value, width) {
+                replacement.push('\n');
+                replacement.push_str(&continuation_indent);
+                replacement.push_str(&wrapped);
+            }
+        }
+        _ => {
+            replacement.push_str(&" ".repeat(indent));
+            replacement.push_str("description: ");
+            replacement.push_str(&render_value(new_value, quote_style_of(original_value)));
+        }
+    }
+
+    if raw_line.ends_with('\r') {
+        replacement = replacement.replace('\n', "\r\n");
+        replacement.push('\r');
+    }
+    let mut out_lines: Vec<String> = lines.iter().map(|l| l.to_string()).collect();
+    out_lines[target] = replacement;
+    let mut out = out_lines.join("\n");
+    if source.ends_with('\n') {
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> &'static str {
+        "# leading comment, must survive untouched\nmodels:\n  - name: stg_order_items\n    description: Individual food and drink items that make up our orders, one row per item.\n    columns:\n      - name: order_item_id\n        description: The unique key for each order item.\n      - name: order_id\n        description: \"{{ doc('order_id_desc') }}\"\n"
+    }
+
+    #[test]
+    fn splices_an_existing_column_description_in_place() {
+        let spliced = splice_description(sample(), "stg_order_items", Some("order_item_id"), "New desc", None)
+            .expect("should splice");
+
+        assert!(spliced.contains("        description: New desc\n"));
+        assert!(spliced.contains("# leading comment, must survive untouched\n"));
+        assert!(spliced.contains("{{ doc('order_id_desc') }}"), "unrelated column untouched");
+        assert!(
+            !spliced.contains("The unique key for each order item."),
+            "old value must be gone"
+        );
+    }
+
+    #[test]
+    fn preserves_the_original_double_quote_style() {
+        let spliced = splice_description(sample(), "stg_order_items", Some("order_id"), "it's new", None)
+            .expect("should splice");
+        assert!(spliced.contains("description: \"it's new\"\n"));
+    }
+
+    #[test]
+    fn splices_a_model_level_description() {
+        let spliced = splice_description(sample(), "stg_order_items", None, "Updated model desc", None)
+            .expect("should splice");
+        assert!(spliced.contains("    description: Updated model desc\n"));
+        assert!(spliced.contains("The unique key for each order item."));
+    }
+
+    #[test]
+    fn wraps_a_long_replacement_into_a_folded_block_scalar() {
+        let long = "a ".repeat(40) + "end";
+        let spliced = splice_description(sample(), "stg_order_items", Some("order_item_id"), &long, Some(20))
+            .expect("should splice");
+        assert!(spliced.contains("        description: >-\n"));
+        assert!(spliced.contains("          a a"));
+    }
+
+    #[test]
+    fn returns_none_for_an_unknown_model() {
+        assert_eq!(
+            splice_description(sample(), "no_such_model", Some("order_item_id"), "x", None),
+            None
+        );
+    }
+
+    #[test]
+    fn returns_none_for_an_unknown_column() {
+        assert_eq!(
+            splice_description(sample(), "stg_order_items", Some("no_such_column"), "x", None),
+            None
+        );
+    }
+
+    #[test]
+    fn returns_none_when_the_existing_description_is_already_a_block_scalar() {
+        let source = "models:\n  - name: m\n    columns:\n      - name: c\n        description: >-\n          wrapped\n          text\n";
+        assert_eq!(splice_description(source, "m", Some("c"), "new", None), None);
+    }
+
+    fn source_sample() -> &'static str {
+        "# leading comment, must survive untouched\nsources:\n  - name: raw\n    tables:\n      - name: orders\n        description: Raw orders table.\n        columns:\n          - name: order_id\n            description: The unique key for each order.\n      - name: customers\n        description: Raw customers table.\n"
+    }
+
+    #[test]
+    fn splices_an_existing_source_table_description_in_place() {
+        let spliced = splice_source_description(source_sample(), "raw", "orders", None, "Updated orders desc", None)
+            .expect("should splice");
+        assert!(spliced.contains("        description: Updated orders desc\n"));
+        assert!(spliced.contains("# leading comment, must survive untouched\n"));
+        assert!(spliced.contains("Raw customers table."), "unrelated table untouched");
+        assert!(!spliced.contains("Raw orders table."), "old value must be gone");
+    }
+
+    #[test]
+    fn splices_an_existing_source_table_column_description_in_place() {
+        let spliced =
+            splice_source_description(source_sample(), "raw", "orders", Some("order_id"), "New desc", None)
+                .expect("should splice");
+        assert!(spliced.contains("            description: New desc\n"));
+        assert!(spliced.contains("Raw orders table."), "table description untouched");
+        assert!(!spliced.contains("The unique key for each order."));
+    }
+
+    #[test]
+    fn returns_none_for_an_unknown_source() {
+        assert_eq!(
+            splice_source_description(source_sample(), "no_such_source", "orders", None, "x", None),
+            None
+        );
+    }
+
+    #[test]
+    fn returns_none_for_an_unknown_table() {
+        assert_eq!(
+            splice_source_description(source_sample(), "raw", "no_such_table", None, "x", None),
+            None
+        );
+    }
+
+    #[test]
+    fn locates_a_model_and_column_entry_line() {
+        assert_eq!(locate_model_entry(sample(), "stg_order_items", None), Some(3));
+        assert_eq!(
+            locate_model_entry(sample(), "stg_order_items", Some("order_id")),
+            Some(8)
+        );
+        assert_eq!(locate_model_entry(sample(), "no_such_model", None), None);
+    }
+
+    #[test]
+    fn locates_a_model_entry_with_no_description_at_all() {
+        let source = "models:\n  - name: m\n    columns:\n      - name: c\n";
+        assert_eq!(locate_model_entry(source, "m", None), Some(2));
+        assert_eq!(locate_model_entry(source, "m", Some("c")), Some(4));
+    }
+
+    #[test]
+    fn locates_a_source_table_and_column_entry_line() {
+        assert_eq!(locate_source_entry(source_sample(), "raw", "orders", None), Some(5));
+        assert_eq!(
+            locate_source_entry(source_sample(), "raw", "orders", Some("order_id")),
+            Some(8)
+        );
+        assert_eq!(locate_source_entry(source_sample(), "raw", "no_such_table", None), None);
+    }
+}